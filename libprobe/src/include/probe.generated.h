@@ -8,8 +8,8 @@
 
 #include "probe_common.h"
 
-#define EVENT_HOOKS bprm_check_security, inode_unlink
-#define ALL_HOOKS bprm_check_security, inode_unlink, sys_enter_execve, sys_exit_fork, sys_exit_vfork, sys_exit_clone, sys_exit_clone3, sched_process_free, inode_getattr
+#define EVENT_HOOKS bprm_check_security, inode_unlink, sb_mount, sb_umount, task_kill, mmap_file, file_mprotect, sock_graft, sched_process_fork, sched_process_exit
+#define ALL_HOOKS bprm_check_security, inode_unlink, sb_mount, sb_umount, task_kill, mmap_file, file_mprotect, sock_graft, sched_process_fork, sched_process_exit, sys_enter_execve, sys_exit_fork, sys_exit_vfork, sys_exit_clone, sys_exit_clone3, sched_process_free, inode_getattr
 
 #define bprm_check_security_index 0
 
@@ -23,6 +23,7 @@ struct bpf_bprm_check_security_event_process_parent_t {
   char name[256];
   unsigned long args_count;
   char args[64][128];
+  unsigned int args_truncated;
   unsigned int ppid;
   unsigned long start;
   unsigned long thread__id;
@@ -37,7 +38,12 @@ struct bpf_bprm_check_security_event_process_t {
   unsigned long args_count;
   unsigned long start;
   unsigned long thread__id;
+  char command_line[4096];
   char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
   struct bpf_bprm_check_security_event_process_parent_t parent;
 };
 struct bpf_bprm_check_security_event_user_group_t {
@@ -55,142 +61,1815 @@ struct bpf_bprm_check_security_event_user_t {
   struct bpf_bprm_check_security_event_user_group_t group;
   struct bpf_bprm_check_security_event_user_effective_t effective;
 };
+struct bpf_bprm_check_security_event_container_t {
+  char id[256];
+};
+struct bpf_bprm_check_security_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_bprm_check_security_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
 struct bpf_bprm_check_security_event_t {
   unsigned long __timestamp;
   struct bpf_bprm_check_security_event_event_t event;
   struct bpf_bprm_check_security_event_process_t process;
   struct bpf_bprm_check_security_event_user_t user;
+  struct bpf_bprm_check_security_event_container_t container;
+  struct bpf_bprm_check_security_event_cgroup_t cgroup;
+  struct bpf_bprm_check_security_event_namespace_t namespace;
+};
+
+struct query_bpf_bprm_check_security_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_bprm_check_security_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_bprm_check_security_event_process_parent_t parent;
+};
+struct query_bpf_bprm_check_security_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_bprm_check_security_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_bprm_check_security_event_t {
+  char ___absolute;
+  struct query_bpf_bprm_check_security_event_process_t process;
+  struct query_bpf_bprm_check_security_event_user_t user;
+  struct query_bpf_bprm_check_security_event_container_t container;
+};
+#define inode_unlink_index 1
+
+struct bpf_inode_unlink_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_inode_unlink_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_inode_unlink_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_inode_unlink_event_process_parent_t parent;
+};
+struct bpf_inode_unlink_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_inode_unlink_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_inode_unlink_event_user_effective_t {
+  unsigned int id;
+  struct bpf_inode_unlink_event_user_effective_group_t group;
+};
+struct bpf_inode_unlink_event_user_t {
+  unsigned int id;
+  struct bpf_inode_unlink_event_user_group_t group;
+  struct bpf_inode_unlink_event_user_effective_t effective;
+};
+struct bpf_inode_unlink_event_file_t {
+  char path[256];
+  unsigned long inode;
+};
+struct bpf_inode_unlink_event_container_t {
+  char id[256];
+};
+struct bpf_inode_unlink_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_inode_unlink_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_inode_unlink_event_t {
+  unsigned long __timestamp;
+  struct bpf_inode_unlink_event_event_t event;
+  struct bpf_inode_unlink_event_process_t process;
+  struct bpf_inode_unlink_event_user_t user;
+  struct bpf_inode_unlink_event_file_t file;
+  struct bpf_inode_unlink_event_container_t container;
+  struct bpf_inode_unlink_event_cgroup_t cgroup;
+  struct bpf_inode_unlink_event_namespace_t namespace;
+};
+
+struct query_bpf_inode_unlink_event_process_t {
+  char name___operator;
+  char name[256];
+};
+struct query_bpf_inode_unlink_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_inode_unlink_event_file_t {
+  char path___operator;
+  char path[256];
+};
+struct query_bpf_inode_unlink_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_inode_unlink_event_t {
+  char ___absolute;
+  struct query_bpf_inode_unlink_event_process_t process;
+  struct query_bpf_inode_unlink_event_user_t user;
+  struct query_bpf_inode_unlink_event_file_t file;
+  struct query_bpf_inode_unlink_event_container_t container;
+};
+#define sb_mount_index 2
+
+struct bpf_sb_mount_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_sb_mount_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_sb_mount_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_sb_mount_event_process_parent_t parent;
+};
+struct bpf_sb_mount_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_sb_mount_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_sb_mount_event_user_effective_t {
+  unsigned int id;
+  struct bpf_sb_mount_event_user_effective_group_t group;
+};
+struct bpf_sb_mount_event_user_t {
+  unsigned int id;
+  struct bpf_sb_mount_event_user_group_t group;
+  struct bpf_sb_mount_event_user_effective_t effective;
+};
+struct bpf_sb_mount_event_mount_t {
+  char source[256];
+  char target[256];
+  char fstype[256];
+  unsigned long flags;
+};
+struct bpf_sb_mount_event_container_t {
+  char id[256];
+};
+struct bpf_sb_mount_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_sb_mount_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_sb_mount_event_t {
+  unsigned long __timestamp;
+  struct bpf_sb_mount_event_event_t event;
+  struct bpf_sb_mount_event_process_t process;
+  struct bpf_sb_mount_event_user_t user;
+  struct bpf_sb_mount_event_mount_t mount;
+  struct bpf_sb_mount_event_container_t container;
+  struct bpf_sb_mount_event_cgroup_t cgroup;
+  struct bpf_sb_mount_event_namespace_t namespace;
+};
+
+struct query_bpf_sb_mount_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_sb_mount_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_sb_mount_event_process_parent_t parent;
+};
+struct query_bpf_sb_mount_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_sb_mount_event_mount_t {
+  char target___operator;
+  char target[256];
+  char fstype___operator;
+  char fstype[256];
+};
+struct query_bpf_sb_mount_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_sb_mount_event_t {
+  char ___absolute;
+  struct query_bpf_sb_mount_event_process_t process;
+  struct query_bpf_sb_mount_event_user_t user;
+  struct query_bpf_sb_mount_event_mount_t mount;
+  struct query_bpf_sb_mount_event_container_t container;
+};
+#define sb_umount_index 3
+
+struct bpf_sb_umount_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_sb_umount_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_sb_umount_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_sb_umount_event_process_parent_t parent;
+};
+struct bpf_sb_umount_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_sb_umount_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_sb_umount_event_user_effective_t {
+  unsigned int id;
+  struct bpf_sb_umount_event_user_effective_group_t group;
+};
+struct bpf_sb_umount_event_user_t {
+  unsigned int id;
+  struct bpf_sb_umount_event_user_group_t group;
+  struct bpf_sb_umount_event_user_effective_t effective;
+};
+struct bpf_sb_umount_event_mount_t {
+  char source[256];
+  char target[256];
+  char fstype[256];
+  unsigned long flags;
+};
+struct bpf_sb_umount_event_container_t {
+  char id[256];
+};
+struct bpf_sb_umount_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_sb_umount_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_sb_umount_event_t {
+  unsigned long __timestamp;
+  struct bpf_sb_umount_event_event_t event;
+  struct bpf_sb_umount_event_process_t process;
+  struct bpf_sb_umount_event_user_t user;
+  struct bpf_sb_umount_event_mount_t mount;
+  struct bpf_sb_umount_event_container_t container;
+  struct bpf_sb_umount_event_cgroup_t cgroup;
+  struct bpf_sb_umount_event_namespace_t namespace;
+};
+
+struct query_bpf_sb_umount_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_sb_umount_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_sb_umount_event_process_parent_t parent;
+};
+struct query_bpf_sb_umount_event_user_t {
+  char id___operator;
+  unsigned int id;
 };
+struct query_bpf_sb_umount_event_mount_t {
+  char target___operator;
+  char target[256];
+  char fstype___operator;
+  char fstype[256];
+};
+struct query_bpf_sb_umount_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_sb_umount_event_t {
+  char ___absolute;
+  struct query_bpf_sb_umount_event_process_t process;
+  struct query_bpf_sb_umount_event_user_t user;
+  struct query_bpf_sb_umount_event_mount_t mount;
+  struct query_bpf_sb_umount_event_container_t container;
+};
+#define task_kill_index 4
+
+struct bpf_task_kill_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_task_kill_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_task_kill_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_task_kill_event_process_parent_t parent;
+};
+struct bpf_task_kill_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_task_kill_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_task_kill_event_user_effective_t {
+  unsigned int id;
+  struct bpf_task_kill_event_user_effective_group_t group;
+};
+struct bpf_task_kill_event_user_t {
+  unsigned int id;
+  struct bpf_task_kill_event_user_group_t group;
+  struct bpf_task_kill_event_user_effective_t effective;
+};
+struct bpf_task_kill_event_signal_t {
+  unsigned int number;
+};
+struct bpf_task_kill_event_target_process_t {
+  unsigned int pid;
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char args[64][128];
+  unsigned int args_truncated;
+};
+struct bpf_task_kill_event_target_t {
+  struct bpf_task_kill_event_target_process_t process;
+};
+struct bpf_task_kill_event_container_t {
+  char id[256];
+};
+struct bpf_task_kill_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_task_kill_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_task_kill_event_t {
+  unsigned long __timestamp;
+  struct bpf_task_kill_event_event_t event;
+  struct bpf_task_kill_event_process_t process;
+  struct bpf_task_kill_event_user_t user;
+  struct bpf_task_kill_event_signal_t signal;
+  struct bpf_task_kill_event_target_t target;
+  struct bpf_task_kill_event_container_t container;
+  struct bpf_task_kill_event_cgroup_t cgroup;
+  struct bpf_task_kill_event_namespace_t namespace;
+};
+
+struct query_bpf_task_kill_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_task_kill_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_task_kill_event_process_parent_t parent;
+};
+struct query_bpf_task_kill_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_task_kill_event_signal_t {
+  char number___operator;
+  unsigned int number;
+};
+struct query_bpf_task_kill_event_target_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_task_kill_event_target_t {
+  struct query_bpf_task_kill_event_target_process_t process;
+};
+struct query_bpf_task_kill_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_task_kill_event_t {
+  char ___absolute;
+  struct query_bpf_task_kill_event_process_t process;
+  struct query_bpf_task_kill_event_user_t user;
+  struct query_bpf_task_kill_event_signal_t signal;
+  struct query_bpf_task_kill_event_target_t target;
+  struct query_bpf_task_kill_event_container_t container;
+};
+#define mmap_file_index 5
+
+struct bpf_mmap_file_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_mmap_file_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_mmap_file_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_mmap_file_event_process_parent_t parent;
+};
+struct bpf_mmap_file_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_mmap_file_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_mmap_file_event_user_effective_t {
+  unsigned int id;
+  struct bpf_mmap_file_event_user_effective_group_t group;
+};
+struct bpf_mmap_file_event_user_t {
+  unsigned int id;
+  struct bpf_mmap_file_event_user_group_t group;
+  struct bpf_mmap_file_event_user_effective_t effective;
+};
+struct bpf_mmap_file_event_file_t {
+  char path[256];
+  unsigned long inode;
+};
+struct bpf_mmap_file_event_memory_t {
+  unsigned int anonymous;
+  unsigned long protection;
+  unsigned long flags;
+};
+struct bpf_mmap_file_event_container_t {
+  char id[256];
+};
+struct bpf_mmap_file_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_mmap_file_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_mmap_file_event_t {
+  unsigned long __timestamp;
+  struct bpf_mmap_file_event_event_t event;
+  struct bpf_mmap_file_event_process_t process;
+  struct bpf_mmap_file_event_user_t user;
+  struct bpf_mmap_file_event_file_t file;
+  struct bpf_mmap_file_event_memory_t memory;
+  struct bpf_mmap_file_event_container_t container;
+  struct bpf_mmap_file_event_cgroup_t cgroup;
+  struct bpf_mmap_file_event_namespace_t namespace;
+};
+
+struct query_bpf_mmap_file_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_mmap_file_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_mmap_file_event_process_parent_t parent;
+};
+struct query_bpf_mmap_file_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_mmap_file_event_file_t {
+  char path___operator;
+  char path[256];
+};
+struct query_bpf_mmap_file_event_memory_t {
+  char anonymous___operator;
+  unsigned int anonymous;
+};
+struct query_bpf_mmap_file_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_mmap_file_event_t {
+  char ___absolute;
+  struct query_bpf_mmap_file_event_process_t process;
+  struct query_bpf_mmap_file_event_user_t user;
+  struct query_bpf_mmap_file_event_file_t file;
+  struct query_bpf_mmap_file_event_memory_t memory;
+  struct query_bpf_mmap_file_event_container_t container;
+};
+#define file_mprotect_index 6
+
+struct bpf_file_mprotect_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_file_mprotect_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_file_mprotect_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_file_mprotect_event_process_parent_t parent;
+};
+struct bpf_file_mprotect_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_file_mprotect_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_file_mprotect_event_user_effective_t {
+  unsigned int id;
+  struct bpf_file_mprotect_event_user_effective_group_t group;
+};
+struct bpf_file_mprotect_event_user_t {
+  unsigned int id;
+  struct bpf_file_mprotect_event_user_group_t group;
+  struct bpf_file_mprotect_event_user_effective_t effective;
+};
+struct bpf_file_mprotect_event_file_t {
+  char path[256];
+  unsigned long inode;
+};
+struct bpf_file_mprotect_event_memory_t {
+  unsigned int anonymous;
+  unsigned long protection;
+  unsigned long address;
+  unsigned long size;
+};
+struct bpf_file_mprotect_event_container_t {
+  char id[256];
+};
+struct bpf_file_mprotect_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_file_mprotect_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_file_mprotect_event_t {
+  unsigned long __timestamp;
+  struct bpf_file_mprotect_event_event_t event;
+  struct bpf_file_mprotect_event_process_t process;
+  struct bpf_file_mprotect_event_user_t user;
+  struct bpf_file_mprotect_event_file_t file;
+  struct bpf_file_mprotect_event_memory_t memory;
+  struct bpf_file_mprotect_event_container_t container;
+  struct bpf_file_mprotect_event_cgroup_t cgroup;
+  struct bpf_file_mprotect_event_namespace_t namespace;
+};
+
+struct query_bpf_file_mprotect_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_file_mprotect_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_file_mprotect_event_process_parent_t parent;
+};
+struct query_bpf_file_mprotect_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_file_mprotect_event_file_t {
+  char path___operator;
+  char path[256];
+};
+struct query_bpf_file_mprotect_event_memory_t {
+  char anonymous___operator;
+  unsigned int anonymous;
+};
+struct query_bpf_file_mprotect_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_file_mprotect_event_t {
+  char ___absolute;
+  struct query_bpf_file_mprotect_event_process_t process;
+  struct query_bpf_file_mprotect_event_user_t user;
+  struct query_bpf_file_mprotect_event_file_t file;
+  struct query_bpf_file_mprotect_event_memory_t memory;
+  struct query_bpf_file_mprotect_event_container_t container;
+};
+#define sock_graft_index 7
+
+struct bpf_sock_graft_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_sock_graft_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_sock_graft_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_sock_graft_event_process_parent_t parent;
+};
+struct bpf_sock_graft_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_sock_graft_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_sock_graft_event_user_effective_t {
+  unsigned int id;
+  struct bpf_sock_graft_event_user_effective_group_t group;
+};
+struct bpf_sock_graft_event_user_t {
+  unsigned int id;
+  struct bpf_sock_graft_event_user_group_t group;
+  struct bpf_sock_graft_event_user_effective_t effective;
+};
+struct bpf_sock_graft_event_source_t {
+  unsigned char ip[16];
+  unsigned int port;
+};
+struct bpf_sock_graft_event_destination_t {
+  unsigned char ip[16];
+  unsigned int port;
+};
+struct bpf_sock_graft_event_network_t {
+  unsigned int iana_number;
+};
+struct bpf_sock_graft_event_container_t {
+  char id[256];
+};
+struct bpf_sock_graft_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_sock_graft_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_sock_graft_event_t {
+  unsigned long __timestamp;
+  struct bpf_sock_graft_event_event_t event;
+  struct bpf_sock_graft_event_process_t process;
+  struct bpf_sock_graft_event_user_t user;
+  struct bpf_sock_graft_event_source_t source;
+  struct bpf_sock_graft_event_destination_t destination;
+  struct bpf_sock_graft_event_network_t network;
+  struct bpf_sock_graft_event_container_t container;
+  struct bpf_sock_graft_event_cgroup_t cgroup;
+  struct bpf_sock_graft_event_namespace_t namespace;
+};
+
+struct query_bpf_sock_graft_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_sock_graft_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_sock_graft_event_process_parent_t parent;
+};
+struct query_bpf_sock_graft_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_sock_graft_event_source_t {
+  char port___operator;
+  unsigned int port;
+};
+struct query_bpf_sock_graft_event_destination_t {
+  char port___operator;
+  unsigned int port;
+};
+struct query_bpf_sock_graft_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_sock_graft_event_t {
+  char ___absolute;
+  struct query_bpf_sock_graft_event_process_t process;
+  struct query_bpf_sock_graft_event_user_t user;
+  struct query_bpf_sock_graft_event_source_t source;
+  struct query_bpf_sock_graft_event_destination_t destination;
+  struct query_bpf_sock_graft_event_container_t container;
+};
+#define sched_process_fork_index 8
+
+struct bpf_sched_process_fork_event_event_t {
+  char action[256];
+  char outcome[256];
+};
+struct bpf_sched_process_fork_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_sched_process_fork_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_sched_process_fork_event_process_parent_t parent;
+};
+struct bpf_sched_process_fork_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_sched_process_fork_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_sched_process_fork_event_user_effective_t {
+  unsigned int id;
+  struct bpf_sched_process_fork_event_user_effective_group_t group;
+};
+struct bpf_sched_process_fork_event_user_t {
+  unsigned int id;
+  struct bpf_sched_process_fork_event_user_group_t group;
+  struct bpf_sched_process_fork_event_user_effective_t effective;
+};
+struct bpf_sched_process_fork_event_container_t {
+  char id[256];
+};
+struct bpf_sched_process_fork_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_sched_process_fork_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_sched_process_fork_event_t {
+  unsigned long __timestamp;
+  struct bpf_sched_process_fork_event_event_t event;
+  struct bpf_sched_process_fork_event_process_t process;
+  struct bpf_sched_process_fork_event_user_t user;
+  struct bpf_sched_process_fork_event_container_t container;
+  struct bpf_sched_process_fork_event_cgroup_t cgroup;
+  struct bpf_sched_process_fork_event_namespace_t namespace;
+};
+
+struct query_bpf_sched_process_fork_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_sched_process_fork_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_sched_process_fork_event_process_parent_t parent;
+};
+struct query_bpf_sched_process_fork_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_sched_process_fork_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_sched_process_fork_event_t {
+  char ___absolute;
+  struct query_bpf_sched_process_fork_event_process_t process;
+  struct query_bpf_sched_process_fork_event_user_t user;
+  struct query_bpf_sched_process_fork_event_container_t container;
+};
+#define sched_process_exit_index 9
+
+struct bpf_sched_process_exit_event_event_t {
+  char action[256];
+  char outcome[256];
+  unsigned long duration;
+};
+struct bpf_sched_process_exit_event_process_parent_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned long args_count;
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned int ppid;
+  unsigned long start;
+  unsigned long thread__id;
+  char executable[256];
+};
+struct bpf_sched_process_exit_event_process_t {
+  unsigned int pid;
+  char entity_id[256];
+  char name[256];
+  unsigned int ppid;
+  char executable[256];
+  unsigned long args_count;
+  unsigned long start;
+  unsigned long end;
+  unsigned long exit_code;
+  unsigned long thread__id;
+  char command_line[4096];
+  char args[64][128];
+  unsigned int args_truncated;
+  unsigned long env_count;
+  char env[8][256];
+  unsigned int env_truncated;
+  struct bpf_sched_process_exit_event_process_parent_t parent;
+};
+struct bpf_sched_process_exit_event_user_group_t {
+  unsigned int id;
+};
+struct bpf_sched_process_exit_event_user_effective_group_t {
+  unsigned int id;
+};
+struct bpf_sched_process_exit_event_user_effective_t {
+  unsigned int id;
+  struct bpf_sched_process_exit_event_user_effective_group_t group;
+};
+struct bpf_sched_process_exit_event_user_t {
+  unsigned int id;
+  struct bpf_sched_process_exit_event_user_group_t group;
+  struct bpf_sched_process_exit_event_user_effective_t effective;
+};
+struct bpf_sched_process_exit_event_container_t {
+  char id[256];
+};
+struct bpf_sched_process_exit_event_cgroup_t {
+  unsigned long id;
+  char path[256];
+};
+struct bpf_sched_process_exit_event_namespace_t {
+  unsigned int pid;
+  unsigned int mnt;
+  unsigned int user;
+};
+struct bpf_sched_process_exit_event_t {
+  unsigned long __timestamp;
+  struct bpf_sched_process_exit_event_event_t event;
+  struct bpf_sched_process_exit_event_process_t process;
+  struct bpf_sched_process_exit_event_user_t user;
+  struct bpf_sched_process_exit_event_container_t container;
+  struct bpf_sched_process_exit_event_cgroup_t cgroup;
+  struct bpf_sched_process_exit_event_namespace_t namespace;
+};
+
+struct query_bpf_sched_process_exit_event_process_parent_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+};
+struct query_bpf_sched_process_exit_event_process_t {
+  char name___operator;
+  char name[256];
+  char executable___operator;
+  char executable[256];
+  struct query_bpf_sched_process_exit_event_process_parent_t parent;
+};
+struct query_bpf_sched_process_exit_event_user_t {
+  char id___operator;
+  unsigned int id;
+};
+struct query_bpf_sched_process_exit_event_container_t {
+  char id___operator;
+  char id[256];
+};
+struct query_bpf_sched_process_exit_event_t {
+  char ___absolute;
+  struct query_bpf_sched_process_exit_event_process_t process;
+  struct query_bpf_sched_process_exit_event_user_t user;
+  struct query_bpf_sched_process_exit_event_container_t container;
+};
+
+enum event_type {
+  type_bprm_check_security_event_t,
+  type_inode_unlink_event_t,
+  type_sb_mount_event_t,
+  type_sb_umount_event_t,
+  type_task_kill_event_t,
+  type_mmap_file_event_t,
+  type_file_mprotect_event_t,
+  type_sock_graft_event_t,
+  type_sched_process_fork_event_t,
+  type_sched_process_exit_event_t,
+};
+
+struct bpf_event_t {
+  enum event_type type;
+  union {
+    struct bpf_bprm_check_security_event_t bprm_check_security_event_t;
+    struct bpf_inode_unlink_event_t inode_unlink_event_t;
+    struct bpf_sb_mount_event_t sb_mount_event_t;
+    struct bpf_sb_umount_event_t sb_umount_event_t;
+    struct bpf_task_kill_event_t task_kill_event_t;
+    struct bpf_mmap_file_event_t mmap_file_event_t;
+    struct bpf_file_mprotect_event_t file_mprotect_event_t;
+    struct bpf_sock_graft_event_t sock_graft_event_t;
+    struct bpf_sched_process_fork_event_t sched_process_fork_event_t;
+    struct bpf_sched_process_exit_event_t sched_process_exit_event_t;
+  };
+};
+
+#ifdef BPF
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(u32));
+  __uint(max_entries, 10);
+} filter_rule_sizes SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(u32));
+  __uint(max_entries, 10);
+} rejection_rule_sizes SEC(".maps");
+
+INLINE_STATIC int ___test_bprm_check_security(
+  struct bpf_bprm_check_security_event_t *event,
+  struct query_bpf_bprm_check_security_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
+
+  return conditional_true;
+}
+
+INLINE_STATIC int ___check_bprm_check_security(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_bprm_check_security_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_bprm_check_security_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_bprm_check_security(event, rule);
+  }
+  return conditional_true;
+}
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_bprm_check_security_event_t));
+  __uint(max_entries, 8);
+} bprm_check_security_filters SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_bprm_check_security_event_t));
+  __uint(max_entries, 8);
+} bprm_check_security_rejections SEC(".maps");
+INLINE_STATIC int ___test_inode_unlink(
+  struct bpf_inode_unlink_event_t *event,
+  struct query_bpf_inode_unlink_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->file.path___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->file.path,rule->file.path);
+      } else if (rule->file.path___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->file.path, rule->file.path);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
+
+  return conditional_true;
+}
+
+INLINE_STATIC int ___check_inode_unlink(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_inode_unlink_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_inode_unlink_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_inode_unlink(event, rule);
+  }
+  return conditional_true;
+}
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_inode_unlink_event_t));
+  __uint(max_entries, 8);
+} inode_unlink_filters SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_inode_unlink_event_t));
+  __uint(max_entries, 8);
+} inode_unlink_rejections SEC(".maps");
+INLINE_STATIC int ___test_sb_mount(
+  struct bpf_sb_mount_event_t *event,
+  struct query_bpf_sb_mount_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->mount.target___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->mount.target,rule->mount.target);
+      } else if (rule->mount.target___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->mount.target, rule->mount.target);
+      }
+      if (rule->mount.fstype___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->mount.fstype,rule->mount.fstype);
+      } else if (rule->mount.fstype___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->mount.fstype, rule->mount.fstype);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
+
+  return conditional_true;
+}
+
+INLINE_STATIC int ___check_sb_mount(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_sb_mount_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_sb_mount_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_sb_mount(event, rule);
+  }
+  return conditional_true;
+}
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_sb_mount_event_t));
+  __uint(max_entries, 8);
+} sb_mount_filters SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_sb_mount_event_t));
+  __uint(max_entries, 8);
+} sb_mount_rejections SEC(".maps");
+INLINE_STATIC int ___test_sb_umount(
+  struct bpf_sb_umount_event_t *event,
+  struct query_bpf_sb_umount_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->mount.target___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->mount.target,rule->mount.target);
+      } else if (rule->mount.target___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->mount.target, rule->mount.target);
+      }
+      if (rule->mount.fstype___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->mount.fstype,rule->mount.fstype);
+      } else if (rule->mount.fstype___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->mount.fstype, rule->mount.fstype);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
+
+  return conditional_true;
+}
+
+INLINE_STATIC int ___check_sb_umount(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_sb_umount_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_sb_umount_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_sb_umount(event, rule);
+  }
+  return conditional_true;
+}
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_sb_umount_event_t));
+  __uint(max_entries, 8);
+} sb_umount_filters SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_sb_umount_event_t));
+  __uint(max_entries, 8);
+} sb_umount_rejections SEC(".maps");
+INLINE_STATIC int ___test_task_kill(
+  struct bpf_task_kill_event_t *event,
+  struct query_bpf_task_kill_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->signal.number___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->signal.number,rule->signal.number);
+      } else if (rule->signal.number___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->signal.number, rule->signal.number);
+      }
+      if (rule->target.process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->target.process.name,rule->target.process.name);
+      } else if (rule->target.process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->target.process.name, rule->target.process.name);
+      }
+      if (rule->target.process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->target.process.executable,rule->target.process.executable);
+      } else if (rule->target.process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->target.process.executable, rule->target.process.executable);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
+
+  return conditional_true;
+}
+
+INLINE_STATIC int ___check_task_kill(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_task_kill_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_task_kill_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_task_kill(event, rule);
+  }
+  return conditional_true;
+}
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_task_kill_event_t));
+  __uint(max_entries, 8);
+} task_kill_filters SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_task_kill_event_t));
+  __uint(max_entries, 8);
+} task_kill_rejections SEC(".maps");
+INLINE_STATIC int ___test_mmap_file(
+  struct bpf_mmap_file_event_t *event,
+  struct query_bpf_mmap_file_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->file.path___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->file.path,rule->file.path);
+      } else if (rule->file.path___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->file.path, rule->file.path);
+      }
+      if (rule->memory.anonymous___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->memory.anonymous,rule->memory.anonymous);
+      } else if (rule->memory.anonymous___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->memory.anonymous, rule->memory.anonymous);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
+
+  return conditional_true;
+}
+
+INLINE_STATIC int ___check_mmap_file(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_mmap_file_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_mmap_file_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_mmap_file(event, rule);
+  }
+  return conditional_true;
+}
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_mmap_file_event_t));
+  __uint(max_entries, 8);
+} mmap_file_filters SEC(".maps");
+
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_mmap_file_event_t));
+  __uint(max_entries, 8);
+} mmap_file_rejections SEC(".maps");
+INLINE_STATIC int ___test_file_mprotect(
+  struct bpf_file_mprotect_event_t *event,
+  struct query_bpf_file_mprotect_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->file.path___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->file.path,rule->file.path);
+      } else if (rule->file.path___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->file.path, rule->file.path);
+      }
+      if (rule->memory.anonymous___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->memory.anonymous,rule->memory.anonymous);
+      } else if (rule->memory.anonymous___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->memory.anonymous, rule->memory.anonymous);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
 
-struct query_bpf_bprm_check_security_event_process_parent_t {
-  char name___operator;
-  char name[256];
-  char executable___operator;
-  char executable[256];
-};
-struct query_bpf_bprm_check_security_event_process_t {
-  char name___operator;
-  char name[256];
-  char executable___operator;
-  char executable[256];
-  struct query_bpf_bprm_check_security_event_process_parent_t parent;
-};
-struct query_bpf_bprm_check_security_event_user_t {
-  char id___operator;
-  unsigned int id;
-};
-struct query_bpf_bprm_check_security_event_t {
-  char ___absolute;
-  struct query_bpf_bprm_check_security_event_process_t process;
-  struct query_bpf_bprm_check_security_event_user_t user;
-};
-#define inode_unlink_index 1
+  return conditional_true;
+}
 
-struct bpf_inode_unlink_event_event_t {
-  char action[256];
-  char outcome[256];
-};
-struct bpf_inode_unlink_event_process_parent_t {
-  unsigned int pid;
-  char entity_id[256];
-  char name[256];
-  unsigned long args_count;
-  char args[64][128];
-  unsigned int ppid;
-  unsigned long start;
-  unsigned long thread__id;
-  char executable[256];
-};
-struct bpf_inode_unlink_event_process_t {
-  unsigned int pid;
-  char entity_id[256];
-  char name[256];
-  unsigned int ppid;
-  char executable[256];
-  unsigned long args_count;
-  unsigned long start;
-  unsigned long thread__id;
-  char args[64][128];
-  struct bpf_inode_unlink_event_process_parent_t parent;
-};
-struct bpf_inode_unlink_event_user_group_t {
-  unsigned int id;
-};
-struct bpf_inode_unlink_event_user_effective_group_t {
-  unsigned int id;
-};
-struct bpf_inode_unlink_event_user_effective_t {
-  unsigned int id;
-  struct bpf_inode_unlink_event_user_effective_group_t group;
-};
-struct bpf_inode_unlink_event_user_t {
-  unsigned int id;
-  struct bpf_inode_unlink_event_user_group_t group;
-  struct bpf_inode_unlink_event_user_effective_t effective;
-};
-struct bpf_inode_unlink_event_file_t {
-  char path[256];
-  unsigned long inode;
-};
-struct bpf_inode_unlink_event_t {
-  unsigned long __timestamp;
-  struct bpf_inode_unlink_event_event_t event;
-  struct bpf_inode_unlink_event_process_t process;
-  struct bpf_inode_unlink_event_user_t user;
-  struct bpf_inode_unlink_event_file_t file;
-};
+INLINE_STATIC int ___check_file_mprotect(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_file_mprotect_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_file_mprotect_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_file_mprotect(event, rule);
+  }
+  return conditional_true;
+}
 
-struct query_bpf_inode_unlink_event_process_t {
-  char name___operator;
-  char name[256];
-};
-struct query_bpf_inode_unlink_event_user_t {
-  char id___operator;
-  unsigned int id;
-};
-struct query_bpf_inode_unlink_event_file_t {
-  char path___operator;
-  char path[256];
-};
-struct query_bpf_inode_unlink_event_t {
-  char ___absolute;
-  struct query_bpf_inode_unlink_event_process_t process;
-  struct query_bpf_inode_unlink_event_user_t user;
-  struct query_bpf_inode_unlink_event_file_t file;
-};
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_file_mprotect_event_t));
+  __uint(max_entries, 8);
+} file_mprotect_filters SEC(".maps");
 
-enum event_type {
-  type_bprm_check_security_event_t,
-  type_inode_unlink_event_t,
-};
+struct {
+  __uint(type, BPF_MAP_TYPE_ARRAY);
+  __uint(key_size, sizeof(u32));
+  __uint(value_size, sizeof(struct query_bpf_file_mprotect_event_t));
+  __uint(max_entries, 8);
+} file_mprotect_rejections SEC(".maps");
+INLINE_STATIC int ___test_sock_graft(
+  struct bpf_sock_graft_event_t *event,
+  struct query_bpf_sock_graft_event_t *rule
+) {
+  int conditional_true = 1;
+  if (rule && event) {
+    if (rule->___absolute == TRUE_ABSOLUTE) {
+      return 1;
+    } else if (rule->___absolute == FALSE_ABSOLUTE) {
+      return 0;
+    } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
+      if (rule->process.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
+      } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
+      }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
+      if (rule->user.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
+      } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
+      }
+      if (rule->source.port___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->source.port,rule->source.port);
+      } else if (rule->source.port___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->source.port, rule->source.port);
+      }
+      if (rule->destination.port___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_EQUALITY(event->destination.port,rule->destination.port);
+      } else if (rule->destination.port___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && NUMBER_INEQUALITY(event->destination.port, rule->destination.port);
+      }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
+    }
+  }
 
-struct bpf_event_t {
-  enum event_type type;
-  union {
-    struct bpf_bprm_check_security_event_t bprm_check_security_event_t;
-    struct bpf_inode_unlink_event_t inode_unlink_event_t;
-  };
-};
+  return conditional_true;
+}
 
-#ifdef BPF
+INLINE_STATIC int ___check_sock_graft(
+  unsigned int size,
+  void *rule_map,
+  struct bpf_sock_graft_event_t *event
+) {
+  int conditional_true = 0;
+  if (!rule_map) return conditional_true;
+  #pragma unroll
+  for (int i = 0; i < MAX_RULE_SIZE; i++) {
+    unsigned int index = i;
+    if (index >= size) {
+      return conditional_true;
+    }
+    struct query_bpf_sock_graft_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_sock_graft(event, rule);
+  }
+  return conditional_true;
+}
 
 struct {
   __uint(type, BPF_MAP_TYPE_ARRAY);
   __uint(key_size, sizeof(u32));
-  __uint(value_size, sizeof(u32));
-  __uint(max_entries, 2);
-} filter_rule_sizes SEC(".maps");
+  __uint(value_size, sizeof(struct query_bpf_sock_graft_event_t));
+  __uint(max_entries, 8);
+} sock_graft_filters SEC(".maps");
 
 struct {
   __uint(type, BPF_MAP_TYPE_ARRAY);
   __uint(key_size, sizeof(u32));
-  __uint(value_size, sizeof(u32));
-  __uint(max_entries, 2);
-} rejection_rule_sizes SEC(".maps");
-
-INLINE_STATIC int ___test_bprm_check_security(
-  struct bpf_bprm_check_security_event_t *event,
-  struct query_bpf_bprm_check_security_event_t *rule
+  __uint(value_size, sizeof(struct query_bpf_sock_graft_event_t));
+  __uint(max_entries, 8);
+} sock_graft_rejections SEC(".maps");
+INLINE_STATIC int ___test_sched_process_fork(
+  struct bpf_sched_process_fork_event_t *event,
+  struct query_bpf_sched_process_fork_event_t *rule
 ) {
   int conditional_true = 1;
   if (rule && event) {
@@ -224,16 +1903,21 @@ INLINE_STATIC int ___test_bprm_check_security(
       } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
         conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
       }
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
+      }
     }
   }
 
   return conditional_true;
 }
 
-INLINE_STATIC int ___check_bprm_check_security(
+INLINE_STATIC int ___check_sched_process_fork(
   unsigned int size,
   void *rule_map,
-  struct bpf_bprm_check_security_event_t *event
+  struct bpf_sched_process_fork_event_t *event
 ) {
   int conditional_true = 0;
   if (!rule_map) return conditional_true;
@@ -243,8 +1927,8 @@ INLINE_STATIC int ___check_bprm_check_security(
     if (index >= size) {
       return conditional_true;
     }
-    struct query_bpf_bprm_check_security_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
-    conditional_true = conditional_true || ___test_bprm_check_security(event, rule);
+    struct query_bpf_sched_process_fork_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_sched_process_fork(event, rule);
   }
   return conditional_true;
 }
@@ -252,19 +1936,19 @@ INLINE_STATIC int ___check_bprm_check_security(
 struct {
   __uint(type, BPF_MAP_TYPE_ARRAY);
   __uint(key_size, sizeof(u32));
-  __uint(value_size, sizeof(struct query_bpf_bprm_check_security_event_t));
+  __uint(value_size, sizeof(struct query_bpf_sched_process_fork_event_t));
   __uint(max_entries, 8);
-} bprm_check_security_filters SEC(".maps");
+} sched_process_fork_filters SEC(".maps");
 
 struct {
   __uint(type, BPF_MAP_TYPE_ARRAY);
   __uint(key_size, sizeof(u32));
-  __uint(value_size, sizeof(struct query_bpf_bprm_check_security_event_t));
+  __uint(value_size, sizeof(struct query_bpf_sched_process_fork_event_t));
   __uint(max_entries, 8);
-} bprm_check_security_rejections SEC(".maps");
-INLINE_STATIC int ___test_inode_unlink(
-  struct bpf_inode_unlink_event_t *event,
-  struct query_bpf_inode_unlink_event_t *rule
+} sched_process_fork_rejections SEC(".maps");
+INLINE_STATIC int ___test_sched_process_exit(
+  struct bpf_sched_process_exit_event_t *event,
+  struct query_bpf_sched_process_exit_event_t *rule
 ) {
   int conditional_true = 1;
   if (rule && event) {
@@ -273,20 +1957,35 @@ INLINE_STATIC int ___test_inode_unlink(
     } else if (rule->___absolute == FALSE_ABSOLUTE) {
       return 0;
     } else {
+      if (rule->process.parent.name___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.name,rule->process.parent.name);
+      } else if (rule->process.parent.name___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.name, rule->process.parent.name);
+      }
+      if (rule->process.parent.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.parent.executable,rule->process.parent.executable);
+      } else if (rule->process.parent.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.parent.executable, rule->process.parent.executable);
+      }
       if (rule->process.name___operator == EQUAL_OPERATOR) {
         conditional_true = conditional_true && STRING_EQUALITY(event->process.name,rule->process.name);
       } else if (rule->process.name___operator == NOT_EQUAL_OPERATOR) {
         conditional_true = conditional_true && STRING_INEQUALITY(event->process.name, rule->process.name);
       }
+      if (rule->process.executable___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->process.executable,rule->process.executable);
+      } else if (rule->process.executable___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->process.executable, rule->process.executable);
+      }
       if (rule->user.id___operator == EQUAL_OPERATOR) {
         conditional_true = conditional_true && NUMBER_EQUALITY(event->user.id,rule->user.id);
       } else if (rule->user.id___operator == NOT_EQUAL_OPERATOR) {
         conditional_true = conditional_true && NUMBER_INEQUALITY(event->user.id, rule->user.id);
       }
-      if (rule->file.path___operator == EQUAL_OPERATOR) {
-        conditional_true = conditional_true && STRING_EQUALITY(event->file.path,rule->file.path);
-      } else if (rule->file.path___operator == NOT_EQUAL_OPERATOR) {
-        conditional_true = conditional_true && STRING_INEQUALITY(event->file.path, rule->file.path);
+      if (rule->container.id___operator == EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_EQUALITY(event->container.id,rule->container.id);
+      } else if (rule->container.id___operator == NOT_EQUAL_OPERATOR) {
+        conditional_true = conditional_true && STRING_INEQUALITY(event->container.id, rule->container.id);
       }
     }
   }
@@ -294,10 +1993,10 @@ INLINE_STATIC int ___test_inode_unlink(
   return conditional_true;
 }
 
-INLINE_STATIC int ___check_inode_unlink(
+INLINE_STATIC int ___check_sched_process_exit(
   unsigned int size,
   void *rule_map,
-  struct bpf_inode_unlink_event_t *event
+  struct bpf_sched_process_exit_event_t *event
 ) {
   int conditional_true = 0;
   if (!rule_map) return conditional_true;
@@ -307,8 +2006,8 @@ INLINE_STATIC int ___check_inode_unlink(
     if (index >= size) {
       return conditional_true;
     }
-    struct query_bpf_inode_unlink_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
-    conditional_true = conditional_true || ___test_inode_unlink(event, rule);
+    struct query_bpf_sched_process_exit_event_t *rule = bpf_map_lookup_elem(rule_map, &index);
+    conditional_true = conditional_true || ___test_sched_process_exit(event, rule);
   }
   return conditional_true;
 }
@@ -316,16 +2015,16 @@ INLINE_STATIC int ___check_inode_unlink(
 struct {
   __uint(type, BPF_MAP_TYPE_ARRAY);
   __uint(key_size, sizeof(u32));
-  __uint(value_size, sizeof(struct query_bpf_inode_unlink_event_t));
+  __uint(value_size, sizeof(struct query_bpf_sched_process_exit_event_t));
   __uint(max_entries, 8);
-} inode_unlink_filters SEC(".maps");
+} sched_process_exit_filters SEC(".maps");
 
 struct {
   __uint(type, BPF_MAP_TYPE_ARRAY);
   __uint(key_size, sizeof(u32));
-  __uint(value_size, sizeof(struct query_bpf_inode_unlink_event_t));
+  __uint(value_size, sizeof(struct query_bpf_sched_process_exit_event_t));
   __uint(max_entries, 8);
-} inode_unlink_rejections SEC(".maps");
+} sched_process_exit_rejections SEC(".maps");
 
 #endif
 
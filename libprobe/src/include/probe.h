@@ -25,6 +25,9 @@ struct handlers {
 
 struct state_configuration {
   unsigned char debug;
+  unsigned char capture_command_line;
+  unsigned int env_allowlist_size;
+  struct env_name env_allowlist[MAX_ENV];
   DECLARE_HANDLER_CONFIGURATIONS(EVENT_HOOKS);
 };
 
@@ -40,6 +43,9 @@ struct state *new_state(struct state_configuration config);
 void poll_state(struct state *s, int timeout);
 void cache_process(struct state *s, pid_t pid,
                    const struct cached_process *process);
+void cache_process_details(struct state *s, pid_t pid,
+                           const struct cached_process_details *details);
+unsigned long state_clock_adjustment(struct state *s);
 void destroy_state(struct state *self);
 
 #endif // __PROBE_H
@@ -4,6 +4,7 @@
 // clang-format off
 #include "vmlinux.h"
 #include <bpf/bpf_core_read.h>
+#include <bpf/bpf_endian.h>
 #include <bpf/bpf_tracing.h>
 #include <bpf/bpf_helpers.h>
 // clang-format on
@@ -15,14 +16,27 @@
 char _license[] SEC("license") = "GPL";
 
 const volatile unsigned long clock_adjustment = 0;
+const volatile unsigned char capture_command_line = 0;
+const volatile unsigned int env_allowlist_size = 0;
+const volatile struct env_name env_allowlist[MAX_ENV] = {};
 
 INLINE_STATIC unsigned long adjust_timestamp(unsigned long timestamp) {
   return (timestamp + clock_adjustment) / 1000000000l;
 }
 
+// /proc/<pid>/stat reports start times in USER_HZ clock ticks, truncating to
+// them lets userspace compute the same start for processes that were already
+// running when the probe was loaded
+#define NSEC_PER_USER_HZ_TICK (1000000000l / 100)
+
+INLINE_STATIC unsigned long process_start(unsigned long start_boottime) {
+  return adjust_timestamp(start_boottime -
+                          start_boottime % NSEC_PER_USER_HZ_TICK);
+}
+
 struct {
   __uint(type, BPF_MAP_TYPE_RINGBUF);
-  __uint(max_entries, 256 * 1024);
+  __uint(max_entries, 1024 * 1024);
 } events SEC(".maps");
 
 const struct cached_process empty_cached_process = {};
@@ -58,6 +72,190 @@ INLINE_STATIC void delete_cached_process(struct task_struct *task) {
   bpf_map_delete_elem(&processes, &pid);
 }
 
+const struct cached_process_details empty_cached_process_details = {};
+
+struct {
+  __uint(type, BPF_MAP_TYPE_HASH);
+  __uint(map_flags, BPF_F_NO_PREALLOC);
+  __uint(max_entries, 10240);
+  __type(key, pid_t);
+  __type(value, struct cached_process_details);
+} process_details SEC(".maps");
+
+INLINE_STATIC struct cached_process_details *
+get_or_create_cached_process_details(struct task_struct *task) {
+  pid_t pid = BPF_CORE_READ(task, tgid);
+  bpf_map_update_elem(&process_details, &pid, &empty_cached_process_details,
+                      BPF_NOEXIST);
+  return bpf_map_lookup_elem(&process_details, &pid);
+}
+
+INLINE_STATIC struct cached_process_details *
+get_cached_process_details(struct task_struct *task) {
+  pid_t pid = BPF_CORE_READ(task, tgid);
+  return bpf_map_lookup_elem(&process_details, &pid);
+}
+
+INLINE_STATIC void
+update_cached_process_details(struct task_struct *task,
+                              const struct cached_process_details *p) {
+  pid_t pid = BPF_CORE_READ(task, tgid);
+  bpf_map_update_elem(&process_details, &pid, p, BPF_ANY);
+}
+
+INLINE_STATIC void delete_cached_process_details(struct task_struct *task) {
+  pid_t pid = BPF_CORE_READ(task, tgid);
+  bpf_map_delete_elem(&process_details, &pid);
+}
+
+// arguments are read one at a time into the scratch buffer, the extra
+// space past the command line keeps every read in bounds for the verifier
+#define MAX_ARG_READ_SIZE 1024
+
+struct exec_scratch {
+  char buffer[MAX_COMMAND_LINE_SIZE + MAX_ARG_READ_SIZE];
+  char env[ENVSIZE];
+};
+
+struct {
+  __uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+  __uint(max_entries, 1);
+  __type(key, u32);
+  __type(value, struct exec_scratch);
+} exec_scratches SEC(".maps");
+
+// whether a string that filled a buffer of the given size kept going
+INLINE_STATIC int string_continues(const char *s, unsigned int size) {
+  char last = 0;
+  bpf_probe_read_user(&last, sizeof(last), s + size - 1);
+  return last != '\0';
+}
+
+// returns whether the command line had to be cut short
+INLINE_STATIC int read_command_line(char *x, struct exec_scratch *scratch,
+                                    const char **args) {
+  const char *argp;
+  unsigned int offset = 0;
+  int truncated = 0;
+
+  for (int i = 0; i <= MAX_ARGS; i++) {
+    bpf_probe_read_user(&argp, sizeof(argp), &args[i]);
+    if (!argp)
+      break;
+    if (i == MAX_ARGS) {
+      // there are more arguments than we read
+      truncated = 1;
+      break;
+    }
+    if (offset >= MAX_COMMAND_LINE_SIZE) {
+      offset = MAX_COMMAND_LINE_SIZE;
+      truncated = 1;
+      break;
+    }
+    long size = bpf_probe_read_user_str(
+        &scratch->buffer[offset & (MAX_COMMAND_LINE_SIZE - 1)],
+        MAX_ARG_READ_SIZE, argp);
+    if (size <= 0)
+      break;
+    if (size == MAX_ARG_READ_SIZE && string_continues(argp, MAX_ARG_READ_SIZE))
+      truncated = 1;
+    offset += size;
+    if (offset > MAX_COMMAND_LINE_SIZE) {
+      // the argument ran past the end, it gets terminated below
+      offset = MAX_COMMAND_LINE_SIZE;
+      truncated = 1;
+      break;
+    }
+    // join the arguments with spaces, the last one gets terminated below
+    if (offset - 1 < MAX_COMMAND_LINE_SIZE)
+      scratch->buffer[(offset - 1) & (MAX_COMMAND_LINE_SIZE - 1)] = ' ';
+  }
+
+  if (offset == 0) {
+    x[0] = '\0';
+    return truncated;
+  }
+  scratch->buffer[(offset - 1) & (MAX_COMMAND_LINE_SIZE - 1)] = '\0';
+  bpf_probe_read_kernel_str(x, MAX_COMMAND_LINE_SIZE, scratch->buffer);
+  return truncated;
+}
+
+INLINE_STATIC int env_matches(const char *env, unsigned int index) {
+  unsigned int len = env_allowlist[index].len;
+  for (unsigned int i = 0; i < MAX_ENV_NAME; i++) {
+    if (i == len)
+      return env[i] == '=';
+    if (env[i] != env_allowlist[index].value[i])
+      return 0;
+  }
+  return 0;
+}
+
+#define MAX_ENV_SCAN 128
+
+// flags the environment as truncated when a variable is cut short, or when
+// there are more variables than are scanned or captured
+INLINE_STATIC void read_env(struct cached_process_details *details,
+                            struct exec_scratch *scratch, const char **envp) {
+  const char *envp_entry;
+  details->env_count = 0;
+  details->env_truncated = 0;
+
+  for (int i = 0; i <= MAX_ENV_SCAN; i++) {
+    bpf_probe_read_user(&envp_entry, sizeof(envp_entry), &envp[i]);
+    if (!envp_entry)
+      return;
+    if (i == MAX_ENV_SCAN) {
+      // variables past the scan limit were never looked at
+      details->env_truncated = 1;
+      return;
+    }
+    long size = bpf_probe_read_user_str(scratch->env, ENVSIZE, envp_entry);
+    if (size <= 0)
+      continue;
+    for (unsigned int k = 0; k < MAX_ENV && k < env_allowlist_size; k++) {
+      if (!env_matches(scratch->env, k))
+        continue;
+      unsigned long count = details->env_count;
+      if (count >= MAX_ENV) {
+        details->env_truncated = 1;
+        return;
+      }
+      if (size == ENVSIZE && string_continues(envp_entry, ENVSIZE))
+        details->env_truncated = 1;
+      bpf_probe_read_kernel_str(details->env[count & (MAX_ENV - 1)], ENVSIZE,
+                                scratch->env);
+      details->env_count = count + 1;
+      break;
+    }
+  }
+}
+
+// returns whether the command line was truncated
+INLINE_STATIC int cache_process_details(struct task_struct *task,
+                                        const char **args, const char **envp) {
+  if (!capture_command_line && !env_allowlist_size)
+    return 0;
+
+  u32 zero = 0;
+  struct exec_scratch *scratch = bpf_map_lookup_elem(&exec_scratches, &zero);
+  struct cached_process_details *details =
+      get_or_create_cached_process_details(task);
+  if (!scratch || !details)
+    return 0;
+
+  int truncated = 0;
+  details->command_line[0] = '\0';
+  if (capture_command_line)
+    truncated = read_command_line(details->command_line, scratch, args);
+
+  details->env_count = 0;
+  details->env_truncated = 0;
+  if (env_allowlist_size)
+    read_env(details, scratch, envp);
+  return truncated;
+}
+
 const struct cached_file empty_cached_file = {};
 
 struct {
@@ -77,6 +275,183 @@ INLINE_STATIC struct cached_file *get_cached_file(struct inode *inode) {
   return bpf_inode_storage_get(&files, inode, 0, 0);
 }
 
+#ifndef PROT_EXEC
+#define PROT_EXEC 0x4
+#endif
+
+#ifndef VM_EXEC
+#define VM_EXEC 0x00000004
+#endif
+
+#ifndef AF_INET
+#define AF_INET 2
+#endif
+
+#ifndef AF_INET6
+#define AF_INET6 10
+#endif
+
+// stores an ipv4 address as an ipv4-mapped ipv6 address so that
+// both families fit in the same 16 byte field
+INLINE_STATIC void set_ipv4_mapped(unsigned char *x, __be32 addr) {
+  __builtin_memset(x, 0, 10);
+  x[10] = 0xff;
+  x[11] = 0xff;
+  __builtin_memcpy(&x[12], &addr, 4);
+}
+
+#ifndef container_of
+#define container_of(ptr, type, member)                                        \
+  ((type *)((void *)(ptr)-bpf_core_field_offset(type, member)))
+#endif
+
+#define MAX_PATH_DEPTH 16
+
+struct path_scratch {
+  char buffer[MAX_PATH_SIZE * 2];
+  char component[MAX_PATH_SIZE];
+};
+
+struct {
+  __uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+  __uint(max_entries, 1);
+  __type(key, u32);
+  __type(value, struct path_scratch);
+} path_scratches SEC(".maps");
+
+// prepends "/name" to the path being built backwards in the scratch buffer
+// and returns the new offset, which is unchanged if the name doesn't fit
+INLINE_STATIC unsigned int prepend_path_component(struct path_scratch *scratch,
+                                                  unsigned int offset,
+                                                  const char *name,
+                                                  unsigned int len) {
+  len &= (MAX_PATH_SIZE - 1);
+  if (len + 1 > offset)
+    return offset;
+  unsigned int next = (offset - len - 1) & (MAX_PATH_SIZE - 1);
+  bpf_probe_read_kernel_str(&scratch->buffer[next + 1], len + 1, name);
+  // the string read clobbers the separator of the previous component
+  if (offset != MAX_PATH_SIZE)
+    scratch->buffer[offset & (MAX_PATH_SIZE * 2 - 1)] = '/';
+  scratch->buffer[next] = '/';
+  return next;
+}
+
+INLINE_STATIC void finish_path(char *x, struct path_scratch *scratch,
+                               unsigned int offset) {
+  if (offset == MAX_PATH_SIZE) {
+    x[0] = '/';
+    return;
+  }
+  bpf_probe_read_kernel_str(x, MAX_PATH_SIZE,
+                            &scratch->buffer[offset & (MAX_PATH_SIZE - 1)]);
+}
+
+// bpf_d_path needs a struct path that lives in the kernel, which we
+// don't have for things like unmounts, so walk the dentries by hand
+// and build the path backwards in a per-cpu scratch buffer
+INLINE_STATIC void read_mountpoint_path(char *x, struct mount *mnt) {
+  u32 zero = 0;
+  struct path_scratch *scratch = bpf_map_lookup_elem(&path_scratches, &zero);
+  if (!scratch)
+    return;
+
+  struct dentry *dentry = BPF_CORE_READ(mnt, mnt_mountpoint);
+  struct mount *current_mnt = BPF_CORE_READ(mnt, mnt_parent);
+  unsigned int offset = MAX_PATH_SIZE;
+  scratch->buffer[MAX_PATH_SIZE] = '\0';
+
+#pragma unroll
+  for (int i = 0; i < MAX_PATH_DEPTH; i++) {
+    struct dentry *parent = BPF_CORE_READ(dentry, d_parent);
+    struct dentry *root = BPF_CORE_READ(current_mnt, mnt.mnt_root);
+    if (dentry == root || dentry == parent) {
+      struct mount *parent_mnt = BPF_CORE_READ(current_mnt, mnt_parent);
+      if (current_mnt == parent_mnt)
+        break; // we've hit the root of the mount tree
+      dentry = BPF_CORE_READ(current_mnt, mnt_mountpoint);
+      current_mnt = parent_mnt;
+      continue;
+    }
+    unsigned int next = prepend_path_component(
+        scratch, offset, BPF_CORE_READ(dentry, d_name.name),
+        BPF_CORE_READ(dentry, d_name.len));
+    if (next == offset)
+      break;
+    offset = next;
+    dentry = parent;
+  }
+
+  finish_path(x, scratch, offset);
+}
+
+// same as above, but for the kernfs nodes backing the task's cgroup v2
+INLINE_STATIC void read_cgroup_path(char *x, struct task_struct *task) {
+  u32 zero = 0;
+  struct path_scratch *scratch = bpf_map_lookup_elem(&path_scratches, &zero);
+  if (!scratch)
+    return;
+
+  struct kernfs_node *kn = BPF_CORE_READ(task, cgroups, dfl_cgrp, kn);
+  unsigned int offset = MAX_PATH_SIZE;
+  scratch->buffer[MAX_PATH_SIZE] = '\0';
+
+#pragma unroll
+  for (int i = 0; i < MAX_PATH_DEPTH; i++) {
+    struct kernfs_node *parent = BPF_CORE_READ(kn, parent);
+    if (!parent)
+      break; // the root node doesn't have a name
+    const char *name = BPF_CORE_READ(kn, name);
+    long len = bpf_probe_read_kernel_str(scratch->component, MAX_PATH_SIZE,
+                                         name);
+    if (len <= 1)
+      break;
+    unsigned int next =
+        prepend_path_component(scratch, offset, scratch->component, len - 1);
+    if (next == offset)
+      break;
+    offset = next;
+    kn = parent;
+  }
+
+  finish_path(x, scratch, offset);
+}
+
+#define CONTAINER_ID_SIZE 64
+
+// container runtimes name the cgroup after the container, so the first
+// 64 character hex string in the cgroup path is the container id
+INLINE_STATIC void set_container_id(char *x, const char *path) {
+  unsigned int run = 0;
+  for (unsigned int i = 0; i < MAX_PATH_SIZE; i++) {
+    char c = path[i];
+    if (!c)
+      return;
+    if ((c >= '0' && c <= '9') || (c >= 'a' && c <= 'f')) {
+      run++;
+    } else {
+      run = 0;
+    }
+    if (run == CONTAINER_ID_SIZE) {
+      unsigned int start = (i + 1 - CONTAINER_ID_SIZE) & (MAX_PATH_SIZE - 1);
+      bpf_probe_read_kernel(x, CONTAINER_ID_SIZE, &path[start]);
+      return;
+    }
+  }
+}
+
+// the pid namespace the task itself lives in, nsproxy only has the one its
+// children get, which differs after unshare(CLONE_NEWPID)
+INLINE_STATIC unsigned int pid_namespace(struct task_struct *task) {
+  struct pid *pid = BPF_CORE_READ(task, thread_pid);
+  if (!pid)
+    return 0;
+  unsigned int level = BPF_CORE_READ(pid, level);
+  struct upid upid = {};
+  bpf_core_read(&upid, sizeof(upid), &pid->numbers[level]);
+  return BPF_CORE_READ(upid.ns, ns.inum);
+}
+
 #define TRACEPOINT(family, module, ctx)                                        \
   SEC("tp/" #family "/" #module)                                               \
   static int module##_hook(ctx)
@@ -104,19 +479,65 @@ INLINE_STATIC struct cached_file *get_cached_file(struct inode *inode) {
     SET_STRING(e->event.outcome, success);                                     \
   }
 
+#define __record_success(m, p, e, r)                                           \
+  const char success[] = "success";                                            \
+  const char action[] = "" #p;                                                 \
+  SET_STRING(e->event.action, action);                                         \
+  SET_STRING(e->event.outcome, success);
+
 #define __basic_process_info_for_task(x, task, ...)                            \
   x.pid = BPF_CORE_READ(task, ##__VA_ARGS__, tgid);                            \
   x.thread__id = BPF_CORE_READ(task, ##__VA_ARGS__, pid);                      \
   x.ppid = BPF_CORE_READ(task, ##__VA_ARGS__, real_parent, tgid);              \
-  x.start = adjust_timestamp(BPF_CORE_READ(task, ##__VA_ARGS__, start_time))
+  x.start = process_start(BPF_CORE_READ(task, ##__VA_ARGS__, start_boottime))
 
 #define __copy_cached_process(x, cached)                                       \
   x.args_count = cached->args_count;                                           \
+  x.args_truncated = cached->truncated;                                        \
   memcpy(x.executable, cached->executable, MAX_PATH_SIZE);                     \
   memcpy(x.name, cached->name, MAX_PATH_SIZE);                                 \
   _Pragma("unroll") for (int i = 0; i < MAX_ARGS && i < cached->args_count;    \
                          i++) memcpy(x.args[i], cached->args[i], ARGSIZE)
 
+#define __container_info_for_task(e, task)                                     \
+  e->cgroup.id = bpf_get_current_cgroup_id();                                  \
+  read_cgroup_path(e->cgroup.path, task);                                      \
+  set_container_id(e->container.id, e->cgroup.path);                           \
+  e->namespace.pid = pid_namespace(task);                                      \
+  e->namespace.mnt = BPF_CORE_READ(task, nsproxy, mnt_ns, ns.inum);            \
+  e->namespace.user = BPF_CORE_READ(task, cred, user_ns, ns.inum)
+
+#define __copy_cached_process_details(x, details)                              \
+  bpf_probe_read_kernel(x.command_line, MAX_COMMAND_LINE_SIZE,                 \
+                        details->command_line);                                \
+  x.env_count = details->env_count;                                            \
+  x.env_truncated = details->env_truncated;                                    \
+  bpf_probe_read_kernel(x.env, sizeof(x.env), details->env)
+
+// fills in everything an event knows about the current task
+#define __fill_event_context(e, c)                                             \
+  struct cached_process *cached;                                               \
+  struct cached_process_details *details;                                      \
+  e->__timestamp = adjust_timestamp(bpf_ktime_get_boot_ns());                  \
+  e->user.id = BPF_CORE_READ(c, real_cred, uid.val);                           \
+  e->user.group.id = BPF_CORE_READ(c, real_cred, gid.val);                     \
+  e->user.effective.id = BPF_CORE_READ(c, cred, uid.val);                      \
+  e->user.effective.group.id = BPF_CORE_READ(c, cred, gid.val);                \
+  __container_info_for_task(e, c);                                             \
+                                                                               \
+  __basic_process_info_for_task(e->process, c);                                \
+  if ((cached = get_cached_process(c))) {                                      \
+    __copy_cached_process(e->process, cached);                                 \
+  }                                                                            \
+  if ((details = get_cached_process_details(c))) {                             \
+    __copy_cached_process_details(e->process, details);                        \
+  }                                                                            \
+                                                                               \
+  __basic_process_info_for_task(e->process.parent, c, real_parent);            \
+  if ((cached = get_cached_process(BPF_CORE_READ(c, real_parent)))) {          \
+    __copy_cached_process(e->process.parent, cached);                          \
+  }
+
 #define SLEEPABLE_LSM_HOOK(module, ...)                                        \
   SEC("lsm.s/" #module)                                                        \
   int BPF_PROG(module##_hook, ##__VA_ARGS__)
@@ -125,42 +546,28 @@ INLINE_STATIC struct cached_file *get_cached_file(struct inode *inode) {
   SEC("lsm/" #module)                                                          \
   int BPF_PROG(module##_hook, ##__VA_ARGS__)
 
-#define LSM_HOOK(module, prefix, ...)                                          \
+#define __LSM_HOOK(module, prefix, condition, check, ...)                      \
   INLINE_STATIC int ____##module(unsigned long long *ctx, ##__VA_ARGS__,       \
                                  struct bpf_##module##_event_t *event,         \
                                  struct task_struct *current_task);            \
   SEC("lsm/" #module)                                                          \
   int BPF_PROG(module##_hook, ##__VA_ARGS__) {                                 \
     int __ret = 0;                                                             \
+    if (!(condition))                                                          \
+      return __ret;                                                            \
     struct bpf_event_t *event = bpf_ringbuf_reserve(                           \
         &events, sizeof(struct bpf_event_t), RINGBUFFER_FLAGS);                \
     if (event) {                                                               \
       event->type = type_##module##_event_t;                                   \
       struct bpf_##module##_event_t *e = &event->module##_event_t;             \
       struct task_struct *c = (struct task_struct *)bpf_get_current_task();    \
-      struct cached_process *cached;                                           \
-                                                                               \
-      e->__timestamp = adjust_timestamp(bpf_ktime_get_boot_ns());              \
-      e->user.id = BPF_CORE_READ(c, real_cred, uid.val);                       \
-      e->user.group.id = BPF_CORE_READ(c, real_cred, gid.val);                 \
-      e->user.effective.id = BPF_CORE_READ(c, cred, uid.val);                  \
-      e->user.effective.group.id = BPF_CORE_READ(c, cred, gid.val);            \
-                                                                               \
-      __basic_process_info_for_task(e->process, c);                            \
-      if ((cached = get_cached_process(c))) {                                  \
-        __copy_cached_process(e->process, cached);                             \
-      }                                                                        \
-                                                                               \
-      __basic_process_info_for_task(e->process.parent, c, real_parent);        \
-      if ((cached = get_cached_process(BPF_CORE_READ(c, real_parent)))) {      \
-        __copy_cached_process(e->process.parent, cached);                      \
-      }                                                                        \
+      __fill_event_context(e, c);                                              \
                                                                                \
       _Pragma("GCC diagnostic push")                                           \
           _Pragma("GCC diagnostic ignored \"-Wint-conversion\"") __ret =       \
               ____##module(___bpf_ctx_cast(__VA_ARGS__), e, c);                \
       _Pragma("GCC diagnostic pop")                                            \
-          __check_rejection_filter(module, prefix, e, __ret);                  \
+          check(module, prefix, e, __ret);                                     \
       bpf_ringbuf_submit(event, RINGBUFFER_FLAGS);                             \
     }                                                                          \
     return __ret;                                                              \
@@ -169,9 +576,53 @@ INLINE_STATIC struct cached_file *get_cached_file(struct inode *inode) {
                           struct bpf_##module##_event_t *event,                \
                           struct task_struct *current_task)
 
+// only emits an event and checks rejection rules when condition holds, the
+// condition can reference any of the hook's arguments
+#define CONDITIONAL_LSM_HOOK(module, prefix, condition, ...)                   \
+  __LSM_HOOK(module, prefix, condition, __check_rejection_filter,              \
+             ##__VA_ARGS__)
+
+#define LSM_HOOK(module, prefix, ...)                                          \
+  CONDITIONAL_LSM_HOOK(module, prefix, 1, ##__VA_ARGS__)
+
+// hooks that return void can't deny anything, so they skip the rejection
+// rules entirely and always record the action as successful
+#define CONDITIONAL_VOID_LSM_HOOK(module, prefix, condition, ...)              \
+  __LSM_HOOK(module, prefix, condition, __record_success, ##__VA_ARGS__)
+
 #define COMPLETE_LSM_HOOK(module, prefix, ...)                                 \
   LSM_HOOK(module, prefix, ##__VA_ARGS__) { return 0; }
 
+// tracepoints can't deny anything either, they just report what happened
+#define CONDITIONAL_EVENT_TRACEPOINT(module, prefix, condition, ...)           \
+  INLINE_STATIC int ____##module(unsigned long long *ctx, ##__VA_ARGS__,       \
+                                 struct bpf_##module##_event_t *event,         \
+                                 struct task_struct *current_task);            \
+  SEC("tp_btf/" #module)                                                       \
+  int BPF_PROG(module##_hook, ##__VA_ARGS__) {                                 \
+    if (!(condition))                                                          \
+      return 0;                                                                \
+    struct bpf_event_t *event = bpf_ringbuf_reserve(                           \
+        &events, sizeof(struct bpf_event_t), RINGBUFFER_FLAGS);                \
+    if (event) {                                                               \
+      event->type = type_##module##_event_t;                                   \
+      struct bpf_##module##_event_t *e = &event->module##_event_t;             \
+      struct task_struct *c = (struct task_struct *)bpf_get_current_task();    \
+      __fill_event_context(e, c);                                              \
+                                                                               \
+      _Pragma("GCC diagnostic push")                                           \
+          _Pragma("GCC diagnostic ignored \"-Wint-conversion\"")               \
+              ____##module(___bpf_ctx_cast(__VA_ARGS__), e, c);                \
+      _Pragma("GCC diagnostic pop")                                            \
+          __record_success(module, prefix, e, 0);                              \
+      bpf_ringbuf_submit(event, RINGBUFFER_FLAGS);                             \
+    }                                                                          \
+    return 0;                                                                  \
+  }                                                                            \
+  static int ____##module(unsigned long long *ctx, ##__VA_ARGS__,              \
+                          struct bpf_##module##_event_t *event,                \
+                          struct task_struct *current_task)
+
 // call this at the beginning of a hook to make the verifier happy
 #define initialize_event()                                                     \
   if (!event)                                                                  \
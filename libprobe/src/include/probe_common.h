@@ -52,6 +52,10 @@ INLINE_STATIC int ___strncmp(const char *x, const char *y, unsigned int len) {
 #define MAX_PATH_SIZE 256
 #define MAX_ARGS 64
 #define ARGSIZE 128
+#define MAX_COMMAND_LINE_SIZE 4096
+#define MAX_ENV 8
+#define ENVSIZE 256
+#define MAX_ENV_NAME 64
 
 struct cached_process {
   char name[MAX_PATH_SIZE];
@@ -61,6 +65,20 @@ struct cached_process {
   int truncated;
 };
 
+// only populated when the probe is configured to capture
+// full command lines or environment variables
+struct cached_process_details {
+  char command_line[MAX_COMMAND_LINE_SIZE];
+  char env[MAX_ENV][ENVSIZE];
+  unsigned long env_count;
+  int env_truncated;
+};
+
+struct env_name {
+  char value[MAX_ENV_NAME];
+  unsigned int len;
+};
+
 struct cached_file {
   char path[MAX_PATH_SIZE];
 };
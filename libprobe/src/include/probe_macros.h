@@ -5,6 +5,7 @@
  * general macros for variadic expansions
  */
 #define GET_MACRO(_0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13,  \
+                  _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24,       \
                   NAME, ...)                                                   \
   NAME
 
@@ -22,9 +23,21 @@
 #define FE_11(WHAT, _ctx, ...) WHAT(_ctx) FE_10(WHAT, __VA_ARGS__)
 #define FE_12(WHAT, _ctx, ...) WHAT(_ctx) FE_11(WHAT, __VA_ARGS__)
 #define FE_13(WHAT, _ctx, ...) WHAT(_ctx) FE_12(WHAT, __VA_ARGS__)
+#define FE_14(WHAT, _ctx, ...) WHAT(_ctx) FE_13(WHAT, __VA_ARGS__)
+#define FE_15(WHAT, _ctx, ...) WHAT(_ctx) FE_14(WHAT, __VA_ARGS__)
+#define FE_16(WHAT, _ctx, ...) WHAT(_ctx) FE_15(WHAT, __VA_ARGS__)
+#define FE_17(WHAT, _ctx, ...) WHAT(_ctx) FE_16(WHAT, __VA_ARGS__)
+#define FE_18(WHAT, _ctx, ...) WHAT(_ctx) FE_17(WHAT, __VA_ARGS__)
+#define FE_19(WHAT, _ctx, ...) WHAT(_ctx) FE_18(WHAT, __VA_ARGS__)
+#define FE_20(WHAT, _ctx, ...) WHAT(_ctx) FE_19(WHAT, __VA_ARGS__)
+#define FE_21(WHAT, _ctx, ...) WHAT(_ctx) FE_20(WHAT, __VA_ARGS__)
+#define FE_22(WHAT, _ctx, ...) WHAT(_ctx) FE_21(WHAT, __VA_ARGS__)
+#define FE_23(WHAT, _ctx, ...) WHAT(_ctx) FE_22(WHAT, __VA_ARGS__)
+#define FE_24(WHAT, _ctx, ...) WHAT(_ctx) FE_23(WHAT, __VA_ARGS__)
 #define FOR_EACH0(action, ...)                                                 \
-  GET_MACRO(_0, __VA_ARGS__, FE_13, FE_12, FE_11, FE_10, FE_9, FE_8, FE_7,     \
-            FE_6, FE_5, FE_4, FE_3, FE_2, FE_1, FE_0)                          \
+  GET_MACRO(_0, __VA_ARGS__, FE_24, FE_23, FE_22, FE_21, FE_20, FE_19, FE_18,  \
+            FE_17, FE_16, FE_15, FE_14, FE_13, FE_12, FE_11, FE_10, FE_9,      \
+            FE_8, FE_7, FE_6, FE_5, FE_4, FE_3, FE_2, FE_1, FE_0)              \
   (action, __VA_ARGS__)
 
 #define FE1_0(WHAT)
@@ -45,9 +58,33 @@
   WHAT(_ctx, _x) FE1_11(WHAT, _ctx, __VA_ARGS__)
 #define FE1_13(WHAT, _ctx, _x, ...)                                            \
   WHAT(_ctx, _x) FE1_12(WHAT, _ctx, __VA_ARGS__)
+#define FE1_14(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_13(WHAT, _ctx, __VA_ARGS__)
+#define FE1_15(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_14(WHAT, _ctx, __VA_ARGS__)
+#define FE1_16(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_15(WHAT, _ctx, __VA_ARGS__)
+#define FE1_17(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_16(WHAT, _ctx, __VA_ARGS__)
+#define FE1_18(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_17(WHAT, _ctx, __VA_ARGS__)
+#define FE1_19(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_18(WHAT, _ctx, __VA_ARGS__)
+#define FE1_20(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_19(WHAT, _ctx, __VA_ARGS__)
+#define FE1_21(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_20(WHAT, _ctx, __VA_ARGS__)
+#define FE1_22(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_21(WHAT, _ctx, __VA_ARGS__)
+#define FE1_23(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_22(WHAT, _ctx, __VA_ARGS__)
+#define FE1_24(WHAT, _ctx, _x, ...)                                            \
+  WHAT(_ctx, _x) FE1_23(WHAT, _ctx, __VA_ARGS__)
 #define FOR_EACH1(action, _ctx, ...)                                           \
-  GET_MACRO(_0, __VA_ARGS__, FE1_13, FE1_12, FE1_11, FE1_10, FE1_9, FE1_8,     \
-            FE1_7, FE1_6, FE1_5, FE1_4, FE1_3, FE1_2, FE1_1, FE1_0)            \
+  GET_MACRO(_0, __VA_ARGS__, FE1_24, FE1_23, FE1_22, FE1_21, FE1_20, FE1_19,   \
+            FE1_18, FE1_17, FE1_16, FE1_15, FE1_14, FE1_13, FE1_12, FE1_11,    \
+            FE1_10, FE1_9, FE1_8, FE1_7, FE1_6, FE1_5, FE1_4, FE1_3, FE1_2,    \
+            FE1_1, FE1_0)                                                      \
   (action, _ctx, __VA_ARGS__)
 
 #define FE2_0(WHAT)
@@ -76,9 +113,33 @@
   WHAT(_ctx, _x, _y) FE2_11(WHAT, _ctx, _x, __VA_ARGS__)
 #define FE2_13(WHAT, _ctx, _x, _y, ...)                                        \
   WHAT(_ctx, _x, _y) FE2_12(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_14(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_13(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_15(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_14(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_16(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_15(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_17(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_16(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_18(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_17(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_19(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_18(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_20(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_19(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_21(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_20(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_22(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_21(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_23(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_22(WHAT, _ctx, _x, __VA_ARGS__)
+#define FE2_24(WHAT, _ctx, _x, _y, ...)                                        \
+  WHAT(_ctx, _x, _y) FE2_23(WHAT, _ctx, _x, __VA_ARGS__)
 #define FOR_EACH2(action, _ctx, _x, ...)                                       \
-  GET_MACRO(_0, __VA_ARGS__, FE2_13, FE2_12, FE2_11, FE2_10, FE2_9, FE2_8,     \
-            FE2_7, FE2_6, FE2_5, FE2_4, FE2_3, FE2_2, FE2_1, FE2_0)            \
+  GET_MACRO(_0, __VA_ARGS__, FE2_24, FE2_23, FE2_22, FE2_21, FE2_20, FE2_19,   \
+            FE2_18, FE2_17, FE2_16, FE2_15, FE2_14, FE2_13, FE2_12, FE2_11,    \
+            FE2_10, FE2_9, FE2_8, FE2_7, FE2_6, FE2_5, FE2_4, FE2_3, FE2_2,    \
+            FE2_1, FE2_0)                                                      \
   (action, _ctx, _x, __VA_ARGS__)
 
 /*
@@ -13,6 +13,11 @@ __attribute__((always_inline)) int fork_trace(void *ctx) {
   if (parent && !child) { // we are in the child process
     // update the current task with the parent information
     update_cached_process(current_task, parent);
+    struct cached_process_details *parent_details =
+        get_cached_process_details(BPF_CORE_READ(current_task, real_parent));
+    if (parent_details) {
+      update_cached_process_details(current_task, parent_details);
+    }
   }
   return 0;
 }
@@ -36,12 +41,15 @@ TRACEPOINT(syscalls, sys_enter_execve, struct trace_event_raw_sys_enter *ctx) {
 
   const char *executable = (const char *)(ctx->args[0]);
   const char **args = (const char **)(ctx->args[1]);
+  const char **envp = (const char **)(ctx->args[2]);
   if (executable) {
     bpf_probe_read_user_str(&cached->executable, MAX_PATH_SIZE, executable);
     set_basename(cached->name, cached->executable, MAX_PATH_SIZE);
   }
 
   unsigned long argc = 0;
+  char last;
+  cached->truncated = 0;
 
 #pragma unroll
   for (int i = 0; i < MAX_ARGS; i++) {
@@ -50,7 +58,13 @@ TRACEPOINT(syscalls, sys_enter_execve, struct trace_event_raw_sys_enter *ctx) {
     bpf_probe_read_user(&argp, sizeof(argp), &args[i]);
     if (!argp)
       goto done;
-    bpf_probe_read_user_str(&cached->args[i], ARGSIZE, argp);
+    long size = bpf_probe_read_user_str(&cached->args[i], ARGSIZE, argp);
+    if (size == ARGSIZE) {
+      // filled the whole buffer, check if the argument kept going
+      bpf_probe_read_user(&last, sizeof(last), argp + ARGSIZE - 1);
+      if (last)
+        cached->truncated = 1;
+    }
     argc++;
   }
   /* try to read one more argument to check if there is one */
@@ -63,6 +77,8 @@ TRACEPOINT(syscalls, sys_enter_execve, struct trace_event_raw_sys_enter *ctx) {
 
 done:
   cached->args_count = argc;
+  if (cache_process_details(current_task, args, envp))
+    cached->truncated = 1;
   return 0;
 }
 
@@ -70,11 +86,50 @@ TRACEPOINT(sched, sched_process_free, void *ctx) {
   struct task_struct *current_task =
       (struct task_struct *)bpf_get_current_task();
   delete_cached_process(current_task);
+  delete_cached_process_details(current_task);
   return 0;
 }
 
 COMPLETE_LSM_HOOK(bprm_check_security, execution, struct linux_binprm *bprm)
 
+//  Process lifecycle events, these fire for threads too so we only report
+//  new thread groups and the exit of the last thread in a group.
+
+CONDITIONAL_EVENT_TRACEPOINT(sched_process_fork, fork,
+                             child->pid == child->tgid,
+                             struct task_struct *parent,
+                             struct task_struct *child) {
+  initialize_event();
+  // the child hasn't been cached yet, but it shares everything but its
+  // identity with the process that forked it
+  __basic_process_info_for_task(event->process, child);
+  __builtin_memset(&event->process.parent, 0, sizeof(event->process.parent));
+  __basic_process_info_for_task(event->process.parent, current_task);
+  struct cached_process *cached = get_cached_process(current_task);
+  if (cached) {
+    __copy_cached_process(event->process.parent, cached);
+  }
+  return 0;
+}
+
+CONDITIONAL_EVENT_TRACEPOINT(sched_process_exit, exit,
+                             BPF_CORE_READ(p, signal, live.counter) == 0,
+                             struct task_struct *p) {
+  initialize_event();
+  int code = BPF_CORE_READ(p, exit_code);
+  if (code & 0x7f) {
+    event->process.exit_code = 128 + (code & 0x7f);
+  } else {
+    event->process.exit_code = (code >> 8) & 0xff;
+  }
+  event->process.end = event->__timestamp;
+  // the last thread to exit isn't necessarily the one that started the
+  // process, so measure from when the group leader started
+  event->event.duration =
+      bpf_ktime_get_ns() - BPF_CORE_READ(p, group_leader, start_time);
+  return 0;
+}
+
 // this isn't the greatest place to capture paths, but generally there's
 // an inode permission check some time prior to unlinking the file
 NOEVENT_LSM_HOOK(inode_getattr, const struct path *path) {
@@ -93,3 +148,110 @@ LSM_HOOK(inode_unlink, unlink, struct inode *dir, struct dentry *victim) {
   }
   return 0;
 }
+
+//  Security hooks for filesystem operations.
+
+LSM_HOOK(sb_mount, mount, const char *dev_name, const struct path *path,
+         const char *type, unsigned long flags, void *data) {
+  initialize_event();
+  if (dev_name) {
+    bpf_probe_read_kernel_str(event->mount.source, MAX_PATH_SIZE, dev_name);
+  }
+  if (type) {
+    bpf_probe_read_kernel_str(event->mount.fstype, MAX_PATH_SIZE, type);
+  }
+  bpf_d_path((struct path *)path, event->mount.target, MAX_PATH_SIZE);
+  event->mount.flags = flags;
+  return 0;
+}
+
+LSM_HOOK(sb_umount, unmount, struct vfsmount *mnt, int flags) {
+  initialize_event();
+  struct mount *m = container_of(mnt, struct mount, mnt);
+  bpf_probe_read_kernel_str(event->mount.source, MAX_PATH_SIZE,
+                            BPF_CORE_READ(m, mnt_devname));
+  bpf_probe_read_kernel_str(event->mount.fstype, MAX_PATH_SIZE,
+                            BPF_CORE_READ(mnt, mnt_sb, s_type, name));
+  read_mountpoint_path(event->mount.target, m);
+  event->mount.flags = flags;
+  return 0;
+}
+
+//  Security hooks for task operations.
+
+LSM_HOOK(task_kill, kill, struct task_struct *p, struct kernel_siginfo *info,
+         int sig, const struct cred *cred) {
+  initialize_event();
+  event->signal.number = sig;
+
+  __basic_process_info_for_task(event->target.process, p);
+  struct cached_process *cached = get_cached_process(p);
+  if (cached) {
+    __copy_cached_process(event->target.process, cached);
+  } else {
+    // we haven't seen this process exec, so fall back to the task's comm
+    bpf_probe_read_kernel_str(event->target.process.name, sizeof(p->comm),
+                              p->comm);
+  }
+  return 0;
+}
+
+//  Security hooks for memory mapping operations, we only care about memory
+//  that is becoming executable.
+
+CONDITIONAL_LSM_HOOK(mmap_file, mmap, prot & PROT_EXEC, struct file *file,
+                     unsigned long reqprot, unsigned long prot,
+                     unsigned long flags) {
+  initialize_event();
+  event->memory.protection = prot;
+  event->memory.flags = flags;
+  event->memory.anonymous = !file;
+  if (file) {
+    bpf_d_path(&file->f_path, event->file.path, MAX_PATH_SIZE);
+    event->file.inode = file->f_inode->i_ino;
+  }
+  return 0;
+}
+
+CONDITIONAL_LSM_HOOK(file_mprotect, mprotect,
+                     (prot & PROT_EXEC) && !(vma->vm_flags & VM_EXEC),
+                     struct vm_area_struct *vma, unsigned long reqprot,
+                     unsigned long prot) {
+  initialize_event();
+  struct file *file = vma->vm_file;
+  event->memory.protection = prot;
+  event->memory.address = vma->vm_start;
+  event->memory.size = vma->vm_end - vma->vm_start;
+  event->memory.anonymous = !file;
+  if (file) {
+    bpf_d_path(&file->f_path, event->file.path, MAX_PATH_SIZE);
+    event->file.inode = file->f_inode->i_ino;
+  }
+  return 0;
+}
+
+//  Security hooks for socket operations. sock_graft is called when an
+//  accepted socket gets attached to the accepting process, which is the
+//  first point where we have both the remote endpoint and the right process
+//  context. It can't deny the connection since it returns void.
+
+CONDITIONAL_VOID_LSM_HOOK(sock_graft, accept,
+                          sk->__sk_common.skc_family == AF_INET ||
+                              sk->__sk_common.skc_family == AF_INET6,
+                          struct sock *sk, struct socket *parent) {
+  initialize_event();
+  event->network.iana_number = BPF_CORE_READ(sk, sk_protocol);
+  event->source.port = bpf_ntohs(BPF_CORE_READ(sk, __sk_common.skc_dport));
+  event->destination.port = BPF_CORE_READ(sk, __sk_common.skc_num);
+  if (BPF_CORE_READ(sk, __sk_common.skc_family) == AF_INET) {
+    set_ipv4_mapped(event->source.ip, BPF_CORE_READ(sk, __sk_common.skc_daddr));
+    set_ipv4_mapped(event->destination.ip,
+                    BPF_CORE_READ(sk, __sk_common.skc_rcv_saddr));
+  } else {
+    BPF_CORE_READ_INTO(&event->source.ip, sk,
+                       __sk_common.skc_v6_daddr.in6_u.u6_addr8);
+    BPF_CORE_READ_INTO(&event->destination.ip, sk,
+                       __sk_common.skc_v6_rcv_saddr.in6_u.u6_addr8);
+  }
+  return 0;
+}
@@ -74,6 +74,10 @@ struct state *new_state(struct state_configuration config) {
     goto cleanup;
   }
   s->obj->rodata->clock_adjustment = get_clock_offset();
+  s->obj->rodata->capture_command_line = config.capture_command_line;
+  s->obj->rodata->env_allowlist_size = config.env_allowlist_size;
+  memcpy(s->obj->rodata->env_allowlist, config.env_allowlist,
+         sizeof(config.env_allowlist));
 
   SET_HANDLER_CONTEXTS(s, config, EVENT_HOOKS)
 
@@ -112,6 +116,16 @@ void cache_process(struct state *s, pid_t pid,
                       BPF_ANY);
 }
 
+void cache_process_details(struct state *s, pid_t pid,
+                           const struct cached_process_details *details) {
+  bpf_map_update_elem(bpf_map__fd(s->obj->maps.process_details), &pid, details,
+                      BPF_ANY);
+}
+
+unsigned long state_clock_adjustment(struct state *s) {
+  return s->obj->rodata->clock_adjustment;
+}
+
 DECLARE_RULE_FLUSHERS(EVENT_HOOKS);
 
 void destroy_state(struct state *s) {
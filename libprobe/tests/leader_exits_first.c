@@ -0,0 +1,20 @@
+// A process whose main thread exits half a second before its only other
+// thread, which was started half a second after the process, so the
+// process lives for about a second but its last thread for half of that.
+#include <pthread.h>
+#include <stdlib.h>
+#include <unistd.h>
+
+static void *worker(void *arg) {
+  (void)arg;
+  usleep(500000);
+  exit(0);
+}
+
+int main(void) {
+  pthread_t thread;
+  usleep(500000);
+  if (pthread_create(&thread, NULL, worker, NULL))
+    return 1;
+  pthread_exit(NULL);
+}
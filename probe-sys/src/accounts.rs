@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::time::{Duration, Instant};
+
+const PASSWD: &str = "/etc/passwd";
+const GROUP: &str = "/etc/group";
+
+// statting the account databases on every lookup would add two syscalls per
+// name we resolve, so only look for changes this often
+const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+// ids that don't resolve are usually transient (i.e. a user that's about to
+// be added), but if they never resolve we don't want to hit NSS every event
+const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(30);
+const MAX_CACHED_ACCOUNTS: usize = 1 << 14;
+
+/// Where user and group names are looked up.
+pub trait AccountSource: Send {
+    fn user_name(&self, uid: u32) -> Option<String>;
+    fn group_name(&self, gid: u32) -> Option<String>;
+    /// Whether any names may have changed since the last call.
+    fn changed(&mut self) -> bool;
+}
+
+/// Resolves names through NSS and watches /etc/passwd and /etc/group for
+/// changes.
+#[derive(Default)]
+pub struct SystemAccounts {
+    versions: Option<Vec<(u64, u64, i64, i64)>>,
+}
+
+impl SystemAccounts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn file_version(path: &str) -> (u64, u64, i64, i64) {
+    // a missing file is a version too, tools like useradd replace the
+    // databases by renaming over them so the inode is part of the version
+    fs::metadata(path).map_or((0, 0, 0, 0), |m| {
+        (m.ino(), m.size(), m.mtime(), m.mtime_nsec())
+    })
+}
+
+impl AccountSource for SystemAccounts {
+    fn user_name(&self, uid: u32) -> Option<String> {
+        users::get_user_by_uid(uid).map(|u| u.name().to_string_lossy().to_string())
+    }
+
+    fn group_name(&self, gid: u32) -> Option<String> {
+        users::get_group_by_gid(gid).map(|g| g.name().to_string_lossy().to_string())
+    }
+
+    fn changed(&mut self) -> bool {
+        let versions = Some(vec![file_version(PASSWD), file_version(GROUP)]);
+        if self.versions == versions {
+            return false;
+        }
+        let first_check = self.versions.is_none();
+        self.versions = versions;
+        !first_check
+    }
+}
+
+struct CachedName {
+    name: Option<String>,
+    cached_at: Instant,
+}
+
+/// A user and group name cache that's flushed whenever its source changes.
+/// Names that resolve are kept until then, ids that don't resolve are retried
+/// once the negative TTL expires.
+pub struct AccountCache<S: AccountSource> {
+    source: S,
+    users: HashMap<u32, CachedName>,
+    groups: HashMap<u32, CachedName>,
+    negative_ttl: Duration,
+    check_interval: Duration,
+    last_check: Option<Instant>,
+}
+
+impl<S: AccountSource> AccountCache<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            users: HashMap::new(),
+            groups: HashMap::new(),
+            negative_ttl: DEFAULT_NEGATIVE_TTL,
+            check_interval: DEFAULT_CHECK_INTERVAL,
+            last_check: None,
+        }
+    }
+
+    pub fn negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = ttl;
+        self
+    }
+
+    pub fn check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+
+    pub fn user_name(&mut self, uid: u32) -> Option<String> {
+        self.check_source();
+        let source = &self.source;
+        lookup(&mut self.users, uid, self.negative_ttl, |id| {
+            source.user_name(id)
+        })
+    }
+
+    pub fn group_name(&mut self, gid: u32) -> Option<String> {
+        self.check_source();
+        let source = &self.source;
+        lookup(&mut self.groups, gid, self.negative_ttl, |id| {
+            source.group_name(id)
+        })
+    }
+
+    fn check_source(&mut self) {
+        let now = Instant::now();
+        if let Some(last_check) = self.last_check {
+            if now.duration_since(last_check) < self.check_interval {
+                return;
+            }
+        }
+        self.last_check = Some(now);
+        if self.source.changed() {
+            self.users.clear();
+            self.groups.clear();
+        }
+    }
+}
+
+fn lookup<F>(
+    cache: &mut HashMap<u32, CachedName>,
+    id: u32,
+    negative_ttl: Duration,
+    resolve: F,
+) -> Option<String>
+where
+    F: Fn(u32) -> Option<String>,
+{
+    let now = Instant::now();
+    if let Some(cached) = cache.get(&id) {
+        if cached.name.is_some() || now.duration_since(cached.cached_at) < negative_ttl {
+            return cached.name.clone();
+        }
+    }
+    if cache.len() >= MAX_CACHED_ACCOUNTS {
+        cache.clear();
+    }
+    let name = resolve(id);
+    cache.insert(
+        id,
+        CachedName {
+            name: name.clone(),
+            cached_at: now,
+        },
+    );
+    name
+}
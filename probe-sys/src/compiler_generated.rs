@@ -52,10 +52,23 @@ impl Default for query_bpf_bprm_check_security_event_user_t {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_bprm_check_security_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_bprm_check_security_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct query_bpf_bprm_check_security_event_t {
     pub ___absolute: u8,
     pub process: query_bpf_bprm_check_security_event_process_t,
     pub user: query_bpf_bprm_check_security_event_user_t,
+    pub container: query_bpf_bprm_check_security_event_container_t,
 }
 
 impl Default for query_bpf_bprm_check_security_event_t {
@@ -152,6 +165,21 @@ impl QueryStruct for query_bpf_bprm_check_security_event_t {
                     Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
                 }
             },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
             _ => Err(format!("string field named {} not found in schema", path)),
         }
     }
@@ -198,11 +226,24 @@ impl Default for query_bpf_inode_unlink_event_file_t {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_inode_unlink_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_inode_unlink_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct query_bpf_inode_unlink_event_t {
     pub ___absolute: u8,
     pub process: query_bpf_inode_unlink_event_process_t,
     pub user: query_bpf_inode_unlink_event_user_t,
     pub file: query_bpf_inode_unlink_event_file_t,
+    pub container: query_bpf_inode_unlink_event_container_t,
 }
 
 impl Default for query_bpf_inode_unlink_event_t {
@@ -269,6 +310,21 @@ impl QueryStruct for query_bpf_inode_unlink_event_t {
                     Err(format!("file.path is too long, maximum 256 characters, given value is {} characters", value.len()))
                 }
             },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
             _ => Err(format!("string field named {} not found in schema", path)),
         }
     }
@@ -277,25 +333,1822 @@ impl QueryStruct for query_bpf_inode_unlink_event_t {
         Ok(())
     }
 }
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_mount_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
 
-pub struct BpfQueryWriter<'a> {
-    table: String,
-    write_query_bprm_check_security_event_t: InnerBpfQueryWriter<query_bpf_bprm_check_security_event_t>,
-    write_query_inode_unlink_event_t: InnerBpfQueryWriter<query_bpf_inode_unlink_event_t>,
-    probe: Option<&'a super::Probe<'a>>,
+impl Default for query_bpf_sb_mount_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_mount_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_sb_mount_event_process_parent_t,
 }
 
-impl<'a> BpfQueryWriter<'a> {
-    pub fn new(probe: Option<&'a super::Probe>, table: String, operation: Operation) -> Self {
-        Self {
-            table: table,
-            write_query_bprm_check_security_event_t: InnerBpfQueryWriter::<query_bpf_bprm_check_security_event_t>::new(
-                "bprm_check_security".into(),
-                operation,
-                8,
-            ),
-            write_query_inode_unlink_event_t: InnerBpfQueryWriter::<query_bpf_inode_unlink_event_t>::new(
-                "inode_unlink".into(),
+impl Default for query_bpf_sb_mount_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_mount_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_sb_mount_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_mount_event_mount_t {
+    pub target___operator: u8,
+    pub target: [c_char; 256],
+    pub fstype___operator: u8,
+    pub fstype: [c_char; 256],
+}
+
+impl Default for query_bpf_sb_mount_event_mount_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_mount_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_sb_mount_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_mount_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_sb_mount_event_process_t,
+    pub user: query_bpf_sb_mount_event_user_t,
+    pub mount: query_bpf_sb_mount_event_mount_t,
+    pub container: query_bpf_sb_mount_event_container_t,
+}
+
+impl Default for query_bpf_sb_mount_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_sb_mount_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "mount.target" => {
+                if self.mount.target___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.mount.target.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.mount.target___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("mount.target is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "mount.fstype" => {
+                if self.mount.fstype___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.mount.fstype.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.mount.fstype___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("mount.fstype is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_umount_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_sb_umount_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_umount_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_sb_umount_event_process_parent_t,
+}
+
+impl Default for query_bpf_sb_umount_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_umount_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_sb_umount_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_umount_event_mount_t {
+    pub target___operator: u8,
+    pub target: [c_char; 256],
+    pub fstype___operator: u8,
+    pub fstype: [c_char; 256],
+}
+
+impl Default for query_bpf_sb_umount_event_mount_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_umount_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_sb_umount_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sb_umount_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_sb_umount_event_process_t,
+    pub user: query_bpf_sb_umount_event_user_t,
+    pub mount: query_bpf_sb_umount_event_mount_t,
+    pub container: query_bpf_sb_umount_event_container_t,
+}
+
+impl Default for query_bpf_sb_umount_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_sb_umount_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "mount.target" => {
+                if self.mount.target___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.mount.target.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.mount.target___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("mount.target is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "mount.fstype" => {
+                if self.mount.fstype___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.mount.fstype.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.mount.fstype___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("mount.fstype is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_task_kill_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_task_kill_event_process_parent_t,
+}
+
+impl Default for query_bpf_task_kill_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_task_kill_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_signal_t {
+    pub number___operator: u8,
+    pub number: u32,
+}
+
+impl Default for query_bpf_task_kill_event_signal_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_target_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_task_kill_event_target_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_target_t {
+    pub process: query_bpf_task_kill_event_target_process_t,
+}
+
+impl Default for query_bpf_task_kill_event_target_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_task_kill_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_task_kill_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_task_kill_event_process_t,
+    pub user: query_bpf_task_kill_event_user_t,
+    pub signal: query_bpf_task_kill_event_signal_t,
+    pub target: query_bpf_task_kill_event_target_t,
+    pub container: query_bpf_task_kill_event_container_t,
+}
+
+impl Default for query_bpf_task_kill_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_task_kill_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            "signal.number" => {
+                if self.signal.number___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("signal.number must be a u32"))?;
+                self.signal.number = v;
+                self.signal.number___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "target.process.name" => {
+                if self.target.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.target.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.target.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("target.process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "target.process.executable" => {
+                if self.target.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.target.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.target.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("target.process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_mmap_file_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_mmap_file_event_process_parent_t,
+}
+
+impl Default for query_bpf_mmap_file_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_mmap_file_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_file_t {
+    pub path___operator: u8,
+    pub path: [c_char; 256],
+}
+
+impl Default for query_bpf_mmap_file_event_file_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_memory_t {
+    pub anonymous___operator: u8,
+    pub anonymous: u32,
+}
+
+impl Default for query_bpf_mmap_file_event_memory_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_mmap_file_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_mmap_file_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_mmap_file_event_process_t,
+    pub user: query_bpf_mmap_file_event_user_t,
+    pub file: query_bpf_mmap_file_event_file_t,
+    pub memory: query_bpf_mmap_file_event_memory_t,
+    pub container: query_bpf_mmap_file_event_container_t,
+}
+
+impl Default for query_bpf_mmap_file_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_mmap_file_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            "memory.anonymous" => {
+                if self.memory.anonymous___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("memory.anonymous must be a u32"))?;
+                self.memory.anonymous = v;
+                self.memory.anonymous___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "file.path" => {
+                if self.file.path___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.file.path.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.file.path___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("file.path is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_file_mprotect_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_file_mprotect_event_process_parent_t,
+}
+
+impl Default for query_bpf_file_mprotect_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_file_mprotect_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_file_t {
+    pub path___operator: u8,
+    pub path: [c_char; 256],
+}
+
+impl Default for query_bpf_file_mprotect_event_file_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_memory_t {
+    pub anonymous___operator: u8,
+    pub anonymous: u32,
+}
+
+impl Default for query_bpf_file_mprotect_event_memory_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_file_mprotect_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_file_mprotect_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_file_mprotect_event_process_t,
+    pub user: query_bpf_file_mprotect_event_user_t,
+    pub file: query_bpf_file_mprotect_event_file_t,
+    pub memory: query_bpf_file_mprotect_event_memory_t,
+    pub container: query_bpf_file_mprotect_event_container_t,
+}
+
+impl Default for query_bpf_file_mprotect_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_file_mprotect_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            "memory.anonymous" => {
+                if self.memory.anonymous___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("memory.anonymous must be a u32"))?;
+                self.memory.anonymous = v;
+                self.memory.anonymous___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "file.path" => {
+                if self.file.path___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.file.path.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.file.path___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("file.path is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_sock_graft_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_sock_graft_event_process_parent_t,
+}
+
+impl Default for query_bpf_sock_graft_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_sock_graft_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_source_t {
+    pub port___operator: u8,
+    pub port: u32,
+}
+
+impl Default for query_bpf_sock_graft_event_source_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_destination_t {
+    pub port___operator: u8,
+    pub port: u32,
+}
+
+impl Default for query_bpf_sock_graft_event_destination_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_sock_graft_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sock_graft_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_sock_graft_event_process_t,
+    pub user: query_bpf_sock_graft_event_user_t,
+    pub source: query_bpf_sock_graft_event_source_t,
+    pub destination: query_bpf_sock_graft_event_destination_t,
+    pub container: query_bpf_sock_graft_event_container_t,
+}
+
+impl Default for query_bpf_sock_graft_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_sock_graft_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            "source.port" => {
+                if self.source.port___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("source.port must be a u32"))?;
+                self.source.port = v;
+                self.source.port___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            "destination.port" => {
+                if self.destination.port___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("destination.port must be a u32"))?;
+                self.destination.port = v;
+                self.destination.port___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_fork_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_sched_process_fork_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_fork_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_sched_process_fork_event_process_parent_t,
+}
+
+impl Default for query_bpf_sched_process_fork_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_fork_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_sched_process_fork_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_fork_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_sched_process_fork_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_fork_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_sched_process_fork_event_process_t,
+    pub user: query_bpf_sched_process_fork_event_user_t,
+    pub container: query_bpf_sched_process_fork_event_container_t,
+}
+
+impl Default for query_bpf_sched_process_fork_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_sched_process_fork_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_exit_event_process_parent_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+}
+
+impl Default for query_bpf_sched_process_exit_event_process_parent_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_exit_event_process_t {
+    pub name___operator: u8,
+    pub name: [c_char; 256],
+    pub executable___operator: u8,
+    pub executable: [c_char; 256],
+    pub parent: query_bpf_sched_process_exit_event_process_parent_t,
+}
+
+impl Default for query_bpf_sched_process_exit_event_process_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_exit_event_user_t {
+    pub id___operator: u8,
+    pub id: u32,
+}
+
+impl Default for query_bpf_sched_process_exit_event_user_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_exit_event_container_t {
+    pub id___operator: u8,
+    pub id: [c_char; 256],
+}
+
+impl Default for query_bpf_sched_process_exit_event_container_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct query_bpf_sched_process_exit_event_t {
+    pub ___absolute: u8,
+    pub process: query_bpf_sched_process_exit_event_process_t,
+    pub user: query_bpf_sched_process_exit_event_user_t,
+    pub container: query_bpf_sched_process_exit_event_container_t,
+}
+
+impl Default for query_bpf_sched_process_exit_event_t {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl QueryStruct for query_bpf_sched_process_exit_event_t {
+    fn set_absolute(&mut self, value: u8) {
+        self.___absolute = value;
+    }
+
+    fn set_number(&mut self, path: String, operator: Operator, value: u64) -> Result<(), String> {
+        match path.as_str() {
+            "user.id" => {
+                if self.user.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                let v = u32::try_from(value).map_err(|_| String::from("user.id must be a u32"))?;
+                self.user.id = v;
+                self.user.id___operator = operator_to_constant(operator);
+                Ok(())
+            }
+            _ => Err(format!("numeric field named {} not found in schema", path)),
+        }
+    }
+
+    fn set_string(
+        &mut self,
+        path: String,
+        operator: Operator,
+        value: String,
+    ) -> Result<(), String> {
+        match path.as_str() {
+            "process.parent.name" => {
+                if self.process.parent.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.parent.executable" => {
+                if self.process.parent.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.parent.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.parent.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.parent.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.name" => {
+                if self.process.name___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.name.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.name___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.name is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "process.executable" => {
+                if self.process.executable___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.process.executable.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.process.executable___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("process.executable is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            "container.id" => {
+                if self.container.id___operator != UNSET_OPERATOR {
+                    // we can only hold a single condition per variable for now
+                    return Err(format!("{} already in condition", path));
+                }
+                if value.len() < 256 {
+                    for (dest, src) in self.container.id.iter_mut().zip(value.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    self.container.id___operator = operator_to_constant(operator);
+                    Ok(())
+                } else {
+                    Err(format!("container.id is too long, maximum 256 characters, given value is {} characters", value.len()))
+                }
+            },
+            _ => Err(format!("string field named {} not found in schema", path)),
+        }
+    }
+
+    fn flush<'a>(&mut self, _probe: &'a super::Probe<'a>) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+pub struct BpfQueryWriter<'a> {
+    table: String,
+    write_query_bprm_check_security_event_t: InnerBpfQueryWriter<query_bpf_bprm_check_security_event_t>,
+    write_query_inode_unlink_event_t: InnerBpfQueryWriter<query_bpf_inode_unlink_event_t>,
+    write_query_sb_mount_event_t: InnerBpfQueryWriter<query_bpf_sb_mount_event_t>,
+    write_query_sb_umount_event_t: InnerBpfQueryWriter<query_bpf_sb_umount_event_t>,
+    write_query_task_kill_event_t: InnerBpfQueryWriter<query_bpf_task_kill_event_t>,
+    write_query_mmap_file_event_t: InnerBpfQueryWriter<query_bpf_mmap_file_event_t>,
+    write_query_file_mprotect_event_t: InnerBpfQueryWriter<query_bpf_file_mprotect_event_t>,
+    write_query_sock_graft_event_t: InnerBpfQueryWriter<query_bpf_sock_graft_event_t>,
+    write_query_sched_process_fork_event_t: InnerBpfQueryWriter<query_bpf_sched_process_fork_event_t>,
+    write_query_sched_process_exit_event_t: InnerBpfQueryWriter<query_bpf_sched_process_exit_event_t>,
+    probe: Option<&'a super::Probe<'a>>,
+}
+
+impl<'a> BpfQueryWriter<'a> {
+    pub fn new(probe: Option<&'a super::Probe>, table: String, operation: Operation) -> Self {
+        Self {
+            table: table,
+            write_query_bprm_check_security_event_t: InnerBpfQueryWriter::<query_bpf_bprm_check_security_event_t>::new(
+                "bprm_check_security".into(),
+                operation,
+                8,
+            ),
+            write_query_inode_unlink_event_t: InnerBpfQueryWriter::<query_bpf_inode_unlink_event_t>::new(
+                "inode_unlink".into(),
+                operation,
+                8,
+            ),
+            write_query_sb_mount_event_t: InnerBpfQueryWriter::<query_bpf_sb_mount_event_t>::new(
+                "sb_mount".into(),
+                operation,
+                8,
+            ),
+            write_query_sb_umount_event_t: InnerBpfQueryWriter::<query_bpf_sb_umount_event_t>::new(
+                "sb_umount".into(),
+                operation,
+                8,
+            ),
+            write_query_task_kill_event_t: InnerBpfQueryWriter::<query_bpf_task_kill_event_t>::new(
+                "task_kill".into(),
+                operation,
+                8,
+            ),
+            write_query_mmap_file_event_t: InnerBpfQueryWriter::<query_bpf_mmap_file_event_t>::new(
+                "mmap_file".into(),
+                operation,
+                8,
+            ),
+            write_query_file_mprotect_event_t: InnerBpfQueryWriter::<query_bpf_file_mprotect_event_t>::new(
+                "file_mprotect".into(),
+                operation,
+                8,
+            ),
+            write_query_sock_graft_event_t: InnerBpfQueryWriter::<query_bpf_sock_graft_event_t>::new(
+                "sock_graft".into(),
+                operation,
+                8,
+            ),
+            write_query_sched_process_fork_event_t: InnerBpfQueryWriter::<query_bpf_sched_process_fork_event_t>::new(
+                "sched_process_fork".into(),
+                operation,
+                8,
+            ),
+            write_query_sched_process_exit_event_t: InnerBpfQueryWriter::<query_bpf_sched_process_exit_event_t>::new(
+                "sched_process_exit".into(),
                 operation,
                 8,
             ),
@@ -314,6 +2167,14 @@ impl<'b> QueryWriter for BpfQueryWriter<'b> {
         match self.table.as_str() {
             "bprm_check_security" => self.write_query_bprm_check_security_event_t.write_statement(field, operator, atom),
             "inode_unlink" => self.write_query_inode_unlink_event_t.write_statement(field, operator, atom),
+            "sb_mount" => self.write_query_sb_mount_event_t.write_statement(field, operator, atom),
+            "sb_umount" => self.write_query_sb_umount_event_t.write_statement(field, operator, atom),
+            "task_kill" => self.write_query_task_kill_event_t.write_statement(field, operator, atom),
+            "mmap_file" => self.write_query_mmap_file_event_t.write_statement(field, operator, atom),
+            "file_mprotect" => self.write_query_file_mprotect_event_t.write_statement(field, operator, atom),
+            "sock_graft" => self.write_query_sock_graft_event_t.write_statement(field, operator, atom),
+            "sched_process_fork" => self.write_query_sched_process_fork_event_t.write_statement(field, operator, atom),
+            "sched_process_exit" => self.write_query_sched_process_exit_event_t.write_statement(field, operator, atom),
             _ => Err(format!("invalid table name {}", self.table)),
         }
     }
@@ -322,6 +2183,14 @@ impl<'b> QueryWriter for BpfQueryWriter<'b> {
         match self.table.as_str() {
             "bprm_check_security" => self.write_query_bprm_check_security_event_t.start_new_clause(),
             "inode_unlink" => self.write_query_inode_unlink_event_t.start_new_clause(),
+            "sb_mount" => self.write_query_sb_mount_event_t.start_new_clause(),
+            "sb_umount" => self.write_query_sb_umount_event_t.start_new_clause(),
+            "task_kill" => self.write_query_task_kill_event_t.start_new_clause(),
+            "mmap_file" => self.write_query_mmap_file_event_t.start_new_clause(),
+            "file_mprotect" => self.write_query_file_mprotect_event_t.start_new_clause(),
+            "sock_graft" => self.write_query_sock_graft_event_t.start_new_clause(),
+            "sched_process_fork" => self.write_query_sched_process_fork_event_t.start_new_clause(),
+            "sched_process_exit" => self.write_query_sched_process_exit_event_t.start_new_clause(),
             _ => Err(format!("invalid table name {}", self.table)),
         }
     }
@@ -330,6 +2199,14 @@ impl<'b> QueryWriter for BpfQueryWriter<'b> {
         match self.table.as_str() {
             "bprm_check_security" => self.write_query_bprm_check_security_event_t.write_absolute(value),
             "inode_unlink" => self.write_query_inode_unlink_event_t.write_absolute(value),
+            "sb_mount" => self.write_query_sb_mount_event_t.write_absolute(value),
+            "sb_umount" => self.write_query_sb_umount_event_t.write_absolute(value),
+            "task_kill" => self.write_query_task_kill_event_t.write_absolute(value),
+            "mmap_file" => self.write_query_mmap_file_event_t.write_absolute(value),
+            "file_mprotect" => self.write_query_file_mprotect_event_t.write_absolute(value),
+            "sock_graft" => self.write_query_sock_graft_event_t.write_absolute(value),
+            "sched_process_fork" => self.write_query_sched_process_fork_event_t.write_absolute(value),
+            "sched_process_exit" => self.write_query_sched_process_exit_event_t.write_absolute(value),
             _ => Err(format!("invalid table name {}", self.table)),
         }
     }
@@ -339,6 +2216,14 @@ impl<'b> QueryWriter for BpfQueryWriter<'b> {
             Some(probe) => match self.table.as_str() {
                 "bprm_check_security" => self.write_query_bprm_check_security_event_t.flush_probe(probe),
                 "inode_unlink" => self.write_query_inode_unlink_event_t.flush_probe(probe),
+                "sb_mount" => self.write_query_sb_mount_event_t.flush_probe(probe),
+                "sb_umount" => self.write_query_sb_umount_event_t.flush_probe(probe),
+                "task_kill" => self.write_query_task_kill_event_t.flush_probe(probe),
+                "mmap_file" => self.write_query_mmap_file_event_t.flush_probe(probe),
+                "file_mprotect" => self.write_query_file_mprotect_event_t.flush_probe(probe),
+                "sock_graft" => self.write_query_sock_graft_event_t.flush_probe(probe),
+                "sched_process_fork" => self.write_query_sched_process_fork_event_t.flush_probe(probe),
+                "sched_process_exit" => self.write_query_sched_process_exit_event_t.flush_probe(probe),
                 _ => Err(format!("invalid table name {}", self.table)),
             },
             _ => Ok(())
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_CACHED_CONTAINERS: usize = 1024;
+
+// relative to the root of the filesystem the runtimes keep their state on
+const DOCKER_STATE: &str = "var/lib/docker/containers";
+const CONTAINERD_STATE: &str = "run/containerd/io.containerd.runtime.v2.task";
+const CONTAINERD_NAMESPACES: [&str; 3] = ["k8s.io", "moby", "default"];
+const CRIO_STATE: &str = "var/lib/containers/storage/overlay-containers";
+
+/// What's known about a container from its runtime's state on disk.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Container {
+    pub runtime: Option<String>,
+    pub name: Option<String>,
+    pub image_name: Option<String>,
+    pub image_tag: Option<String>,
+}
+
+/// Containers resolved from their runtime's state, keyed by container id.
+pub struct ContainerCache {
+    root: PathBuf,
+    containers: HashMap<String, Container>,
+}
+
+impl Default for ContainerCache {
+    fn default() -> Self {
+        Self::with_root("/")
+    }
+}
+
+impl ContainerCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Looks for runtime state under `root` rather than `/`.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Self {
+        Self {
+            root: root.into(),
+            containers: HashMap::new(),
+        }
+    }
+
+    pub fn get(&mut self, id: &str, cgroup_path: &str) -> Container {
+        if let Some(container) = self.containers.get(id) {
+            return container.clone();
+        }
+        let container = resolve(&self.root, id, cgroup_path);
+        // a container's state may not be on disk yet when we see its first
+        // events, so only hold onto the ones we could fully resolve
+        if container.name.is_some() {
+            if self.containers.len() >= MAX_CACHED_CONTAINERS {
+                self.containers.clear();
+            }
+            self.containers.insert(id.to_string(), container.clone());
+        }
+        container
+    }
+}
+
+fn runtime_from_cgroup(root: &Path, id: &str, cgroup_path: &str) -> Option<&'static str> {
+    if cgroup_path.contains("docker") {
+        Some("docker")
+    } else if cgroup_path.contains("containerd") {
+        Some("containerd")
+    } else if cgroup_path.contains("crio") {
+        Some("cri-o")
+    } else if cgroup_path.contains("libpod") {
+        Some("podman")
+    } else if cgroup_path.contains("kubepods") {
+        // the cgroupfs driver names pod cgroups after the pod and container
+        // alone, i.e. /kubepods/burstable/pod<uid>/<id>, so look for the
+        // container in each runtime's state instead
+        runtime_from_state(root, id)
+    } else {
+        None
+    }
+}
+
+fn runtime_from_state(root: &Path, id: &str) -> Option<&'static str> {
+    if root.join(DOCKER_STATE).join(id).exists() {
+        Some("docker")
+    } else if CONTAINERD_NAMESPACES.iter().any(|namespace| {
+        root.join(CONTAINERD_STATE)
+            .join(namespace)
+            .join(id)
+            .exists()
+    }) {
+        Some("containerd")
+    } else if root.join(CRIO_STATE).join(id).exists() {
+        Some("cri-o")
+    } else {
+        None
+    }
+}
+
+fn split_image(image: &str) -> (Option<String>, Option<String>) {
+    let image = image.split('@').next().unwrap_or(image);
+    let name_start = image.rfind('/').map_or(0, |i| i + 1);
+    match image[name_start..].rfind(':') {
+        Some(i) => (
+            Some(image[..name_start + i].to_string()),
+            Some(image[name_start + i + 1..].to_string()),
+        ),
+        None => (Some(image.to_string()), None),
+    }
+}
+
+fn get_string(data: &str, path: &str) -> Option<String> {
+    ajson::get(data, path)
+        .map(|v| v.to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn resolve(root: &Path, id: &str, cgroup_path: &str) -> Container {
+    let runtime = runtime_from_cgroup(root, id, cgroup_path);
+    let mut container = Container {
+        runtime: runtime.map(String::from),
+        ..Default::default()
+    };
+    let (name, image) = match runtime {
+        Some("docker") => resolve_docker(root, id),
+        Some("containerd") => resolve_containerd(root, id),
+        Some("cri-o") => resolve_crio(root, id),
+        _ => (None, None),
+    };
+    container.name = name;
+    if let Some(image) = image {
+        let (image_name, image_tag) = split_image(&image);
+        container.image_name = image_name;
+        container.image_tag = image_tag;
+    }
+    container
+}
+
+fn resolve_docker(root: &Path, id: &str) -> (Option<String>, Option<String>) {
+    let config = root.join(DOCKER_STATE).join(id).join("config.v2.json");
+    match fs::read_to_string(config) {
+        Ok(data) => (
+            get_string(&data, "Name").map(|n| n.trim_start_matches('/').to_string()),
+            get_string(&data, "Config.Image"),
+        ),
+        Err(_) => (None, None),
+    }
+}
+
+fn resolve_containerd(root: &Path, id: &str) -> (Option<String>, Option<String>) {
+    for namespace in CONTAINERD_NAMESPACES.iter() {
+        let config = root
+            .join(CONTAINERD_STATE)
+            .join(namespace)
+            .join(id)
+            .join("config.json");
+        if let Ok(data) = fs::read_to_string(config) {
+            return (
+                get_string(&data, r"annotations.io\.kubernetes\.cri\.container-name"),
+                get_string(&data, r"annotations.io\.kubernetes\.cri\.image-name"),
+            );
+        }
+    }
+    (None, None)
+}
+
+fn resolve_crio(root: &Path, id: &str) -> (Option<String>, Option<String>) {
+    let config = root
+        .join(CRIO_STATE)
+        .join(id)
+        .join("userdata")
+        .join("config.json");
+    match fs::read_to_string(config) {
+        Ok(data) => (
+            get_string(&data, r"annotations.io\.kubernetes\.container\.name"),
+            get_string(&data, r"annotations.io\.kubernetes\.cri-o\.ImageName"),
+        ),
+        Err(_) => (None, None),
+    }
+}
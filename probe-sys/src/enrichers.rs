@@ -0,0 +1,491 @@
+use std::convert::TryFrom;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::PoisonError;
+
+use crate::errors::SerializableResult;
+use crate::helpers::{entity_id, parse_id};
+
+/// A value read from or written to an event field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    String(String),
+    Number(u64),
+    Bool(bool),
+    Strings(Vec<String>),
+}
+
+/// Access to the fields of an event by their dotted schema path,
+/// i.e. `process.parent.executable`.
+pub trait FieldAccess {
+    fn get_field(&self, path: &str) -> Option<FieldValue>;
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()>;
+}
+
+/// A single step of the enrichment pipeline, run on every event whose module
+/// it applies to.
+pub trait Enricher: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn applies_to(&self, _module: &str) -> bool {
+        true
+    }
+
+    fn enrich(&self, module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()>;
+}
+
+/// An ordered list of enrichers.
+pub struct EnricherChain {
+    enrichers: Vec<Box<dyn Enricher>>,
+}
+
+impl EnricherChain {
+    pub fn new() -> Self {
+        Self { enrichers: vec![] }
+    }
+
+    /// The enrichers that ship with the probe, in their default order.
+    pub fn builtin() -> Vec<Box<dyn Enricher>> {
+        vec![
+            Box::new(UserNames {}),
+            Box::new(EntityIds {}),
+            Box::new(Ancestry {}),
+            Box::new(ContainerMetadata {}),
+            Box::new(HostMetadata {}),
+            Box::new(EcsCategorization {}),
+            Box::new(CommandLine {}),
+            Box::new(FilePath {}),
+            Box::new(KillTarget {}),
+            Box::new(NetworkConnection {}),
+        ]
+    }
+
+    /// Builds a chain out of the named enrichers, in the order they're named.
+    pub fn configure(names: &[String], available: Vec<Box<dyn Enricher>>) -> Result<Self, String> {
+        let mut available: Vec<Option<Box<dyn Enricher>>> =
+            available.into_iter().map(Some).collect();
+        let mut chain = Self::new();
+        for name in names {
+            let enricher = available
+                .iter_mut()
+                .find(|e| e.as_ref().map_or(false, |e| e.name() == name))
+                .and_then(Option::take)
+                .ok_or_else(|| format!("unknown or repeated enricher {}", name))?;
+            chain.push(enricher);
+        }
+        Ok(chain)
+    }
+
+    pub fn push(&mut self, enricher: Box<dyn Enricher>) {
+        self.enrichers.push(enricher);
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.enrichers.iter().map(|e| e.name()).collect()
+    }
+
+    pub fn apply(&self, module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        for enricher in self.enrichers.iter().filter(|e| e.applies_to(module)) {
+            enricher.enrich(module, event)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for EnricherChain {
+    fn default() -> Self {
+        Self {
+            enrichers: Self::builtin(),
+        }
+    }
+}
+
+fn set_string(event: &mut dyn FieldAccess, path: &str, value: &str) -> SerializableResult<()> {
+    event.set_field(path, FieldValue::String(value.to_string()))
+}
+
+fn get_string(event: &dyn FieldAccess, path: &str) -> Option<String> {
+    match event.get_field(path) {
+        Some(FieldValue::String(value)) if !value.is_empty() => Some(value),
+        _ => None,
+    }
+}
+
+// the entity id of the process at the path, i.e. `process.parent`, unless
+// its pid is unknown
+fn process_entity_id(event: &dyn FieldAccess, process: &str) -> SerializableResult<Option<String>> {
+    let pid = match event.get_field(&format!("{}.pid", process)) {
+        Some(FieldValue::Number(pid)) if pid > 0 => u32::try_from(pid).ok(),
+        _ => None,
+    };
+    match (pid, event.get_field(&format!("{}.start", process))) {
+        (Some(pid), Some(FieldValue::Number(start))) => entity_id(pid, start).map(Some),
+        _ => Ok(None),
+    }
+}
+
+// id fields, the name fields they resolve to, and whether they're groups
+const ACCOUNTS: [(&str, &str, bool); 4] = [
+    ("user.id", "user.name", false),
+    ("user.group.id", "user.group.name", true),
+    ("user.effective.id", "user.effective.name", false),
+    ("user.effective.group.id", "user.effective.group.name", true),
+];
+
+/// Names the real and effective users and groups of every event.
+pub struct UserNames {}
+
+impl Enricher for UserNames {
+    fn name(&self) -> &str {
+        "user"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        let mut cache = crate::USERS_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for &(id_path, name_path, group) in ACCOUNTS.iter() {
+            let id = match get_string(event, id_path) {
+                Some(id) => parse_id(id_path, &id)?,
+                None => continue,
+            };
+            let name = if group {
+                cache.group_name(id)
+            } else {
+                cache.user_name(id)
+            };
+            if let Some(name) = name {
+                set_string(event, name_path, &name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fills in the entity ids of the process and its parent.
+pub struct EntityIds {}
+
+impl Enricher for EntityIds {
+    fn name(&self) -> &str {
+        "entity_id"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        for process in &["process", "process.parent"] {
+            if let Some(id) = process_entity_id(event, process)? {
+                set_string(event, &format!("{}.entity_id", process), &id)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fills in process.ancestry from the process table.
+pub struct Ancestry {}
+
+impl Enricher for Ancestry {
+    fn name(&self) -> &str {
+        "ancestry"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        let id = match process_entity_id(event, "process")? {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+        let ancestors = crate::PROCESS_TABLE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .ancestry(&id);
+        if ancestors.is_empty() {
+            return Ok(());
+        }
+        let mut entity_ids = Vec::with_capacity(ancestors.len());
+        let mut names = Vec::with_capacity(ancestors.len());
+        let mut executables = Vec::with_capacity(ancestors.len());
+        for ancestor in ancestors {
+            entity_ids.push(ancestor.entity_id);
+            names.push(ancestor.name);
+            executables.push(ancestor.executable);
+        }
+        event.set_field(
+            "process.ancestry.entity_id",
+            FieldValue::Strings(entity_ids),
+        )?;
+        event.set_field("process.ancestry.name", FieldValue::Strings(names))?;
+        event.set_field(
+            "process.ancestry.executable",
+            FieldValue::Strings(executables),
+        )
+    }
+}
+
+/// Fills in the runtime, name, and image of the container an event came
+/// from.
+pub struct ContainerMetadata {}
+
+impl Enricher for ContainerMetadata {
+    fn name(&self) -> &str {
+        "container"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        let container_id = match get_string(event, "container.id") {
+            Some(container_id) => container_id,
+            None => return Ok(()),
+        };
+        let cgroup_path = get_string(event, "cgroup.path").unwrap_or_default();
+        let resolved = crate::CONTAINERS_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&container_id, &cgroup_path);
+        let fields = [
+            ("container.runtime", resolved.runtime),
+            ("container.name", resolved.name),
+            ("container.image.name", resolved.image_name),
+            ("container.image.tag", resolved.image_tag),
+        ];
+        for (path, value) in fields.iter() {
+            if let Some(value) = value {
+                set_string(event, path, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fills in the host and operating system fields of every event.
+pub struct HostMetadata {}
+
+impl Enricher for HostMetadata {
+    fn name(&self) -> &str {
+        "host"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        let metadata = crate::HOST_METADATA.get();
+        event.set_field("host.uptime", FieldValue::Number(metadata.uptime()))?;
+        let fields = [
+            ("host.id", &metadata.id),
+            ("host.hostname", &metadata.hostname),
+            ("host.os.name", &metadata.os_name),
+            ("host.os.kernel", &metadata.kernel),
+        ];
+        for (path, value) in fields.iter() {
+            if let Some(value) = value {
+                set_string(event, path, value)?;
+            }
+        }
+        event.set_field("host.mac", FieldValue::Strings(metadata.macs.clone()))?;
+        event.set_field("host.ip", FieldValue::Strings(metadata.ips.clone()))?;
+        set_string(event, "host.os.type", "linux")
+    }
+}
+
+// module, event.category, event.type, and event.module for each event
+const CATEGORIZATIONS: [(&str, &str, &str, &str); 10] = [
+    ("bprm_check_security", "process", "start", "bpf-lsm"),
+    ("inode_unlink", "file", "deletion", "bpf-lsm"),
+    ("sb_mount", "file", "creation", "bpf-lsm"),
+    ("sb_umount", "file", "deletion", "bpf-lsm"),
+    ("task_kill", "process", "info", "bpf-lsm"),
+    ("mmap_file", "process", "change", "bpf-lsm"),
+    ("file_mprotect", "process", "change", "bpf-lsm"),
+    ("sock_graft", "network", "connection", "bpf-lsm"),
+    ("sched_process_fork", "process", "start", "bpf-tracepoint"),
+    ("sched_process_exit", "process", "end", "bpf-tracepoint"),
+];
+
+/// Sets the ECS categorization fields of every event.
+pub struct EcsCategorization {}
+
+impl Enricher for EcsCategorization {
+    fn name(&self) -> &str {
+        "ecs"
+    }
+
+    fn applies_to(&self, module: &str) -> bool {
+        CATEGORIZATIONS.iter().any(|c| c.0 == module)
+    }
+
+    fn enrich(&self, module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        for (_, category, event_type, event_module) in
+            CATEGORIZATIONS.iter().filter(|c| c.0 == module)
+        {
+            set_string(event, "event.kind", "event")?;
+            set_string(event, "event.category", category)?;
+            set_string(event, "event.type", event_type)?;
+            set_string(event, "event.module", event_module)?;
+            set_string(event, "event.provider", &module.replace('_', "-"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Fills in process.command_line from process.args when the full command
+/// line wasn't captured.
+pub struct CommandLine {}
+
+impl Enricher for CommandLine {
+    fn name(&self) -> &str {
+        "command_line"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        if let Some(FieldValue::String(command_line)) = event.get_field("process.command_line") {
+            if !command_line.is_empty() {
+                return Ok(());
+            }
+        }
+        if let Some(FieldValue::Strings(args)) = event.get_field("process.args") {
+            set_string(event, "process.command_line", &args.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits file.path into file.name, file.directory, and file.extension.
+pub struct FilePath {}
+
+impl Enricher for FilePath {
+    fn name(&self) -> &str {
+        "file"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        let file_path = match event.get_field("file.path") {
+            // anonymous memory mappings have no path
+            Some(FieldValue::String(file_path)) if !file_path.is_empty() => file_path,
+            _ => return Ok(()),
+        };
+        let path = Path::new(&file_path);
+        if let Some(name) = path.file_name() {
+            set_string(event, "file.name", &name.to_string_lossy())?;
+        }
+        if let Some(parent) = path.parent() {
+            set_string(event, "file.directory", &parent.to_string_lossy())?;
+        }
+        if let Some(extension) = path.extension() {
+            set_string(event, "file.extension", &extension.to_string_lossy())?;
+        }
+        Ok(())
+    }
+}
+
+const SIGNAL_NAMES: [&str; 31] = [
+    "SIGHUP",
+    "SIGINT",
+    "SIGQUIT",
+    "SIGILL",
+    "SIGTRAP",
+    "SIGABRT",
+    "SIGBUS",
+    "SIGFPE",
+    "SIGKILL",
+    "SIGUSR1",
+    "SIGSEGV",
+    "SIGUSR2",
+    "SIGPIPE",
+    "SIGALRM",
+    "SIGTERM",
+    "SIGSTKFLT",
+    "SIGCHLD",
+    "SIGCONT",
+    "SIGSTOP",
+    "SIGTSTP",
+    "SIGTTIN",
+    "SIGTTOU",
+    "SIGURG",
+    "SIGXCPU",
+    "SIGXFSZ",
+    "SIGVTALRM",
+    "SIGPROF",
+    "SIGWINCH",
+    "SIGIO",
+    "SIGPWR",
+    "SIGSYS",
+];
+const SIGRTMIN: u64 = 34;
+const SIGRTMAX: u64 = 64;
+
+fn signal_name(number: u64) -> Option<String> {
+    match number {
+        1..=31 => Some(SIGNAL_NAMES[number as usize - 1].to_string()),
+        SIGRTMIN => Some("SIGRTMIN".to_string()),
+        SIGRTMAX => Some("SIGRTMAX".to_string()),
+        n if n > SIGRTMIN && n < SIGRTMAX => Some(format!("SIGRTMIN+{}", n - SIGRTMIN)),
+        _ => None,
+    }
+}
+
+fn transport_name(iana_number: &str) -> Option<String> {
+    match iana_number {
+        "6" => Some("tcp".to_string()),
+        "17" => Some("udp".to_string()),
+        "33" => Some("dccp".to_string()),
+        "132" => Some("sctp".to_string()),
+        _ => None,
+    }
+}
+
+/// Names the signal of task_kill events and fills in the command line and
+/// entity id of the process the signal was sent to.
+pub struct KillTarget {}
+
+impl Enricher for KillTarget {
+    fn name(&self) -> &str {
+        "kill"
+    }
+
+    fn applies_to(&self, module: &str) -> bool {
+        module == "task_kill"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        if let Some(FieldValue::Number(number)) = event.get_field("signal.number") {
+            if let Some(name) = signal_name(number) {
+                set_string(event, "signal.name", &name)?;
+            }
+        }
+        if let Some(FieldValue::Strings(args)) = event.get_field("target.process.args") {
+            set_string(event, "target.process.command_line", &args.join(" "))?;
+        }
+        if let Some(id) = process_entity_id(event, "target.process")? {
+            set_string(event, "target.process.entity_id", &id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Fills in the direction, transport, and IP version of sock_graft
+/// connections.
+pub struct NetworkConnection {}
+
+impl Enricher for NetworkConnection {
+    fn name(&self) -> &str {
+        "network"
+    }
+
+    fn applies_to(&self, module: &str) -> bool {
+        module == "sock_graft"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        set_string(event, "network.direction", "ingress")?;
+        if let Some(FieldValue::String(iana_number)) = event.get_field("network.iana_number") {
+            if let Some(transport) = transport_name(&iana_number) {
+                set_string(event, "network.transport", &transport)?;
+            }
+        }
+        let source_ip = match event.get_field("source.ip") {
+            Some(FieldValue::String(ip)) => ip.parse::<IpAddr>().ok(),
+            _ => None,
+        };
+        match source_ip {
+            Some(IpAddr::V4(_)) => set_string(event, "network.type", "ipv4")?,
+            Some(IpAddr::V6(_)) => set_string(event, "network.type", "ipv6")?,
+            None => {}
+        }
+        Ok(())
+    }
+}
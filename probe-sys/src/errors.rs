@@ -21,6 +21,12 @@ impl fmt::Display for Error {
 pub enum SerializationError {
     Json(PrintError),
     Bytes(ProtobufError),
+    Decode(ProtobufError),
+    MissingEvent(&'static str),
+    InvalidField(&'static str, String),
+    MachineId(String),
+    UnknownField(String),
+    FieldType(String, &'static str),
     Transform(String),
 }
 
@@ -29,6 +35,12 @@ impl fmt::Display for SerializationError {
         match self {
             Self::Json(_e) => write!(f, "json serialization failed"),
             Self::Bytes(e) => std::fmt::Display::fmt(&e, f),
+            Self::Decode(e) => write!(f, "could not decode event: {}", e),
+            Self::MissingEvent(module) => write!(f, "{} event is missing its body", module),
+            Self::InvalidField(field, value) => write!(f, "invalid {} value: {:?}", field, value),
+            Self::MachineId(e) => write!(f, "could not read machine id: {}", e),
+            Self::UnknownField(path) => write!(f, "field named {} not found in schema", path),
+            Self::FieldType(path, expected) => write!(f, "{} must be a {}", path, expected),
             Self::Transform(e) => std::fmt::Display::fmt(&e, f),
         }
     }
@@ -39,6 +51,12 @@ impl error::Error for SerializationError {
         match self {
             Self::Json(_) => None,
             Self::Bytes(e) => Some(e),
+            Self::Decode(e) => Some(e),
+            Self::MissingEvent(_) => None,
+            Self::InvalidField(..) => None,
+            Self::MachineId(_) => None,
+            Self::UnknownField(_) => None,
+            Self::FieldType(..) => None,
             Self::Transform(_) => None,
         }
     }
@@ -19,6 +19,34 @@ impl Default for cached_process {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct cached_process_details {
+    pub command_line: [c_char; 4096],
+    pub env: [[c_char; 256]; 8],
+    pub env_count: u64,
+    pub env_truncated: i32,
+}
+
+impl Default for cached_process_details {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct env_name {
+    pub value: [c_char; 64],
+    pub len: u32,
+}
+
+impl Default for env_name {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bprm_check_security_event_event_t {
@@ -27,12 +55,745 @@ pub struct bprm_check_security_event_event_t {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_process_parent_t {
+pub struct bprm_check_security_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: bprm_check_security_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_user_effective_t {
+    pub id: u32,
+    pub group: bprm_check_security_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_user_t {
+    pub id: u32,
+    pub group: bprm_check_security_event_user_group_t,
+    pub effective: bprm_check_security_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bprm_check_security_event_t {
+    pub __timestamp: u64,
+    pub event: bprm_check_security_event_event_t,
+    pub process: bprm_check_security_event_process_t,
+    pub user: bprm_check_security_event_user_t,
+    pub container: bprm_check_security_event_container_t,
+    pub cgroup: bprm_check_security_event_cgroup_t,
+    pub namespace: bprm_check_security_event_namespace_t,
+}
+
+pub type bprm_check_security_event_handler = extern "C" fn(ctx: *mut c_void, e: bprm_check_security_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: inode_unlink_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_user_effective_t {
+    pub id: u32,
+    pub group: inode_unlink_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_user_t {
+    pub id: u32,
+    pub group: inode_unlink_event_user_group_t,
+    pub effective: inode_unlink_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_file_t {
+    pub path: [c_char; 256],
+    pub inode: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct inode_unlink_event_t {
+    pub __timestamp: u64,
+    pub event: inode_unlink_event_event_t,
+    pub process: inode_unlink_event_process_t,
+    pub user: inode_unlink_event_user_t,
+    pub file: inode_unlink_event_file_t,
+    pub container: inode_unlink_event_container_t,
+    pub cgroup: inode_unlink_event_cgroup_t,
+    pub namespace: inode_unlink_event_namespace_t,
+}
+
+pub type inode_unlink_event_handler = extern "C" fn(ctx: *mut c_void, e: inode_unlink_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: sb_mount_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_user_effective_t {
+    pub id: u32,
+    pub group: sb_mount_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_user_t {
+    pub id: u32,
+    pub group: sb_mount_event_user_group_t,
+    pub effective: sb_mount_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_mount_t {
+    pub source: [c_char; 256],
+    pub target: [c_char; 256],
+    pub fstype: [c_char; 256],
+    pub flags: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_mount_event_t {
+    pub __timestamp: u64,
+    pub event: sb_mount_event_event_t,
+    pub process: sb_mount_event_process_t,
+    pub user: sb_mount_event_user_t,
+    pub mount: sb_mount_event_mount_t,
+    pub container: sb_mount_event_container_t,
+    pub cgroup: sb_mount_event_cgroup_t,
+    pub namespace: sb_mount_event_namespace_t,
+}
+
+pub type sb_mount_event_handler = extern "C" fn(ctx: *mut c_void, e: sb_mount_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: sb_umount_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_user_effective_t {
+    pub id: u32,
+    pub group: sb_umount_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_user_t {
+    pub id: u32,
+    pub group: sb_umount_event_user_group_t,
+    pub effective: sb_umount_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_mount_t {
+    pub source: [c_char; 256],
+    pub target: [c_char; 256],
+    pub fstype: [c_char; 256],
+    pub flags: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sb_umount_event_t {
+    pub __timestamp: u64,
+    pub event: sb_umount_event_event_t,
+    pub process: sb_umount_event_process_t,
+    pub user: sb_umount_event_user_t,
+    pub mount: sb_umount_event_mount_t,
+    pub container: sb_umount_event_container_t,
+    pub cgroup: sb_umount_event_cgroup_t,
+    pub namespace: sb_umount_event_namespace_t,
+}
+
+pub type sb_umount_event_handler = extern "C" fn(ctx: *mut c_void, e: sb_umount_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: task_kill_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_user_effective_t {
+    pub id: u32,
+    pub group: task_kill_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_user_t {
+    pub id: u32,
+    pub group: task_kill_event_user_group_t,
+    pub effective: task_kill_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_signal_t {
+    pub number: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_target_process_t {
+    pub pid: u32,
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_target_t {
+    pub process: task_kill_event_target_process_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct task_kill_event_t {
+    pub __timestamp: u64,
+    pub event: task_kill_event_event_t,
+    pub process: task_kill_event_process_t,
+    pub user: task_kill_event_user_t,
+    pub signal: task_kill_event_signal_t,
+    pub target: task_kill_event_target_t,
+    pub container: task_kill_event_container_t,
+    pub cgroup: task_kill_event_cgroup_t,
+    pub namespace: task_kill_event_namespace_t,
+}
+
+pub type task_kill_event_handler = extern "C" fn(ctx: *mut c_void, e: task_kill_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: mmap_file_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_user_effective_t {
+    pub id: u32,
+    pub group: mmap_file_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_user_t {
+    pub id: u32,
+    pub group: mmap_file_event_user_group_t,
+    pub effective: mmap_file_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_file_t {
+    pub path: [c_char; 256],
+    pub inode: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_memory_t {
+    pub anonymous: u32,
+    pub protection: u64,
+    pub flags: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct mmap_file_event_t {
+    pub __timestamp: u64,
+    pub event: mmap_file_event_event_t,
+    pub process: mmap_file_event_process_t,
+    pub user: mmap_file_event_user_t,
+    pub file: mmap_file_event_file_t,
+    pub memory: mmap_file_event_memory_t,
+    pub container: mmap_file_event_container_t,
+    pub cgroup: mmap_file_event_cgroup_t,
+    pub namespace: mmap_file_event_namespace_t,
+}
+
+pub type mmap_file_event_handler = extern "C" fn(ctx: *mut c_void, e: mmap_file_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: file_mprotect_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_user_effective_t {
+    pub id: u32,
+    pub group: file_mprotect_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_user_t {
+    pub id: u32,
+    pub group: file_mprotect_event_user_group_t,
+    pub effective: file_mprotect_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_file_t {
+    pub path: [c_char; 256],
+    pub inode: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_memory_t {
+    pub anonymous: u32,
+    pub protection: u64,
+    pub address: u64,
+    pub size: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct file_mprotect_event_t {
+    pub __timestamp: u64,
+    pub event: file_mprotect_event_event_t,
+    pub process: file_mprotect_event_process_t,
+    pub user: file_mprotect_event_user_t,
+    pub file: file_mprotect_event_file_t,
+    pub memory: file_mprotect_event_memory_t,
+    pub container: file_mprotect_event_container_t,
+    pub cgroup: file_mprotect_event_cgroup_t,
+    pub namespace: file_mprotect_event_namespace_t,
+}
+
+pub type file_mprotect_event_handler = extern "C" fn(ctx: *mut c_void, e: file_mprotect_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_process_parent_t {
     pub pid: u32,
     pub entity_id: [c_char; 256],
     pub name: [c_char; 256],
     pub args_count: u64,
     pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
     pub ppid: u32,
     pub start: u64,
     pub thread__id: u64,
@@ -40,7 +801,7 @@ pub struct bprm_check_security_event_process_parent_t {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_process_t {
+pub struct sock_graft_event_process_t {
     pub pid: u32,
     pub entity_id: [c_char; 256],
     pub name: [c_char; 256],
@@ -49,56 +810,103 @@ pub struct bprm_check_security_event_process_t {
     pub args_count: u64,
     pub start: u64,
     pub thread__id: u64,
+    pub command_line: [c_char; 4096],
     pub args: [[c_char; 128]; 64],
-    pub parent: bprm_check_security_event_process_parent_t,
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: sock_graft_event_process_parent_t,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_user_group_t {
+pub struct sock_graft_event_user_group_t {
     pub id: u32,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_user_effective_group_t {
+pub struct sock_graft_event_user_effective_group_t {
     pub id: u32,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_user_effective_t {
+pub struct sock_graft_event_user_effective_t {
     pub id: u32,
-    pub group: bprm_check_security_event_user_effective_group_t,
+    pub group: sock_graft_event_user_effective_group_t,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_user_t {
+pub struct sock_graft_event_user_t {
     pub id: u32,
-    pub group: bprm_check_security_event_user_group_t,
-    pub effective: bprm_check_security_event_user_effective_t,
+    pub group: sock_graft_event_user_group_t,
+    pub effective: sock_graft_event_user_effective_t,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct bprm_check_security_event_t {
+pub struct sock_graft_event_source_t {
+    pub ip: [u8; 16],
+    pub port: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_destination_t {
+    pub ip: [u8; 16],
+    pub port: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_network_t {
+    pub iana_number: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sock_graft_event_t {
     pub __timestamp: u64,
-    pub event: bprm_check_security_event_event_t,
-    pub process: bprm_check_security_event_process_t,
-    pub user: bprm_check_security_event_user_t,
+    pub event: sock_graft_event_event_t,
+    pub process: sock_graft_event_process_t,
+    pub user: sock_graft_event_user_t,
+    pub source: sock_graft_event_source_t,
+    pub destination: sock_graft_event_destination_t,
+    pub network: sock_graft_event_network_t,
+    pub container: sock_graft_event_container_t,
+    pub cgroup: sock_graft_event_cgroup_t,
+    pub namespace: sock_graft_event_namespace_t,
 }
 
-pub type bprm_check_security_event_handler = extern "C" fn(ctx: *mut c_void, e: bprm_check_security_event_t);
+pub type sock_graft_event_handler = extern "C" fn(ctx: *mut c_void, e: sock_graft_event_t);
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_event_t {
+pub struct sched_process_fork_event_event_t {
     pub action: [c_char; 256],
     pub outcome: [c_char; 256],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_process_parent_t {
+pub struct sched_process_fork_event_process_parent_t {
     pub pid: u32,
     pub entity_id: [c_char; 256],
     pub name: [c_char; 256],
     pub args_count: u64,
     pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
     pub ppid: u32,
     pub start: u64,
     pub thread__id: u64,
@@ -106,7 +914,7 @@ pub struct inode_unlink_event_process_parent_t {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_process_t {
+pub struct sched_process_fork_event_process_t {
     pub pid: u32,
     pub entity_id: [c_char; 256],
     pub name: [c_char; 256],
@@ -115,58 +923,192 @@ pub struct inode_unlink_event_process_t {
     pub args_count: u64,
     pub start: u64,
     pub thread__id: u64,
+    pub command_line: [c_char; 4096],
     pub args: [[c_char; 128]; 64],
-    pub parent: inode_unlink_event_process_parent_t,
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: sched_process_fork_event_process_parent_t,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_user_group_t {
+pub struct sched_process_fork_event_user_group_t {
     pub id: u32,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_user_effective_group_t {
+pub struct sched_process_fork_event_user_effective_group_t {
     pub id: u32,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_user_effective_t {
+pub struct sched_process_fork_event_user_effective_t {
     pub id: u32,
-    pub group: inode_unlink_event_user_effective_group_t,
+    pub group: sched_process_fork_event_user_effective_group_t,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_user_t {
+pub struct sched_process_fork_event_user_t {
     pub id: u32,
-    pub group: inode_unlink_event_user_group_t,
-    pub effective: inode_unlink_event_user_effective_t,
+    pub group: sched_process_fork_event_user_group_t,
+    pub effective: sched_process_fork_event_user_effective_t,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_file_t {
+pub struct sched_process_fork_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_fork_event_cgroup_t {
+    pub id: u64,
     pub path: [c_char; 256],
-    pub inode: u64,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct inode_unlink_event_t {
+pub struct sched_process_fork_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_fork_event_t {
     pub __timestamp: u64,
-    pub event: inode_unlink_event_event_t,
-    pub process: inode_unlink_event_process_t,
-    pub user: inode_unlink_event_user_t,
-    pub file: inode_unlink_event_file_t,
+    pub event: sched_process_fork_event_event_t,
+    pub process: sched_process_fork_event_process_t,
+    pub user: sched_process_fork_event_user_t,
+    pub container: sched_process_fork_event_container_t,
+    pub cgroup: sched_process_fork_event_cgroup_t,
+    pub namespace: sched_process_fork_event_namespace_t,
 }
 
-pub type inode_unlink_event_handler = extern "C" fn(ctx: *mut c_void, e: inode_unlink_event_t);
+pub type sched_process_fork_event_handler = extern "C" fn(ctx: *mut c_void, e: sched_process_fork_event_t);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_event_t {
+    pub action: [c_char; 256],
+    pub outcome: [c_char; 256],
+    pub duration: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_process_parent_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub args_count: u64,
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub ppid: u32,
+    pub start: u64,
+    pub thread__id: u64,
+    pub executable: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_process_t {
+    pub pid: u32,
+    pub entity_id: [c_char; 256],
+    pub name: [c_char; 256],
+    pub ppid: u32,
+    pub executable: [c_char; 256],
+    pub args_count: u64,
+    pub start: u64,
+    pub end: u64,
+    pub exit_code: u64,
+    pub thread__id: u64,
+    pub command_line: [c_char; 4096],
+    pub args: [[c_char; 128]; 64],
+    pub args_truncated: u32,
+    pub env_count: u64,
+    pub env: [[c_char; 256]; 8],
+    pub env_truncated: u32,
+    pub parent: sched_process_exit_event_process_parent_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_user_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_user_effective_group_t {
+    pub id: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_user_effective_t {
+    pub id: u32,
+    pub group: sched_process_exit_event_user_effective_group_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_user_t {
+    pub id: u32,
+    pub group: sched_process_exit_event_user_group_t,
+    pub effective: sched_process_exit_event_user_effective_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_container_t {
+    pub id: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_cgroup_t {
+    pub id: u64,
+    pub path: [c_char; 256],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_namespace_t {
+    pub pid: u32,
+    pub mnt: u32,
+    pub user: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sched_process_exit_event_t {
+    pub __timestamp: u64,
+    pub event: sched_process_exit_event_event_t,
+    pub process: sched_process_exit_event_process_t,
+    pub user: sched_process_exit_event_user_t,
+    pub container: sched_process_exit_event_container_t,
+    pub cgroup: sched_process_exit_event_cgroup_t,
+    pub namespace: sched_process_exit_event_namespace_t,
+}
+
+pub type sched_process_exit_event_handler = extern "C" fn(ctx: *mut c_void, e: sched_process_exit_event_t);
 
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct state_configuration {
     pub debug: bool,
+    pub capture_command_line: bool,
+    pub env_allowlist_size: u32,
+    pub env_allowlist: [env_name; 8],
     pub bprm_check_security_ctx: *mut c_void,
     pub bprm_check_security_handler: bprm_check_security_event_handler,
     pub inode_unlink_ctx: *mut c_void,
     pub inode_unlink_handler: inode_unlink_event_handler,
+    pub sb_mount_ctx: *mut c_void,
+    pub sb_mount_handler: sb_mount_event_handler,
+    pub sb_umount_ctx: *mut c_void,
+    pub sb_umount_handler: sb_umount_event_handler,
+    pub task_kill_ctx: *mut c_void,
+    pub task_kill_handler: task_kill_event_handler,
+    pub mmap_file_ctx: *mut c_void,
+    pub mmap_file_handler: mmap_file_event_handler,
+    pub file_mprotect_ctx: *mut c_void,
+    pub file_mprotect_handler: file_mprotect_event_handler,
+    pub sock_graft_ctx: *mut c_void,
+    pub sock_graft_handler: sock_graft_event_handler,
+    pub sched_process_fork_ctx: *mut c_void,
+    pub sched_process_fork_handler: sched_process_fork_event_handler,
+    pub sched_process_exit_ctx: *mut c_void,
+    pub sched_process_exit_handler: sched_process_exit_event_handler,
 }
 pub enum state {}
 extern "C" {
@@ -174,10 +1116,28 @@ extern "C" {
     pub fn poll_state(_self: *mut state, timeout: c_int);
     pub fn destroy_state(_self: *mut state);
     pub fn cache_process(_self: *mut state, pid: i32, process: *const cached_process);
+    pub fn cache_process_details(_self: *mut state, pid: i32, details: *const cached_process_details);
+    pub fn state_clock_adjustment(_self: *mut state) -> u64;
     pub fn flush_bprm_check_security_filter_rule(_self: *mut state, rule: query_bpf_bprm_check_security_event_t);
     pub fn flush_bprm_check_security_rejection_rule(_self: *mut state, rule: query_bpf_bprm_check_security_event_t);
     pub fn flush_inode_unlink_filter_rule(_self: *mut state, rule: query_bpf_inode_unlink_event_t);
     pub fn flush_inode_unlink_rejection_rule(_self: *mut state, rule: query_bpf_inode_unlink_event_t);
+    pub fn flush_sb_mount_filter_rule(_self: *mut state, rule: query_bpf_sb_mount_event_t);
+    pub fn flush_sb_mount_rejection_rule(_self: *mut state, rule: query_bpf_sb_mount_event_t);
+    pub fn flush_sb_umount_filter_rule(_self: *mut state, rule: query_bpf_sb_umount_event_t);
+    pub fn flush_sb_umount_rejection_rule(_self: *mut state, rule: query_bpf_sb_umount_event_t);
+    pub fn flush_task_kill_filter_rule(_self: *mut state, rule: query_bpf_task_kill_event_t);
+    pub fn flush_task_kill_rejection_rule(_self: *mut state, rule: query_bpf_task_kill_event_t);
+    pub fn flush_mmap_file_filter_rule(_self: *mut state, rule: query_bpf_mmap_file_event_t);
+    pub fn flush_mmap_file_rejection_rule(_self: *mut state, rule: query_bpf_mmap_file_event_t);
+    pub fn flush_file_mprotect_filter_rule(_self: *mut state, rule: query_bpf_file_mprotect_event_t);
+    pub fn flush_file_mprotect_rejection_rule(_self: *mut state, rule: query_bpf_file_mprotect_event_t);
+    pub fn flush_sock_graft_filter_rule(_self: *mut state, rule: query_bpf_sock_graft_event_t);
+    pub fn flush_sock_graft_rejection_rule(_self: *mut state, rule: query_bpf_sock_graft_event_t);
+    pub fn flush_sched_process_fork_filter_rule(_self: *mut state, rule: query_bpf_sched_process_fork_event_t);
+    pub fn flush_sched_process_fork_rejection_rule(_self: *mut state, rule: query_bpf_sched_process_fork_event_t);
+    pub fn flush_sched_process_exit_filter_rule(_self: *mut state, rule: query_bpf_sched_process_exit_event_t);
+    pub fn flush_sched_process_exit_rejection_rule(_self: *mut state, rule: query_bpf_sched_process_exit_event_t);
 }
 
 pub unsafe fn unpack_bprm_check_security_closure<F>(closure: &mut F) -> (*mut c_void, bprm_check_security_event_handler)
@@ -206,3 +1166,107 @@ where
     }
     (closure as *mut F as *mut c_void, trampoline::<F>)
 }
+pub unsafe fn unpack_sb_mount_closure<F>(closure: &mut F) -> (*mut c_void, sb_mount_event_handler)
+where
+    F: FnMut(sb_mount_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: sb_mount_event_t)
+    where
+        F: FnMut(sb_mount_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_sb_umount_closure<F>(closure: &mut F) -> (*mut c_void, sb_umount_event_handler)
+where
+    F: FnMut(sb_umount_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: sb_umount_event_t)
+    where
+        F: FnMut(sb_umount_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_task_kill_closure<F>(closure: &mut F) -> (*mut c_void, task_kill_event_handler)
+where
+    F: FnMut(task_kill_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: task_kill_event_t)
+    where
+        F: FnMut(task_kill_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_mmap_file_closure<F>(closure: &mut F) -> (*mut c_void, mmap_file_event_handler)
+where
+    F: FnMut(mmap_file_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: mmap_file_event_t)
+    where
+        F: FnMut(mmap_file_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_file_mprotect_closure<F>(closure: &mut F) -> (*mut c_void, file_mprotect_event_handler)
+where
+    F: FnMut(file_mprotect_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: file_mprotect_event_t)
+    where
+        F: FnMut(file_mprotect_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_sock_graft_closure<F>(closure: &mut F) -> (*mut c_void, sock_graft_event_handler)
+where
+    F: FnMut(sock_graft_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: sock_graft_event_t)
+    where
+        F: FnMut(sock_graft_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_sched_process_fork_closure<F>(closure: &mut F) -> (*mut c_void, sched_process_fork_event_handler)
+where
+    F: FnMut(sched_process_fork_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: sched_process_fork_event_t)
+    where
+        F: FnMut(sched_process_fork_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
+pub unsafe fn unpack_sched_process_exit_closure<F>(closure: &mut F) -> (*mut c_void, sched_process_exit_event_handler)
+where
+    F: FnMut(sched_process_exit_event_t),
+{
+    extern "C" fn trampoline<F>(data: *mut c_void, e: sched_process_exit_event_t)
+    where
+        F: FnMut(sched_process_exit_event_t),
+    {
+        let closure: &mut F = unsafe { &mut *(data as *mut F) };
+        (*closure)(e);
+    }
+    (closure as *mut F as *mut c_void, trampoline::<F>)
+}
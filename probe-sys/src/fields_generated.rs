@@ -0,0 +1,5935 @@
+#![allow(clippy::all)]
+
+use std::convert::TryFrom;
+
+use crate::enrichers::{FieldAccess, FieldValue};
+use crate::errors::{SerializableResult, SerializationError};
+use crate::struct_pb::*;
+
+impl FieldAccess for BprmCheckSecurityEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for InodeUnlinkEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "file.name" => self.file.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "file.directory" => self.file.as_ref().map(|x| FieldValue::String(x.get_directory().to_string())),
+            "file.path" => self.file.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "file.extension" => self.file.as_ref().map(|x| FieldValue::String(x.get_extension().to_string())),
+            "file.inode" => self.file.as_ref().map(|x| FieldValue::String(x.get_inode().to_string())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.name" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.directory" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_directory(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.path" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.extension" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_extension(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.inode" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_inode(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for SbMountEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "mount.source" => self.mount.as_ref().map(|x| FieldValue::String(x.get_source().to_string())),
+            "mount.target" => self.mount.as_ref().map(|x| FieldValue::String(x.get_target().to_string())),
+            "mount.fstype" => self.mount.as_ref().map(|x| FieldValue::String(x.get_fstype().to_string())),
+            "mount.flags" => self.mount.as_ref().map(|x| FieldValue::Number(x.get_flags().into())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.source" => match value {
+                FieldValue::String(v) => {
+                    self.mount.get_mut_ref().set_source(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.target" => match value {
+                FieldValue::String(v) => {
+                    self.mount.get_mut_ref().set_target(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.fstype" => match value {
+                FieldValue::String(v) => {
+                    self.mount.get_mut_ref().set_fstype(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.flags" => match value {
+                FieldValue::Number(v) => {
+                    self.mount.get_mut_ref().set_flags(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for SbUmountEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "mount.source" => self.mount.as_ref().map(|x| FieldValue::String(x.get_source().to_string())),
+            "mount.target" => self.mount.as_ref().map(|x| FieldValue::String(x.get_target().to_string())),
+            "mount.fstype" => self.mount.as_ref().map(|x| FieldValue::String(x.get_fstype().to_string())),
+            "mount.flags" => self.mount.as_ref().map(|x| FieldValue::Number(x.get_flags().into())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.source" => match value {
+                FieldValue::String(v) => {
+                    self.mount.get_mut_ref().set_source(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.target" => match value {
+                FieldValue::String(v) => {
+                    self.mount.get_mut_ref().set_target(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.fstype" => match value {
+                FieldValue::String(v) => {
+                    self.mount.get_mut_ref().set_fstype(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "mount.flags" => match value {
+                FieldValue::Number(v) => {
+                    self.mount.get_mut_ref().set_flags(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for TaskKillEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "signal.number" => self.signal.as_ref().map(|x| FieldValue::Number(x.get_number().into())),
+            "signal.name" => self.signal.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "target.process.pid" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "target.process.entity_id" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "target.process.name" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "target.process.ppid" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "target.process.executable" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "target.process.args_count" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "target.process.start" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "target.process.thread.id" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "target.process.command_line" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "target.process.args" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "target.process.args_truncated" => self.target.as_ref().and_then(|x| x.process.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "signal.number" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.signal.get_mut_ref().set_number(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "signal.name" => match value {
+                FieldValue::String(v) => {
+                    self.signal.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "target.process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.target.get_mut_ref().process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "target.process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "target.process.name" => match value {
+                FieldValue::String(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "target.process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.target.get_mut_ref().process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "target.process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "target.process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "target.process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "target.process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "target.process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "target.process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "target.process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.target.get_mut_ref().process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for MmapFileEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "file.name" => self.file.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "file.directory" => self.file.as_ref().map(|x| FieldValue::String(x.get_directory().to_string())),
+            "file.path" => self.file.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "file.extension" => self.file.as_ref().map(|x| FieldValue::String(x.get_extension().to_string())),
+            "file.inode" => self.file.as_ref().map(|x| FieldValue::String(x.get_inode().to_string())),
+            "memory.anonymous" => self.memory.as_ref().map(|x| FieldValue::Bool(x.get_anonymous())),
+            "memory.protection" => self.memory.as_ref().map(|x| FieldValue::Number(x.get_protection().into())),
+            "memory.flags" => self.memory.as_ref().map(|x| FieldValue::Number(x.get_flags().into())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.name" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.directory" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_directory(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.path" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.extension" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_extension(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.inode" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_inode(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "memory.anonymous" => match value {
+                FieldValue::Bool(v) => {
+                    self.memory.get_mut_ref().set_anonymous(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "memory.protection" => match value {
+                FieldValue::Number(v) => {
+                    self.memory.get_mut_ref().set_protection(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "memory.flags" => match value {
+                FieldValue::Number(v) => {
+                    self.memory.get_mut_ref().set_flags(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for FileMprotectEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "file.name" => self.file.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "file.directory" => self.file.as_ref().map(|x| FieldValue::String(x.get_directory().to_string())),
+            "file.path" => self.file.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "file.extension" => self.file.as_ref().map(|x| FieldValue::String(x.get_extension().to_string())),
+            "file.inode" => self.file.as_ref().map(|x| FieldValue::String(x.get_inode().to_string())),
+            "memory.anonymous" => self.memory.as_ref().map(|x| FieldValue::Bool(x.get_anonymous())),
+            "memory.protection" => self.memory.as_ref().map(|x| FieldValue::Number(x.get_protection().into())),
+            "memory.address" => self.memory.as_ref().map(|x| FieldValue::Number(x.get_address().into())),
+            "memory.size" => self.memory.as_ref().map(|x| FieldValue::Number(x.get_size().into())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.name" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.directory" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_directory(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.path" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.extension" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_extension(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "file.inode" => match value {
+                FieldValue::String(v) => {
+                    self.file.get_mut_ref().set_inode(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "memory.anonymous" => match value {
+                FieldValue::Bool(v) => {
+                    self.memory.get_mut_ref().set_anonymous(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "memory.protection" => match value {
+                FieldValue::Number(v) => {
+                    self.memory.get_mut_ref().set_protection(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "memory.address" => match value {
+                FieldValue::Number(v) => {
+                    self.memory.get_mut_ref().set_address(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "memory.size" => match value {
+                FieldValue::Number(v) => {
+                    self.memory.get_mut_ref().set_size(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for SockGraftEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "source.ip" => self.source.as_ref().map(|x| FieldValue::String(x.get_ip().to_string())),
+            "source.port" => self.source.as_ref().map(|x| FieldValue::Number(x.get_port().into())),
+            "destination.ip" => self.destination.as_ref().map(|x| FieldValue::String(x.get_ip().to_string())),
+            "destination.port" => self.destination.as_ref().map(|x| FieldValue::Number(x.get_port().into())),
+            "network.iana_number" => self.network.as_ref().map(|x| FieldValue::String(x.get_iana_number().to_string())),
+            "network.transport" => self.network.as_ref().map(|x| FieldValue::String(x.get_transport().to_string())),
+            "network.type" => self.network.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "network.direction" => self.network.as_ref().map(|x| FieldValue::String(x.get_direction().to_string())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "source.ip" => match value {
+                FieldValue::String(v) => {
+                    self.source.get_mut_ref().set_ip(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "source.port" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.source.get_mut_ref().set_port(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "destination.ip" => match value {
+                FieldValue::String(v) => {
+                    self.destination.get_mut_ref().set_ip(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "destination.port" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.destination.get_mut_ref().set_port(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "network.iana_number" => match value {
+                FieldValue::String(v) => {
+                    self.network.get_mut_ref().set_iana_number(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "network.transport" => match value {
+                FieldValue::String(v) => {
+                    self.network.get_mut_ref().set_transport(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "network.type" => match value {
+                FieldValue::String(v) => {
+                    self.network.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "network.direction" => match value {
+                FieldValue::String(v) => {
+                    self.network.get_mut_ref().set_direction(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for SchedProcessForkEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
+impl FieldAccess for SchedProcessExitEvent {
+    fn get_field(&self, path: &str) -> Option<FieldValue> {
+        match path {
+            "event.id" => self.event.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "event.kind" => self.event.as_ref().map(|x| FieldValue::String(x.get_kind().to_string())),
+            "event.category" => self.event.as_ref().map(|x| FieldValue::String(x.get_category().to_string())),
+            "event.action" => self.event.as_ref().map(|x| FieldValue::String(x.get_action().to_string())),
+            "event.type" => self.event.as_ref().map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "event.outcome" => self.event.as_ref().map(|x| FieldValue::String(x.get_outcome().to_string())),
+            "event.module" => self.event.as_ref().map(|x| FieldValue::String(x.get_module().to_string())),
+            "event.provider" => self.event.as_ref().map(|x| FieldValue::String(x.get_provider().to_string())),
+            "event.sequence" => self.event.as_ref().map(|x| FieldValue::Number(x.get_sequence().into())),
+            "event.ingested" => self.event.as_ref().map(|x| FieldValue::Number(x.get_ingested().into())),
+            "event.duration" => self.event.as_ref().map(|x| FieldValue::Number(x.get_duration().into())),
+            "host.os.type" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_field_type().to_string())),
+            "host.os.name" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "host.os.kernel" => self.host.as_ref().and_then(|x| x.os.as_ref()).map(|x| FieldValue::String(x.get_kernel().to_string())),
+            "host.id" => self.host.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "host.hostname" => self.host.as_ref().map(|x| FieldValue::String(x.get_hostname().to_string())),
+            "host.ip" => self.host.as_ref().map(|x| FieldValue::Strings(x.ip.to_vec())),
+            "host.mac" => self.host.as_ref().map(|x| FieldValue::Strings(x.mac.to_vec())),
+            "host.uptime" => self.host.as_ref().map(|x| FieldValue::Number(x.get_uptime().into())),
+            "process.hash.sha256" => self.process.as_ref().and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.ancestry.entity_id" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.entity_id.to_vec())),
+            "process.ancestry.name" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.name.to_vec())),
+            "process.ancestry.executable" => self.process.as_ref().and_then(|x| x.ancestry.as_ref()).map(|x| FieldValue::Strings(x.executable.to_vec())),
+            "process.parent.hash.sha256" => self.process.as_ref().and_then(|x| x.parent.as_ref()).and_then(|x| x.hash.as_ref()).map(|x| FieldValue::String(x.get_sha256().to_string())),
+            "process.parent.pid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.parent.entity_id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.parent.name" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.parent.args_count" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.parent.args" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.parent.args_truncated" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.parent.ppid" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.parent.start" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_start().into())),
+            "process.parent.thread.id" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.parent.executable" => self.process.as_ref().and_then(|x| x.parent.as_ref()).map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.pid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "process.entity_id" => self.process.as_ref().map(|x| FieldValue::String(x.get_entity_id().to_string())),
+            "process.name" => self.process.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "process.ppid" => self.process.as_ref().map(|x| FieldValue::Number(x.get_ppid().into())),
+            "process.executable" => self.process.as_ref().map(|x| FieldValue::String(x.get_executable().to_string())),
+            "process.args_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_args_count().into())),
+            "process.start" => self.process.as_ref().map(|x| FieldValue::Number(x.get_start().into())),
+            "process.end" => self.process.as_ref().map(|x| FieldValue::Number(x.get_end().into())),
+            "process.exit_code" => self.process.as_ref().map(|x| FieldValue::Number(x.get_exit_code().into())),
+            "process.thread.id" => self.process.as_ref().map(|x| FieldValue::Number(x.get_thread_id().into())),
+            "process.command_line" => self.process.as_ref().map(|x| FieldValue::String(x.get_command_line().to_string())),
+            "process.args" => self.process.as_ref().map(|x| FieldValue::Strings(x.args.to_vec())),
+            "process.args_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_args_truncated())),
+            "process.env_count" => self.process.as_ref().map(|x| FieldValue::Number(x.get_env_count().into())),
+            "process.env" => self.process.as_ref().map(|x| FieldValue::Strings(x.env.to_vec())),
+            "process.env_truncated" => self.process.as_ref().map(|x| FieldValue::Bool(x.get_env_truncated())),
+            "user.group.id" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.group.name" => self.user.as_ref().and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.group.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.group.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).and_then(|x| x.group.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.effective.id" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.effective.name" => self.user.as_ref().and_then(|x| x.effective.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "user.id" => self.user.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "user.name" => self.user.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.name" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_name().to_string())),
+            "container.image.tag" => self.container.as_ref().and_then(|x| x.image.as_ref()).map(|x| FieldValue::String(x.get_tag().to_string())),
+            "container.id" => self.container.as_ref().map(|x| FieldValue::String(x.get_id().to_string())),
+            "container.runtime" => self.container.as_ref().map(|x| FieldValue::String(x.get_runtime().to_string())),
+            "container.name" => self.container.as_ref().map(|x| FieldValue::String(x.get_name().to_string())),
+            "cgroup.id" => self.cgroup.as_ref().map(|x| FieldValue::Number(x.get_id().into())),
+            "cgroup.path" => self.cgroup.as_ref().map(|x| FieldValue::String(x.get_path().to_string())),
+            "namespace.pid" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_pid().into())),
+            "namespace.mnt" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_mnt().into())),
+            "namespace.user" => self.namespace.as_ref().map(|x| FieldValue::Number(x.get_user().into())),
+            "@timestamp" => Some(FieldValue::Number(self.get_timestamp().into())),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, path: &str, value: FieldValue) -> SerializableResult<()> {
+        match path {
+            "event.id" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.kind" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_kind(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.category" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_category(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.action" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_action(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.type" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.outcome" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_outcome(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.module" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_module(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.provider" => match value {
+                FieldValue::String(v) => {
+                    self.event.get_mut_ref().set_provider(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "event.sequence" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_sequence(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.ingested" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_ingested(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "event.duration" => match value {
+                FieldValue::Number(v) => {
+                    self.event.get_mut_ref().set_duration(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "host.os.type" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_field_type(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.name" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.os.kernel" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().os.get_mut_ref().set_kernel(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.id" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.hostname" => match value {
+                FieldValue::String(v) => {
+                    self.host.get_mut_ref().set_hostname(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "host.ip" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().ip = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.mac" => match value {
+                FieldValue::Strings(v) => {
+                    self.host.get_mut_ref().mac = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "host.uptime" => match value {
+                FieldValue::Number(v) => {
+                    self.host.get_mut_ref().set_uptime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ancestry.entity_id" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().entity_id = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.name" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().name = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.ancestry.executable" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().ancestry.get_mut_ref().executable = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.hash.sha256" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().hash.get_mut_ref().set_sha256(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.parent.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.parent.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.parent.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().parent.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.parent.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.parent.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().parent.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.entity_id" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_entity_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.name" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.ppid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.process.get_mut_ref().set_ppid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "process.executable" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_executable(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_args_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.start" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_start(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.end" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_end(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.exit_code" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_exit_code(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.thread.id" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_thread_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.command_line" => match value {
+                FieldValue::String(v) => {
+                    self.process.get_mut_ref().set_command_line(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "process.args" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().args = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.args_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_args_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "process.env_count" => match value {
+                FieldValue::Number(v) => {
+                    self.process.get_mut_ref().set_env_count(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "process.env" => match value {
+                FieldValue::Strings(v) => {
+                    self.process.get_mut_ref().env = v.into();
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string array")),
+            },
+            "process.env_truncated" => match value {
+                FieldValue::Bool(v) => {
+                    self.process.get_mut_ref().set_env_truncated(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "bool")),
+            },
+            "user.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.group.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().group.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.effective.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().effective.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.id" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "user.name" => match value {
+                FieldValue::String(v) => {
+                    self.user.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.image.tag" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().image.get_mut_ref().set_tag(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.id" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.runtime" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_runtime(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "container.name" => match value {
+                FieldValue::String(v) => {
+                    self.container.get_mut_ref().set_name(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "cgroup.id" => match value {
+                FieldValue::Number(v) => {
+                    self.cgroup.get_mut_ref().set_id(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            "cgroup.path" => match value {
+                FieldValue::String(v) => {
+                    self.cgroup.get_mut_ref().set_path(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "string")),
+            },
+            "namespace.pid" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_pid(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.mnt" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_mnt(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "namespace.user" => match value {
+                FieldValue::Number(v) => {
+                    let v = u32::try_from(v).map_err(|_| SerializationError::FieldType(path.to_string(), "u32"))?;
+                    self.namespace.get_mut_ref().set_user(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u32")),
+            },
+            "@timestamp" => match value {
+                FieldValue::Number(v) => {
+                    self.set_timestamp(v);
+                    Ok(())
+                }
+                _ => Err(SerializationError::FieldType(path.to_string(), "u64")),
+            },
+            _ => Err(SerializationError::UnknownField(path.to_string())),
+        }
+    }
+}
+
@@ -1,8 +1,48 @@
+use once_cell::sync::Lazy;
 use rule_compiler::Operator;
+use sha2::Digest;
 use std::ffi::CStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::os::raw::c_char;
 
 use crate::constants::{EQUAL_OPERATOR, FALSE_ABSOLUTE, NOT_EQUAL_OPERATOR, TRUE_ABSOLUTE};
+use crate::errors::{SerializableResult, SerializationError};
+
+static MACHINE_ID: Lazy<Result<String, String>> =
+    Lazy::new(|| machine_uid::get().map_err(|e| e.to_string()));
+
+pub(crate) fn machine_id() -> SerializableResult<&'static str> {
+    MACHINE_ID
+        .as_ref()
+        .map(String::as_str)
+        .map_err(|e| SerializationError::MachineId(e.clone()))
+}
+
+/// Computes the entity id for a process, a hash of the machine id,
+/// the pid, and the process start time.
+pub fn entity_id(pid: u32, start: u64) -> SerializableResult<String> {
+    let id = format!("{}{}{}", machine_id()?, format!("{:01$}", pid, 5), start);
+    Ok(format!("{:x}", sha2::Sha256::digest(id.as_bytes())))
+}
+
+// the kernel reports process start times in clock ticks of USER_HZ
+const NANOS_PER_TICK: u64 = 1_000_000_000 / 100;
+
+/// The start time of a process in seconds since the epoch, computed from
+/// its /proc/<pid>/stat the same way the probe computes it from the task.
+pub fn process_start(stat: &str, clock_adjustment: u64) -> Option<u64> {
+    // the command name can contain spaces and parentheses of its own
+    let fields = &stat[stat.rfind(')')? + 1..];
+    // starttime is the 22nd field, the 20th one after the command name
+    let ticks: u64 = fields.split_whitespace().nth(19)?.parse().ok()?;
+    Some((ticks * NANOS_PER_TICK + clock_adjustment) / 1_000_000_000)
+}
+
+pub(crate) fn parse_id(field: &'static str, value: &str) -> SerializableResult<u32> {
+    value
+        .parse::<u32>()
+        .map_err(|_| SerializationError::InvalidField(field, value.to_string()))
+}
 
 pub(crate) fn transform_string(val: Vec<c_char>) -> String {
     unsafe { CStr::from_ptr(val.as_ptr()).to_string_lossy().into_owned() }
@@ -16,7 +56,7 @@ where
     unsafe {
         let mut strings = vec![];
         for x in 0..size {
-            if size as usize >= max_length {
+            if x as usize >= max_length {
                 break
             }
             let ptr: Vec<c_char> = arr[x as usize].into();
@@ -32,6 +72,19 @@ pub(crate) fn int_to_string(v: u64) -> String {
     v.to_string()
 }
 
+pub(crate) fn int_to_bool(v: u64) -> bool {
+    v != 0
+}
+
+pub(crate) fn ip_to_string(v: [u8; 16]) -> String {
+    let ip = Ipv6Addr::from(v);
+    // ipv4 addresses are stored as ipv4-mapped ipv6 addresses
+    match ip.segments() {
+        [0, 0, 0, 0, 0, 0xffff, ..] => Ipv4Addr::new(v[12], v[13], v[14], v[15]).to_string(),
+        _ => ip.to_string(),
+    }
+}
+
 pub(crate) fn operator_to_constant(operator: Operator) -> u8 {
     match operator {
         Operator::Equal => EQUAL_OPERATOR,
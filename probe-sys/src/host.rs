@@ -0,0 +1,82 @@
+use pnet::datalink::interfaces;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sysinfo::{System, SystemExt};
+
+use crate::helpers::machine_id;
+
+// interfaces and hostnames change rarely enough that picking them up
+// within a minute is fine
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub(crate) struct HostMetadata {
+    pub id: Option<String>,
+    pub hostname: Option<String>,
+    pub os_name: Option<String>,
+    pub kernel: Option<String>,
+    pub boot_time: u64,
+    pub ips: Vec<String>,
+    pub macs: Vec<String>,
+}
+
+impl HostMetadata {
+    fn collect() -> Self {
+        let system = System::new();
+        let mut metadata = Self {
+            id: machine_id().ok().map(String::from),
+            hostname: system.get_host_name(),
+            os_name: system.get_name(),
+            kernel: system.get_kernel_version(),
+            boot_time: system.get_boot_time(),
+            ..Default::default()
+        };
+        let all_interfaces = interfaces();
+        let active_interfaces = all_interfaces
+            .iter()
+            .filter(|e| e.is_up() && !e.is_loopback() && !e.ips.is_empty());
+        for interface in active_interfaces {
+            if let Some(mac) = interface.mac {
+                metadata.macs.push(mac.to_string());
+            }
+            for ip in &interface.ips {
+                metadata.ips.push(ip.ip().to_string());
+            }
+        }
+        metadata
+    }
+
+    pub fn uptime(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs())
+            .saturating_sub(self.boot_time)
+    }
+}
+
+pub(crate) struct HostMetadataProvider {
+    current: RwLock<(Instant, Arc<HostMetadata>)>,
+}
+
+impl HostMetadataProvider {
+    pub fn new() -> Self {
+        Self {
+            current: RwLock::new((Instant::now(), Arc::new(HostMetadata::collect()))),
+        }
+    }
+
+    pub fn get(&self) -> Arc<HostMetadata> {
+        {
+            let current = self.current.read().unwrap_or_else(PoisonError::into_inner);
+            if current.0.elapsed() < REFRESH_INTERVAL {
+                return current.1.clone();
+            }
+        }
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        // another worker may have refreshed while we waited on the lock
+        if current.0.elapsed() >= REFRESH_INTERVAL {
+            *current = (Instant::now(), Arc::new(HostMetadata::collect()));
+        }
+        current.1.clone()
+    }
+}
@@ -1,10 +1,17 @@
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
-use users::UsersCache;
 
+use crate::host::HostMetadataProvider;
+use crate::process_table::ProcessTable;
+
+mod accounts;
 mod constants;
+mod containers;
+mod enrichers;
 mod errors;
 mod helpers;
+mod host;
+mod process_table;
 mod query_writer;
 mod traits;
 
@@ -19,19 +26,30 @@ mod compiler_generated;
 #[allow(non_snake_case)]
 mod ffi_generated;
 #[rustfmt::skip]
+mod fields_generated;
+#[rustfmt::skip]
 mod probe_generated;
 #[rustfmt::skip]
 mod serial_generated;
 #[rustfmt::skip]
 mod transform_generated;
 
+pub use accounts::{AccountCache, AccountSource, SystemAccounts};
+pub use containers::{Container, ContainerCache};
+pub use enrichers::{Enricher, EnricherChain, FieldAccess, FieldValue};
 pub use errors::{Error, SerializableResult, SerializationError};
+pub use helpers::{entity_id, process_start};
 pub use probe_generated::Probe;
 pub use serial_generated::*;
 pub use struct_pb::*;
 pub use traits::{ProbeHandler, SerializableEvent};
-pub use transform_generated::{TransformationHandler, Transformer};
+pub use transform_generated::Transformer;
 // for tests
 pub use query_writer::BpfQueryWriterFactory;
 
-static USERS_CACHE: Lazy<Mutex<UsersCache>> = Lazy::new(|| Mutex::new(UsersCache::new()));
+static USERS_CACHE: Lazy<Mutex<AccountCache<SystemAccounts>>> =
+    Lazy::new(|| Mutex::new(AccountCache::new(SystemAccounts::new())));
+static CONTAINERS_CACHE: Lazy<Mutex<ContainerCache>> =
+    Lazy::new(|| Mutex::new(ContainerCache::new()));
+static PROCESS_TABLE: Lazy<Mutex<ProcessTable>> = Lazy::new(|| Mutex::new(ProcessTable::new()));
+static HOST_METADATA: Lazy<HostMetadataProvider> = Lazy::new(HostMetadataProvider::new);
@@ -3,6 +3,7 @@
 use log::{debug, warn};
 use rule_compiler::{compile, Operation};
 use std::convert::TryInto;
+use std::fs;
 use std::mem::transmute_copy;
 use std::os::raw::c_int;
 use std::os::unix::ffi::OsStrExt;
@@ -12,6 +13,7 @@ use sysinfo::{ProcessExt, System, SystemExt};
 
 use crate::errors::Error;
 use crate::ffi_generated as ffi;
+use crate::helpers::{entity_id, process_start};
 use crate::query_writer::BpfQueryWriterFactory;
 use crate::struct_pb;
 use crate::traits::{ProbeHandler, QueryStruct};
@@ -22,7 +24,18 @@ pub struct Probe<'a> {
     // the same lifetime as the state wrapper
     _bprm_check_security_handler: Option<Box<dyn 'a + Fn(ffi::bprm_check_security_event_t)>>,
     _inode_unlink_handler: Option<Box<dyn 'a + Fn(ffi::inode_unlink_event_t)>>,
+    _sb_mount_handler: Option<Box<dyn 'a + Fn(ffi::sb_mount_event_t)>>,
+    _sb_umount_handler: Option<Box<dyn 'a + Fn(ffi::sb_umount_event_t)>>,
+    _task_kill_handler: Option<Box<dyn 'a + Fn(ffi::task_kill_event_t)>>,
+    _mmap_file_handler: Option<Box<dyn 'a + Fn(ffi::mmap_file_event_t)>>,
+    _file_mprotect_handler: Option<Box<dyn 'a + Fn(ffi::file_mprotect_event_t)>>,
+    _sock_graft_handler: Option<Box<dyn 'a + Fn(ffi::sock_graft_event_t)>>,
+    _sched_process_fork_handler: Option<Box<dyn 'a + Fn(ffi::sched_process_fork_event_t)>>,
+    _sched_process_exit_handler: Option<Box<dyn 'a + Fn(ffi::sched_process_exit_event_t)>>,
     debug: bool,
+    command_line: bool,
+    environment: Vec<String>,
+    ancestry_depth: usize,
 }
 
 impl<'a> Probe<'a> {
@@ -31,7 +44,18 @@ impl<'a> Probe<'a> {
             ctx: None,
             _bprm_check_security_handler: None,
             _inode_unlink_handler: None,
+            _sb_mount_handler: None,
+            _sb_umount_handler: None,
+            _task_kill_handler: None,
+            _mmap_file_handler: None,
+            _file_mprotect_handler: None,
+            _sock_graft_handler: None,
+            _sched_process_fork_handler: None,
+            _sched_process_exit_handler: None,
             debug: false,
+            command_line: false,
+            environment: vec![],
+            ancestry_depth: 8,
         }
     }
 
@@ -49,6 +73,21 @@ impl<'a> Probe<'a> {
         self
     }
 
+    pub fn command_line(&mut self, capture: bool) -> &mut Self {
+        self.command_line = capture;
+        self
+    }
+
+    pub fn environment(&mut self, allowlist: Vec<String>) -> &mut Self {
+        self.environment = allowlist;
+        self
+    }
+
+    pub fn ancestry_depth(&mut self, depth: usize) -> &mut Self {
+        self.ancestry_depth = depth;
+        self
+    }
+
     pub fn run<F: 'a, U>(&mut self, handler: F) -> Result<&mut Self, Error>
     where
         F: 'a + ProbeHandler<U> + panic::RefUnwindSafe + Copy,
@@ -56,8 +95,14 @@ impl<'a> Probe<'a> {
     {
         let mut bprm_check_security_wrapper = move |e: ffi::bprm_check_security_event_t| {
             let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::BprmCheckSecurityEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
                 handler
-                    .enqueue(&mut struct_pb::BprmCheckSecurityEvent::from(e))
+                    .enqueue(&mut event)
                     .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
             });
             if result.is_err() {
@@ -68,8 +113,14 @@ impl<'a> Probe<'a> {
             unsafe { ffi::unpack_bprm_check_security_closure(&mut bprm_check_security_wrapper) };
         let mut inode_unlink_wrapper = move |e: ffi::inode_unlink_event_t| {
             let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::InodeUnlinkEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
                 handler
-                    .enqueue(&mut struct_pb::InodeUnlinkEvent::from(e))
+                    .enqueue(&mut event)
                     .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
             });
             if result.is_err() {
@@ -78,12 +129,193 @@ impl<'a> Probe<'a> {
         };
         let (inode_unlink_closure, inode_unlink_callback) =
             unsafe { ffi::unpack_inode_unlink_closure(&mut inode_unlink_wrapper) };
+        let mut sb_mount_wrapper = move |e: ffi::sb_mount_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::SbMountEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (sb_mount_closure, sb_mount_callback) =
+            unsafe { ffi::unpack_sb_mount_closure(&mut sb_mount_wrapper) };
+        let mut sb_umount_wrapper = move |e: ffi::sb_umount_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::SbUmountEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (sb_umount_closure, sb_umount_callback) =
+            unsafe { ffi::unpack_sb_umount_closure(&mut sb_umount_wrapper) };
+        let mut task_kill_wrapper = move |e: ffi::task_kill_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::TaskKillEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (task_kill_closure, task_kill_callback) =
+            unsafe { ffi::unpack_task_kill_closure(&mut task_kill_wrapper) };
+        let mut mmap_file_wrapper = move |e: ffi::mmap_file_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::MmapFileEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (mmap_file_closure, mmap_file_callback) =
+            unsafe { ffi::unpack_mmap_file_closure(&mut mmap_file_wrapper) };
+        let mut file_mprotect_wrapper = move |e: ffi::file_mprotect_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::FileMprotectEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (file_mprotect_closure, file_mprotect_callback) =
+            unsafe { ffi::unpack_file_mprotect_closure(&mut file_mprotect_wrapper) };
+        let mut sock_graft_wrapper = move |e: ffi::sock_graft_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::SockGraftEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (sock_graft_closure, sock_graft_callback) =
+            unsafe { ffi::unpack_sock_graft_closure(&mut sock_graft_wrapper) };
+        let mut sched_process_fork_wrapper = move |e: ffi::sched_process_fork_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::SchedProcessForkEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (sched_process_fork_closure, sched_process_fork_callback) =
+            unsafe { ffi::unpack_sched_process_fork_closure(&mut sched_process_fork_wrapper) };
+        let mut sched_process_exit_wrapper = move |e: ffi::sched_process_exit_event_t| {
+            let result = panic::catch_unwind(|| {
+                let mut event = struct_pb::SchedProcessExitEvent::from(e);
+                // track processes in the order the kernel reported them rather
+                // than the order the transformation workers get to them
+                if let Err(e) = event.track_process() {
+                    debug!("could not track process: {}", e);
+                }
+                handler
+                    .enqueue(&mut event)
+                    .unwrap_or_else(|e| warn!("error enqueuing data: {}", e));
+            });
+            if result.is_err() {
+                debug!("panic while handling event");
+            }
+        };
+        let (sched_process_exit_closure, sched_process_exit_callback) =
+            unsafe { ffi::unpack_sched_process_exit_closure(&mut sched_process_exit_wrapper) };
+        let mut env_allowlist: [ffi::env_name; 8] = Default::default();
+        let mut allowed_env: Vec<&str> = vec![];
+        for name in &self.environment {
+            if allowed_env.len() >= env_allowlist.len() {
+                warn!("only {} environment variables can be captured, ignoring {}", env_allowlist.len(), name);
+                continue;
+            }
+            if name.is_empty() || name.len() >= 64 || name.contains('=') {
+                warn!("ignoring invalid environment variable name {}", name);
+                continue;
+            }
+            let entry = &mut env_allowlist[allowed_env.len()];
+            for (dest, src) in entry.value.iter_mut().zip(name.as_bytes().iter()) {
+                *dest = *src as _;
+            }
+            entry.len = name.len() as u32;
+            allowed_env.push(name.as_str());
+        }
         let state_config = ffi::state_configuration {
             debug: self.debug,
+            capture_command_line: self.command_line,
+            env_allowlist_size: allowed_env.len() as u32,
+            env_allowlist: env_allowlist,
             bprm_check_security_ctx: bprm_check_security_closure,
             bprm_check_security_handler: bprm_check_security_callback,
             inode_unlink_ctx: inode_unlink_closure,
             inode_unlink_handler: inode_unlink_callback,
+            sb_mount_ctx: sb_mount_closure,
+            sb_mount_handler: sb_mount_callback,
+            sb_umount_ctx: sb_umount_closure,
+            sb_umount_handler: sb_umount_callback,
+            task_kill_ctx: task_kill_closure,
+            task_kill_handler: task_kill_callback,
+            mmap_file_ctx: mmap_file_closure,
+            mmap_file_handler: mmap_file_callback,
+            file_mprotect_ctx: file_mprotect_closure,
+            file_mprotect_handler: file_mprotect_callback,
+            sock_graft_ctx: sock_graft_closure,
+            sock_graft_handler: sock_graft_callback,
+            sched_process_fork_ctx: sched_process_fork_closure,
+            sched_process_fork_handler: sched_process_fork_callback,
+            sched_process_exit_ctx: sched_process_exit_closure,
+            sched_process_exit_handler: sched_process_exit_callback,
         };
         let state = unsafe { ffi::new_state(state_config) };
         if state.is_null() {
@@ -92,7 +324,28 @@ impl<'a> Probe<'a> {
         let mut system = System::new();
         system.refresh_processes();
         let empty_path = Path::new("");
+        let mut process_table = crate::PROCESS_TABLE.lock().unwrap();
+        process_table.set_ancestry_depth(self.ancestry_depth);
+        // derive start times the way the probe does, so that the entity ids
+        // of processes that were already running match the ones in events
+        let clock_adjustment = unsafe { ffi::state_clock_adjustment(state) };
+        let seeded_entity_id = |pid: i32| {
+            fs::read_to_string(format!("/proc/{}/stat", pid))
+                .ok()
+                .and_then(|stat| process_start(&stat, clock_adjustment))
+                .and_then(|start| entity_id(pid as u32, start).ok())
+        };
         for (pid, process) in system.get_processes() {
+            let parent_entity_id = process.parent().and_then(seeded_entity_id);
+            if let Some(process_entity_id) = seeded_entity_id(*pid) {
+                process_table.observe(
+                    process_entity_id,
+                    parent_entity_id,
+                    process.name(),
+                    &process.exe().to_string_lossy(),
+                );
+            }
+
             let exe = process.exe();
             if exe == empty_path {
                 continue;
@@ -115,14 +368,49 @@ impl<'a> Probe<'a> {
                 }
             }
 
-            if cached.args_count < cmd.len().try_into().unwrap() {
+            if cached.args_count < cmd.len().try_into().unwrap() || cmd.iter().any(|arg| arg.len() >= 128) {
+                cached.truncated = 1;
+            }
+            let command_line = cmd.join(" ");
+            if self.command_line && command_line.len() > 4095 {
                 cached.truncated = 1;
             }
             unsafe { ffi::cache_process(state, *pid as i32, &cached) };
+
+            if self.command_line || !allowed_env.is_empty() {
+                let mut details: ffi::cached_process_details = Default::default();
+                if self.command_line {
+                    for (dest, src) in details.command_line.iter_mut().take(4095).zip(command_line.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                }
+                let env: Vec<&String> = process.environ().iter().filter(|variable| {
+                    let name = variable.split('=').next().unwrap_or("");
+                    allowed_env.contains(&name)
+                }).collect();
+                if env.len() > details.env.len() || env.iter().any(|variable| variable.len() > 255) {
+                    details.env_truncated = 1;
+                }
+                for (e, variable) in details.env.iter_mut().zip(env) {
+                    for (dest, src) in e.iter_mut().take(255).zip(variable.as_bytes().iter()) {
+                        *dest = *src as _;
+                    }
+                    details.env_count += 1;
+                }
+                unsafe { ffi::cache_process_details(state, *pid as i32, &details) };
+            }
         }
         self.ctx = Some(state);
         self._bprm_check_security_handler = Some(Box::new(bprm_check_security_wrapper));
         self._inode_unlink_handler = Some(Box::new(inode_unlink_wrapper));
+        self._sb_mount_handler = Some(Box::new(sb_mount_wrapper));
+        self._sb_umount_handler = Some(Box::new(sb_umount_wrapper));
+        self._task_kill_handler = Some(Box::new(task_kill_wrapper));
+        self._mmap_file_handler = Some(Box::new(mmap_file_wrapper));
+        self._file_mprotect_handler = Some(Box::new(file_mprotect_wrapper));
+        self._sock_graft_handler = Some(Box::new(sock_graft_wrapper));
+        self._sched_process_fork_handler = Some(Box::new(sched_process_fork_wrapper));
+        self._sched_process_exit_handler = Some(Box::new(sched_process_exit_wrapper));
         Ok(self)
     }
 
@@ -143,12 +431,74 @@ impl<'a> Probe<'a> {
                     let rule = transmute_copy(&rule);
                     ffi::flush_inode_unlink_rejection_rule(ctx, rule);
                 },
+                ("sb_mount", Operation::Filter) => unsafe {
+                    ffi::flush_sb_mount_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("sb_mount", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_sb_mount_rejection_rule(ctx, rule);
+                },
+                ("sb_umount", Operation::Filter) => unsafe {
+                    ffi::flush_sb_umount_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("sb_umount", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_sb_umount_rejection_rule(ctx, rule);
+                },
+                ("task_kill", Operation::Filter) => unsafe {
+                    ffi::flush_task_kill_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("task_kill", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_task_kill_rejection_rule(ctx, rule);
+                },
+                ("mmap_file", Operation::Filter) => unsafe {
+                    ffi::flush_mmap_file_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("mmap_file", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_mmap_file_rejection_rule(ctx, rule);
+                },
+                ("file_mprotect", Operation::Filter) => unsafe {
+                    ffi::flush_file_mprotect_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("file_mprotect", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_file_mprotect_rejection_rule(ctx, rule);
+                },
+                ("sock_graft", Operation::Filter) => unsafe {
+                    ffi::flush_sock_graft_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("sock_graft", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_sock_graft_rejection_rule(ctx, rule);
+                },
+                ("sched_process_fork", Operation::Filter) => unsafe {
+                    ffi::flush_sched_process_fork_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("sched_process_fork", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_sched_process_fork_rejection_rule(ctx, rule);
+                },
+                ("sched_process_exit", Operation::Filter) => unsafe {
+                    ffi::flush_sched_process_exit_filter_rule(ctx, transmute_copy(&rule));
+                },
+                ("sched_process_exit", Operation::Reject) => unsafe {
+                    let rule = transmute_copy(&rule);
+                    ffi::flush_sched_process_exit_rejection_rule(ctx, rule);
+                },
                 _ => return,
             },
             _ => return,
         }
     }
 
+    /// The offset from boot time to wall clock time the probe adjusts
+    /// timestamps by, once it's running.
+    pub fn clock_adjustment(&self) -> Option<u64> {
+        self.ctx.map(|ctx| unsafe { ffi::state_clock_adjustment(ctx) })
+    }
+
     pub fn poll(&self, timeout: i32) {
         match self.ctx {
             Some(ctx) => unsafe { ffi::poll_state(ctx, timeout as c_int) },
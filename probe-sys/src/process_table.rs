@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+const MAX_TRACKED_PROCESSES: usize = 1 << 16;
+const DEFAULT_ANCESTRY_DEPTH: usize = 8;
+
+#[derive(Clone, Default)]
+pub(crate) struct TrackedProcess {
+    pub entity_id: String,
+    pub parent_entity_id: Option<String>,
+    pub name: String,
+    pub executable: String,
+    pub exited: bool,
+}
+
+pub(crate) struct ProcessTable {
+    processes: HashMap<String, TrackedProcess>,
+    ancestry_depth: usize,
+}
+
+impl ProcessTable {
+    pub fn new() -> Self {
+        Self {
+            processes: HashMap::new(),
+            ancestry_depth: DEFAULT_ANCESTRY_DEPTH,
+        }
+    }
+
+    pub fn set_ancestry_depth(&mut self, depth: usize) {
+        self.ancestry_depth = depth;
+    }
+
+    // records what we currently know about a process, anything we don't know
+    // (an empty name or a missing parent) keeps the previously recorded value
+    pub fn observe(
+        &mut self,
+        entity_id: String,
+        parent_entity_id: Option<String>,
+        name: &str,
+        executable: &str,
+    ) {
+        if !self.processes.contains_key(&entity_id) && self.processes.len() >= MAX_TRACKED_PROCESSES
+        {
+            // exited processes are only kept around so that their descendants
+            // still have a full ancestry, they're the first to go
+            self.processes.retain(|_, p| !p.exited);
+            if self.processes.len() >= MAX_TRACKED_PROCESSES {
+                self.processes.clear();
+            }
+        }
+        let process = self
+            .processes
+            .entry(entity_id.clone())
+            .or_insert_with(|| TrackedProcess {
+                entity_id,
+                ..Default::default()
+            });
+        if parent_entity_id.is_some() {
+            process.parent_entity_id = parent_entity_id;
+        }
+        if !name.is_empty() {
+            process.name = name.to_string();
+        }
+        if !executable.is_empty() {
+            process.executable = executable.to_string();
+        }
+    }
+
+    pub fn exit(&mut self, entity_id: &str) {
+        if let Some(process) = self.processes.get_mut(entity_id) {
+            process.exited = true;
+        }
+    }
+
+    // walks up the parent links of a process, nearest ancestor first
+    pub fn ancestry(&self, entity_id: &str) -> Vec<TrackedProcess> {
+        let mut ancestors = vec![];
+        let mut current = self
+            .processes
+            .get(entity_id)
+            .and_then(|p| p.parent_entity_id.as_ref());
+        while let Some(parent_entity_id) = current {
+            if ancestors.len() >= self.ancestry_depth {
+                break;
+            }
+            match self.processes.get(parent_entity_id) {
+                Some(parent) => {
+                    ancestors.push(parent.clone());
+                    current = parent.parent_entity_id.as_ref();
+                }
+                None => break,
+            }
+        }
+        ancestors
+    }
+}
@@ -1,11 +1,8 @@
 #![allow(clippy::all)]
 
-use machine_uid;
-use pnet::datalink::interfaces;
 use protobuf::json::print_to_string;
 use protobuf::Message;
-use sha2::Digest;
-use sysinfo::{System, SystemExt};
+use std::sync::PoisonError;
 use users::{Groups, Users};
 
 use crate::errors::{SerializableResult, SerializationError};
@@ -31,10 +28,12 @@ impl From<ffi::bprm_check_security_event_process_parent_t> for BprmCheckSecurity
         event.set_name(transform_string(e.name.into()));
         event.set_args_count(e.args_count);
         event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
         event.set_ppid(e.ppid);
         event.set_start(e.start);
         event.set_thread_id(e.thread__id);
         event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
         event
     }
 }
@@ -50,7 +49,14 @@ impl From<ffi::bprm_check_security_event_process_t> for BprmCheckSecurityEventPr
         event.set_args_count(e.args_count);
         event.set_start(e.start);
         event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
         event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
         event.parent = Some(e.parent.into()).into();
         event
     }
@@ -91,6 +97,34 @@ impl From<ffi::bprm_check_security_event_user_t> for BprmCheckSecurityEventUser
     }
 }
 
+impl From<ffi::bprm_check_security_event_container_t> for BprmCheckSecurityEventContainer {
+    fn from(e: ffi::bprm_check_security_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::bprm_check_security_event_cgroup_t> for BprmCheckSecurityEventCgroup {
+    fn from(e: ffi::bprm_check_security_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::bprm_check_security_event_namespace_t> for BprmCheckSecurityEventNamespace {
+    fn from(e: ffi::bprm_check_security_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
 impl From<ffi::bprm_check_security_event_t> for BprmCheckSecurityEvent {
     fn from(e: ffi::bprm_check_security_event_t) -> Self {
         let mut event = Self::default();
@@ -99,10 +133,34 @@ impl From<ffi::bprm_check_security_event_t> for BprmCheckSecurityEvent {
         event.host = Some(Default::default()).into();
         event.process = Some(e.process.into()).into();
         event.user = Some(e.user.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
         event
     }
 }
 
+impl BprmCheckSecurityEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
 impl SerializableEvent for BprmCheckSecurityEvent {
     fn to_json(&self) -> SerializableResult<String> {
         match print_to_string(self) {
@@ -138,98 +196,6 @@ impl SerializableEvent for BprmCheckSecurityEvent {
     fn suffix(&self) -> &'static str {
         "bprm_check_security"
     }
-
-    fn enrich_common<'a>(&'a mut self) -> SerializableResult<&'a mut Self> {
-        {
-            let cache = super::USERS_CACHE.lock().unwrap();
-            // real enrichments
-            let user = self.user.get_mut_ref();
-            let uid = user.get_id().parse::<u32>().unwrap();
-            let group = user.group.get_mut_ref();
-            let gid = group.get_id().parse::<u32>().unwrap();
-
-            for enriched_group in cache.get_group_by_gid(gid) {
-                group.set_name(enriched_group.name().to_string_lossy().to_string());
-            }
-            for enriched_user in cache.get_user_by_uid(uid) {
-                user.set_name(enriched_user.name().to_string_lossy().to_string());
-            }
-
-            // effective enrichments
-            let effective_user = user.effective.get_mut_ref();
-            let effective_uid = effective_user.get_id().parse::<u32>().unwrap();
-            let effective_group = effective_user.group.get_mut_ref();
-            let effective_gid = effective_group.get_id().parse::<u32>().unwrap();
-            for enriched_group in cache.get_group_by_gid(effective_gid) {
-                effective_group.set_name(enriched_group.name().to_string_lossy().to_string());
-            }
-            for enriched_user in cache.get_user_by_uid(effective_uid) {
-                effective_user.set_name(enriched_user.name().to_string_lossy().to_string());
-            }
-        }
-
-        // entity id enrichments
-        let machine_id = machine_uid::get().unwrap(); // this should probably be error checked
-
-        let process = self.process.get_mut_ref();
-        let pid = process.get_pid();
-        let process_start = process.get_start();
-        let process_entity_id = format!(
-            "{}{}{}",
-            machine_id,
-            format!("{:01$}", pid, 5),
-            process_start
-        );
-        process.set_entity_id(format!(
-            "{:x}",
-            sha2::Sha256::digest(process_entity_id.as_bytes())
-        ));
-
-        let parent = process.parent.get_mut_ref();
-        let ppid = parent.get_pid();
-        let parent_start = parent.get_start();
-        let parent_entity_id = format!(
-            "{}{}{}",
-            machine_id,
-            format!("{:01$}", ppid, 5),
-            parent_start
-        );
-        parent.set_entity_id(format!(
-            "{:x}",
-            sha2::Sha256::digest(parent_entity_id.as_bytes())
-        ));
-
-        let system = System::new();
-        let host = self.host.get_mut_ref();
-        host.set_uptime(system.get_uptime());
-        for hostname in system.get_host_name() {
-            host.set_hostname(hostname);
-        }
-        let all_interfaces = interfaces();
-        let active_interfaces = all_interfaces
-            .iter()
-            .filter(|e| e.is_up() && !e.is_loopback() && !e.ips.is_empty());
-        for interface in active_interfaces {
-            if interface.mac.is_some() {
-                host.mac.push(interface.mac.unwrap().to_string());
-            }
-            for ip in &interface.ips {
-                host.ip.push(ip.ip().to_string());
-            }
-        }
-        host.os = Some(Default::default()).into();
-
-        let os = host.os.get_mut_ref();
-        os.set_field_type("linux".to_string());
-        for os_name in system.get_name() {
-            os.set_name(os_name);
-        }
-        for kernel_version in system.get_kernel_version() {
-            os.set_kernel(kernel_version);
-        }
-
-        Ok(self)
-    }
 }
 impl From<ffi::inode_unlink_event_event_t> for InodeUnlinkEventEvent {
     fn from(e: ffi::inode_unlink_event_event_t) -> Self {
@@ -248,10 +214,12 @@ impl From<ffi::inode_unlink_event_process_parent_t> for InodeUnlinkEventProcessP
         event.set_name(transform_string(e.name.into()));
         event.set_args_count(e.args_count);
         event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
         event.set_ppid(e.ppid);
         event.set_start(e.start);
         event.set_thread_id(e.thread__id);
         event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
         event
     }
 }
@@ -267,7 +235,14 @@ impl From<ffi::inode_unlink_event_process_t> for InodeUnlinkEventProcess {
         event.set_args_count(e.args_count);
         event.set_start(e.start);
         event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
         event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
         event.parent = Some(e.parent.into()).into();
         event
     }
@@ -317,6 +292,34 @@ impl From<ffi::inode_unlink_event_file_t> for InodeUnlinkEventFile {
     }
 }
 
+impl From<ffi::inode_unlink_event_container_t> for InodeUnlinkEventContainer {
+    fn from(e: ffi::inode_unlink_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::inode_unlink_event_cgroup_t> for InodeUnlinkEventCgroup {
+    fn from(e: ffi::inode_unlink_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::inode_unlink_event_namespace_t> for InodeUnlinkEventNamespace {
+    fn from(e: ffi::inode_unlink_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
 impl From<ffi::inode_unlink_event_t> for InodeUnlinkEvent {
     fn from(e: ffi::inode_unlink_event_t) -> Self {
         let mut event = Self::default();
@@ -326,10 +329,34 @@ impl From<ffi::inode_unlink_event_t> for InodeUnlinkEvent {
         event.process = Some(e.process.into()).into();
         event.user = Some(e.user.into()).into();
         event.file = Some(e.file.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
         event
     }
 }
 
+impl InodeUnlinkEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
 impl SerializableEvent for InodeUnlinkEvent {
     fn to_json(&self) -> SerializableResult<String> {
         match print_to_string(self) {
@@ -365,96 +392,1626 @@ impl SerializableEvent for InodeUnlinkEvent {
     fn suffix(&self) -> &'static str {
         "inode_unlink"
     }
+}
+impl From<ffi::sb_mount_event_event_t> for SbMountEventEvent {
+    fn from(e: ffi::sb_mount_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
 
-    fn enrich_common<'a>(&'a mut self) -> SerializableResult<&'a mut Self> {
-        {
-            let cache = super::USERS_CACHE.lock().unwrap();
-            // real enrichments
-            let user = self.user.get_mut_ref();
-            let uid = user.get_id().parse::<u32>().unwrap();
-            let group = user.group.get_mut_ref();
-            let gid = group.get_id().parse::<u32>().unwrap();
+impl From<ffi::sb_mount_event_process_parent_t> for SbMountEventProcessParent {
+    fn from(e: ffi::sb_mount_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
 
-            for enriched_group in cache.get_group_by_gid(gid) {
-                group.set_name(enriched_group.name().to_string_lossy().to_string());
-            }
-            for enriched_user in cache.get_user_by_uid(uid) {
-                user.set_name(enriched_user.name().to_string_lossy().to_string());
-            }
+impl From<ffi::sb_mount_event_process_t> for SbMountEventProcess {
+    fn from(e: ffi::sb_mount_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
 
-            // effective enrichments
-            let effective_user = user.effective.get_mut_ref();
-            let effective_uid = effective_user.get_id().parse::<u32>().unwrap();
-            let effective_group = effective_user.group.get_mut_ref();
-            let effective_gid = effective_group.get_id().parse::<u32>().unwrap();
-            for enriched_group in cache.get_group_by_gid(effective_gid) {
-                effective_group.set_name(enriched_group.name().to_string_lossy().to_string());
-            }
-            for enriched_user in cache.get_user_by_uid(effective_uid) {
-                effective_user.set_name(enriched_user.name().to_string_lossy().to_string());
-            }
-        }
+impl From<ffi::sb_mount_event_user_group_t> for SbMountEventUserGroup {
+    fn from(e: ffi::sb_mount_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
 
-        // entity id enrichments
-        let machine_id = machine_uid::get().unwrap(); // this should probably be error checked
-
-        let process = self.process.get_mut_ref();
-        let pid = process.get_pid();
-        let process_start = process.get_start();
-        let process_entity_id = format!(
-            "{}{}{}",
-            machine_id,
-            format!("{:01$}", pid, 5),
-            process_start
-        );
-        process.set_entity_id(format!(
-            "{:x}",
-            sha2::Sha256::digest(process_entity_id.as_bytes())
-        ));
-
-        let parent = process.parent.get_mut_ref();
-        let ppid = parent.get_pid();
-        let parent_start = parent.get_start();
-        let parent_entity_id = format!(
-            "{}{}{}",
-            machine_id,
-            format!("{:01$}", ppid, 5),
-            parent_start
-        );
-        parent.set_entity_id(format!(
-            "{:x}",
-            sha2::Sha256::digest(parent_entity_id.as_bytes())
-        ));
-
-        let system = System::new();
-        let host = self.host.get_mut_ref();
-        host.set_uptime(system.get_uptime());
-        for hostname in system.get_host_name() {
-            host.set_hostname(hostname);
-        }
-        let all_interfaces = interfaces();
-        let active_interfaces = all_interfaces
-            .iter()
-            .filter(|e| e.is_up() && !e.is_loopback() && !e.ips.is_empty());
-        for interface in active_interfaces {
-            if interface.mac.is_some() {
-                host.mac.push(interface.mac.unwrap().to_string());
-            }
-            for ip in &interface.ips {
-                host.ip.push(ip.ip().to_string());
+impl From<ffi::sb_mount_event_user_effective_group_t> for SbMountEventUserEffectiveGroup {
+    fn from(e: ffi::sb_mount_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_user_effective_t> for SbMountEventUserEffective {
+    fn from(e: ffi::sb_mount_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_user_t> for SbMountEventUser {
+    fn from(e: ffi::sb_mount_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_mount_t> for SbMountEventMount {
+    fn from(e: ffi::sb_mount_event_mount_t) -> Self {
+        let mut event = Self::default();
+        event.set_source(transform_string(e.source.into()));
+        event.set_target(transform_string(e.target.into()));
+        event.set_fstype(transform_string(e.fstype.into()));
+        event.set_flags(e.flags);
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_container_t> for SbMountEventContainer {
+    fn from(e: ffi::sb_mount_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_cgroup_t> for SbMountEventCgroup {
+    fn from(e: ffi::sb_mount_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_namespace_t> for SbMountEventNamespace {
+    fn from(e: ffi::sb_mount_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::sb_mount_event_t> for SbMountEvent {
+    fn from(e: ffi::sb_mount_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.mount = Some(e.mount.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl SbMountEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
             }
-        }
-        host.os = Some(Default::default()).into();
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
 
-        let os = host.os.get_mut_ref();
-        os.set_field_type("linux".to_string());
-        for os_name in system.get_name() {
-            os.set_name(os_name);
+impl SerializableEvent for SbMountEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
         }
-        for kernel_version in system.get_kernel_version() {
-            os.set_kernel(kernel_version);
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.sb_mount_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::SBMOUNTEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
         }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "sb_mount"
+    }
+}
+impl From<ffi::sb_umount_event_event_t> for SbUmountEventEvent {
+    fn from(e: ffi::sb_umount_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_process_parent_t> for SbUmountEventProcessParent {
+    fn from(e: ffi::sb_umount_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_process_t> for SbUmountEventProcess {
+    fn from(e: ffi::sb_umount_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_user_group_t> for SbUmountEventUserGroup {
+    fn from(e: ffi::sb_umount_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_user_effective_group_t> for SbUmountEventUserEffectiveGroup {
+    fn from(e: ffi::sb_umount_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_user_effective_t> for SbUmountEventUserEffective {
+    fn from(e: ffi::sb_umount_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_user_t> for SbUmountEventUser {
+    fn from(e: ffi::sb_umount_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
 
-        Ok(self)
+impl From<ffi::sb_umount_event_mount_t> for SbUmountEventMount {
+    fn from(e: ffi::sb_umount_event_mount_t) -> Self {
+        let mut event = Self::default();
+        event.set_source(transform_string(e.source.into()));
+        event.set_target(transform_string(e.target.into()));
+        event.set_fstype(transform_string(e.fstype.into()));
+        event.set_flags(e.flags);
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_container_t> for SbUmountEventContainer {
+    fn from(e: ffi::sb_umount_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_cgroup_t> for SbUmountEventCgroup {
+    fn from(e: ffi::sb_umount_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_namespace_t> for SbUmountEventNamespace {
+    fn from(e: ffi::sb_umount_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::sb_umount_event_t> for SbUmountEvent {
+    fn from(e: ffi::sb_umount_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.mount = Some(e.mount.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl SbUmountEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
+impl SerializableEvent for SbUmountEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.sb_umount_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::SBUMOUNTEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "sb_umount"
+    }
+}
+impl From<ffi::task_kill_event_event_t> for TaskKillEventEvent {
+    fn from(e: ffi::task_kill_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_process_parent_t> for TaskKillEventProcessParent {
+    fn from(e: ffi::task_kill_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_process_t> for TaskKillEventProcess {
+    fn from(e: ffi::task_kill_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_user_group_t> for TaskKillEventUserGroup {
+    fn from(e: ffi::task_kill_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_user_effective_group_t> for TaskKillEventUserEffectiveGroup {
+    fn from(e: ffi::task_kill_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_user_effective_t> for TaskKillEventUserEffective {
+    fn from(e: ffi::task_kill_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_user_t> for TaskKillEventUser {
+    fn from(e: ffi::task_kill_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_signal_t> for TaskKillEventSignal {
+    fn from(e: ffi::task_kill_event_signal_t) -> Self {
+        let mut event = Self::default();
+        event.set_number(e.number);
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_target_process_t> for TaskKillEventTargetProcess {
+    fn from(e: ffi::task_kill_event_target_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_target_t> for TaskKillEventTarget {
+    fn from(e: ffi::task_kill_event_target_t) -> Self {
+        let mut event = Self::default();
+        event.process = Some(e.process.into()).into();
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_container_t> for TaskKillEventContainer {
+    fn from(e: ffi::task_kill_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_cgroup_t> for TaskKillEventCgroup {
+    fn from(e: ffi::task_kill_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_namespace_t> for TaskKillEventNamespace {
+    fn from(e: ffi::task_kill_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::task_kill_event_t> for TaskKillEvent {
+    fn from(e: ffi::task_kill_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.signal = Some(e.signal.into()).into();
+        event.target = Some(e.target.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl TaskKillEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
+impl SerializableEvent for TaskKillEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.task_kill_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::TASKKILLEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "task_kill"
+    }
+}
+impl From<ffi::mmap_file_event_event_t> for MmapFileEventEvent {
+    fn from(e: ffi::mmap_file_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_process_parent_t> for MmapFileEventProcessParent {
+    fn from(e: ffi::mmap_file_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_process_t> for MmapFileEventProcess {
+    fn from(e: ffi::mmap_file_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_user_group_t> for MmapFileEventUserGroup {
+    fn from(e: ffi::mmap_file_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_user_effective_group_t> for MmapFileEventUserEffectiveGroup {
+    fn from(e: ffi::mmap_file_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_user_effective_t> for MmapFileEventUserEffective {
+    fn from(e: ffi::mmap_file_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_user_t> for MmapFileEventUser {
+    fn from(e: ffi::mmap_file_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_file_t> for MmapFileEventFile {
+    fn from(e: ffi::mmap_file_event_file_t) -> Self {
+        let mut event = Self::default();
+        event.set_path(transform_string(e.path.into()));
+        event.set_inode(int_to_string(e.inode.into()));
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_memory_t> for MmapFileEventMemory {
+    fn from(e: ffi::mmap_file_event_memory_t) -> Self {
+        let mut event = Self::default();
+        event.set_anonymous(int_to_bool(e.anonymous.into()));
+        event.set_protection(e.protection);
+        event.set_flags(e.flags);
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_container_t> for MmapFileEventContainer {
+    fn from(e: ffi::mmap_file_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_cgroup_t> for MmapFileEventCgroup {
+    fn from(e: ffi::mmap_file_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_namespace_t> for MmapFileEventNamespace {
+    fn from(e: ffi::mmap_file_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::mmap_file_event_t> for MmapFileEvent {
+    fn from(e: ffi::mmap_file_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.file = Some(e.file.into()).into();
+        event.memory = Some(e.memory.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl MmapFileEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
+impl SerializableEvent for MmapFileEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.mmap_file_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::MMAPFILEEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "mmap_file"
+    }
+}
+impl From<ffi::file_mprotect_event_event_t> for FileMprotectEventEvent {
+    fn from(e: ffi::file_mprotect_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_process_parent_t> for FileMprotectEventProcessParent {
+    fn from(e: ffi::file_mprotect_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_process_t> for FileMprotectEventProcess {
+    fn from(e: ffi::file_mprotect_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_user_group_t> for FileMprotectEventUserGroup {
+    fn from(e: ffi::file_mprotect_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_user_effective_group_t> for FileMprotectEventUserEffectiveGroup {
+    fn from(e: ffi::file_mprotect_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_user_effective_t> for FileMprotectEventUserEffective {
+    fn from(e: ffi::file_mprotect_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_user_t> for FileMprotectEventUser {
+    fn from(e: ffi::file_mprotect_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_file_t> for FileMprotectEventFile {
+    fn from(e: ffi::file_mprotect_event_file_t) -> Self {
+        let mut event = Self::default();
+        event.set_path(transform_string(e.path.into()));
+        event.set_inode(int_to_string(e.inode.into()));
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_memory_t> for FileMprotectEventMemory {
+    fn from(e: ffi::file_mprotect_event_memory_t) -> Self {
+        let mut event = Self::default();
+        event.set_anonymous(int_to_bool(e.anonymous.into()));
+        event.set_protection(e.protection);
+        event.set_address(e.address);
+        event.set_size(e.size);
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_container_t> for FileMprotectEventContainer {
+    fn from(e: ffi::file_mprotect_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_cgroup_t> for FileMprotectEventCgroup {
+    fn from(e: ffi::file_mprotect_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_namespace_t> for FileMprotectEventNamespace {
+    fn from(e: ffi::file_mprotect_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::file_mprotect_event_t> for FileMprotectEvent {
+    fn from(e: ffi::file_mprotect_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.file = Some(e.file.into()).into();
+        event.memory = Some(e.memory.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl FileMprotectEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
+impl SerializableEvent for FileMprotectEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.file_mprotect_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::FILEMPROTECTEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "file_mprotect"
+    }
+}
+impl From<ffi::sock_graft_event_event_t> for SockGraftEventEvent {
+    fn from(e: ffi::sock_graft_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_process_parent_t> for SockGraftEventProcessParent {
+    fn from(e: ffi::sock_graft_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_process_t> for SockGraftEventProcess {
+    fn from(e: ffi::sock_graft_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_user_group_t> for SockGraftEventUserGroup {
+    fn from(e: ffi::sock_graft_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_user_effective_group_t> for SockGraftEventUserEffectiveGroup {
+    fn from(e: ffi::sock_graft_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_user_effective_t> for SockGraftEventUserEffective {
+    fn from(e: ffi::sock_graft_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_user_t> for SockGraftEventUser {
+    fn from(e: ffi::sock_graft_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_source_t> for SockGraftEventSource {
+    fn from(e: ffi::sock_graft_event_source_t) -> Self {
+        let mut event = Self::default();
+        event.set_ip(ip_to_string(e.ip.into()));
+        event.set_port(e.port);
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_destination_t> for SockGraftEventDestination {
+    fn from(e: ffi::sock_graft_event_destination_t) -> Self {
+        let mut event = Self::default();
+        event.set_ip(ip_to_string(e.ip.into()));
+        event.set_port(e.port);
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_network_t> for SockGraftEventNetwork {
+    fn from(e: ffi::sock_graft_event_network_t) -> Self {
+        let mut event = Self::default();
+        event.set_iana_number(int_to_string(e.iana_number.into()));
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_container_t> for SockGraftEventContainer {
+    fn from(e: ffi::sock_graft_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_cgroup_t> for SockGraftEventCgroup {
+    fn from(e: ffi::sock_graft_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_namespace_t> for SockGraftEventNamespace {
+    fn from(e: ffi::sock_graft_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::sock_graft_event_t> for SockGraftEvent {
+    fn from(e: ffi::sock_graft_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.source = Some(e.source.into()).into();
+        event.destination = Some(e.destination.into()).into();
+        event.network = Some(e.network.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl SockGraftEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
+impl SerializableEvent for SockGraftEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.sock_graft_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::SOCKGRAFTEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "sock_graft"
+    }
+}
+impl From<ffi::sched_process_fork_event_event_t> for SchedProcessForkEventEvent {
+    fn from(e: ffi::sched_process_fork_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_process_parent_t> for SchedProcessForkEventProcessParent {
+    fn from(e: ffi::sched_process_fork_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_process_t> for SchedProcessForkEventProcess {
+    fn from(e: ffi::sched_process_fork_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_user_group_t> for SchedProcessForkEventUserGroup {
+    fn from(e: ffi::sched_process_fork_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_user_effective_group_t> for SchedProcessForkEventUserEffectiveGroup {
+    fn from(e: ffi::sched_process_fork_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_user_effective_t> for SchedProcessForkEventUserEffective {
+    fn from(e: ffi::sched_process_fork_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_user_t> for SchedProcessForkEventUser {
+    fn from(e: ffi::sched_process_fork_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_container_t> for SchedProcessForkEventContainer {
+    fn from(e: ffi::sched_process_fork_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_cgroup_t> for SchedProcessForkEventCgroup {
+    fn from(e: ffi::sched_process_fork_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_namespace_t> for SchedProcessForkEventNamespace {
+    fn from(e: ffi::sched_process_fork_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::sched_process_fork_event_t> for SchedProcessForkEvent {
+    fn from(e: ffi::sched_process_fork_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl SchedProcessForkEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id, parent_entity_id, process.get_name(), process.get_executable());
+        Ok(())
+    }
+}
+
+impl SerializableEvent for SchedProcessForkEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.sched_process_fork_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::SCHEDPROCESSFORKEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "sched_process_fork"
+    }
+}
+impl From<ffi::sched_process_exit_event_event_t> for SchedProcessExitEventEvent {
+    fn from(e: ffi::sched_process_exit_event_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_action(transform_string(e.action.into()));
+        event.set_outcome(transform_string(e.outcome.into()));
+        event.set_duration(e.duration);
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_process_parent_t> for SchedProcessExitEventProcessParent {
+    fn from(e: ffi::sched_process_exit_event_process_parent_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_args_count(e.args_count);
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_ppid(e.ppid);
+        event.set_start(e.start);
+        event.set_thread_id(e.thread__id);
+        event.set_executable(transform_string(e.executable.into()));
+        event.hash = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_process_t> for SchedProcessExitEventProcess {
+    fn from(e: ffi::sched_process_exit_event_process_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_entity_id(transform_string(e.entity_id.into()));
+        event.set_name(transform_string(e.name.into()));
+        event.set_ppid(e.ppid);
+        event.set_executable(transform_string(e.executable.into()));
+        event.set_args_count(e.args_count);
+        event.set_start(e.start);
+        event.set_end(e.end);
+        event.set_exit_code(e.exit_code);
+        event.set_thread_id(e.thread__id);
+        event.set_command_line(transform_string(e.command_line.into()));
+        event.args.append(&mut convert_string_array(event.get_args_count(), e.args.into()));
+        event.set_args_truncated(int_to_bool(e.args_truncated.into()));
+        event.set_env_count(e.env_count);
+        event.env.append(&mut convert_string_array(event.get_env_count(), e.env.into()));
+        event.set_env_truncated(int_to_bool(e.env_truncated.into()));
+        event.hash = Some(Default::default()).into();
+        event.ancestry = Some(Default::default()).into();
+        event.parent = Some(e.parent.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_user_group_t> for SchedProcessExitEventUserGroup {
+    fn from(e: ffi::sched_process_exit_event_user_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_user_effective_group_t> for SchedProcessExitEventUserEffectiveGroup {
+    fn from(e: ffi::sched_process_exit_event_user_effective_group_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_user_effective_t> for SchedProcessExitEventUserEffective {
+    fn from(e: ffi::sched_process_exit_event_user_effective_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_user_t> for SchedProcessExitEventUser {
+    fn from(e: ffi::sched_process_exit_event_user_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(int_to_string(e.id.into()));
+        event.group = Some(e.group.into()).into();
+        event.effective = Some(e.effective.into()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_container_t> for SchedProcessExitEventContainer {
+    fn from(e: ffi::sched_process_exit_event_container_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(transform_string(e.id.into()));
+        event.image = Some(Default::default()).into();
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_cgroup_t> for SchedProcessExitEventCgroup {
+    fn from(e: ffi::sched_process_exit_event_cgroup_t) -> Self {
+        let mut event = Self::default();
+        event.set_id(e.id);
+        event.set_path(transform_string(e.path.into()));
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_namespace_t> for SchedProcessExitEventNamespace {
+    fn from(e: ffi::sched_process_exit_event_namespace_t) -> Self {
+        let mut event = Self::default();
+        event.set_pid(e.pid);
+        event.set_mnt(e.mnt);
+        event.set_user(e.user);
+        event
+    }
+}
+
+impl From<ffi::sched_process_exit_event_t> for SchedProcessExitEvent {
+    fn from(e: ffi::sched_process_exit_event_t) -> Self {
+        let mut event = Self::default();
+        event.set_timestamp(e.__timestamp);
+        event.event = Some(e.event.into()).into();
+        event.host = Some(Default::default()).into();
+        event.process = Some(e.process.into()).into();
+        event.user = Some(e.user.into()).into();
+        event.container = Some(e.container.into()).into();
+        event.cgroup = Some(e.cgroup.into()).into();
+        event.namespace = Some(e.namespace.into()).into();
+        event
+    }
+}
+
+impl SchedProcessExitEvent {
+    pub(crate) fn track_process(&self) -> SerializableResult<()> {
+        let process = match self.process.as_ref() {
+            Some(process) => process,
+            None => return Ok(()),
+        };
+        let process_entity_id = entity_id(process.get_pid(), process.get_start())?;
+        let mut table = super::PROCESS_TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let parent_entity_id = match process.parent.as_ref() {
+            Some(parent) => {
+                let parent_entity_id = entity_id(parent.get_pid(), parent.get_start())?;
+                table.observe(parent_entity_id.clone(), None, parent.get_name(), parent.get_executable());
+                Some(parent_entity_id)
+            }
+            None => None,
+        };
+        table.observe(process_entity_id.clone(), parent_entity_id, process.get_name(), process.get_executable());
+        table.exit(&process_entity_id);
+        Ok(())
+    }
+}
+
+impl SerializableEvent for SchedProcessExitEvent {
+    fn to_json(&self) -> SerializableResult<String> {
+        match print_to_string(self) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Json(e)),
+        }
+    }
+
+    fn to_bytes(&self) -> SerializableResult<Vec<u8>> {
+        let mut event = Event::new();
+        event.sched_process_exit_event_t = Some(self.clone()).into();
+        event.set_event_type(event::EventType::SCHEDPROCESSEXITEVENT);
+        match event.write_to_bytes() {
+            Ok(result) => Ok(result),
+            Err(e) => Err(SerializationError::Bytes(e)),
+        }
+    }
+
+    fn update_id(&mut self, id: &mut str) {
+        self.event.as_mut().and_then(|e| {
+            e.set_id(id.to_string().to_owned());
+            Some(e)
+        });
+    }
+
+    fn update_sequence(&mut self, seq: u64) {
+        self.event.as_mut().and_then(|e| {
+            e.set_sequence(seq);
+            Some(e)
+        });
+    }
+
+    fn suffix(&self) -> &'static str {
+        "sched_process_exit"
     }
 }
@@ -1,5 +1,6 @@
 use rule_compiler::{Operation, Operator};
 
+use crate::enrichers::FieldAccess;
 use crate::errors::SerializableResult;
 
 pub trait ProbeHandler<U> {
@@ -8,10 +9,9 @@ pub trait ProbeHandler<U> {
         T: SerializableEvent + std::fmt::Debug;
 }
 
-pub trait SerializableEvent {
+pub trait SerializableEvent: FieldAccess {
     fn to_json(&self) -> SerializableResult<String>;
     fn to_bytes(&self) -> SerializableResult<Vec<u8>>;
-    fn enrich_common(&mut self) -> SerializableResult<&mut Self>;
     fn update_id(&mut self, id: &mut str);
     fn update_sequence(&mut self, seq: u64);
     fn suffix(&self) -> &'static str;
@@ -1,36 +1,91 @@
 #![allow(clippy::all)]
 
 use protobuf::Message;
+use std::sync::Arc;
 
-use crate::errors::SerializableResult;
+use crate::enrichers::EnricherChain;
+
+use crate::errors::{SerializableResult, SerializationError};
 use crate::struct_pb::*;
 use crate::traits::SerializableEvent;
 
-pub trait TransformationHandler {
-    fn enrich_bprm_check_security<'a>(&self, e: &'a mut BprmCheckSecurityEvent) -> SerializableResult<&'a mut BprmCheckSecurityEvent>;
-    fn enrich_inode_unlink<'a>(&self, e: &'a mut InodeUnlinkEvent) -> SerializableResult<&'a mut InodeUnlinkEvent>;
+pub struct Transformer {
+    enrichers: Arc<EnricherChain>,
 }
 
-pub struct Transformer<T> {
-    handler: T,
-}
+impl Transformer {
+    pub fn new() -> Self {
+        Self { enrichers: Arc::new(EnricherChain::default()) }
+    }
 
-impl<T: TransformationHandler> Transformer<T> {
-    pub fn new(handler: T) -> Self {
-        Self { handler: handler }
+    pub fn enrichers(mut self, enrichers: Arc<EnricherChain>) -> Self {
+        self.enrichers = enrichers;
+        self
     }
 
-    pub fn transform(&self, data: Vec<u8>) -> SerializableResult<(String, String)> {
-        let e = Event::parse_from_bytes(&data).unwrap();
+    pub fn transform(&self, data: &[u8]) -> SerializableResult<(String, String)> {
+        let mut e = Event::parse_from_bytes(data).map_err(SerializationError::Decode)?;
         match e.get_event_type() {
             event::EventType::BPRMCHECKSECURITYEVENT => {
-                let json = self.handler.enrich_bprm_check_security((&mut e.bprm_check_security_event_t.unwrap()).enrich_common()?)?.to_json()?;
+                let event = e.bprm_check_security_event_t.as_mut().ok_or(SerializationError::MissingEvent("bprm_check_security"))?;
+                self.enrichers.apply("bprm_check_security", event)?;
+                let json = event.to_json()?;
                 Ok((String::from("bprm_check_security"), json))
             },
             event::EventType::INODEUNLINKEVENT => {
-                let json = self.handler.enrich_inode_unlink((&mut e.inode_unlink_event_t.unwrap()).enrich_common()?)?.to_json()?;
+                let event = e.inode_unlink_event_t.as_mut().ok_or(SerializationError::MissingEvent("inode_unlink"))?;
+                self.enrichers.apply("inode_unlink", event)?;
+                let json = event.to_json()?;
                 Ok((String::from("inode_unlink"), json))
             },
+            event::EventType::SBMOUNTEVENT => {
+                let event = e.sb_mount_event_t.as_mut().ok_or(SerializationError::MissingEvent("sb_mount"))?;
+                self.enrichers.apply("sb_mount", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("sb_mount"), json))
+            },
+            event::EventType::SBUMOUNTEVENT => {
+                let event = e.sb_umount_event_t.as_mut().ok_or(SerializationError::MissingEvent("sb_umount"))?;
+                self.enrichers.apply("sb_umount", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("sb_umount"), json))
+            },
+            event::EventType::TASKKILLEVENT => {
+                let event = e.task_kill_event_t.as_mut().ok_or(SerializationError::MissingEvent("task_kill"))?;
+                self.enrichers.apply("task_kill", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("task_kill"), json))
+            },
+            event::EventType::MMAPFILEEVENT => {
+                let event = e.mmap_file_event_t.as_mut().ok_or(SerializationError::MissingEvent("mmap_file"))?;
+                self.enrichers.apply("mmap_file", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("mmap_file"), json))
+            },
+            event::EventType::FILEMPROTECTEVENT => {
+                let event = e.file_mprotect_event_t.as_mut().ok_or(SerializationError::MissingEvent("file_mprotect"))?;
+                self.enrichers.apply("file_mprotect", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("file_mprotect"), json))
+            },
+            event::EventType::SOCKGRAFTEVENT => {
+                let event = e.sock_graft_event_t.as_mut().ok_or(SerializationError::MissingEvent("sock_graft"))?;
+                self.enrichers.apply("sock_graft", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("sock_graft"), json))
+            },
+            event::EventType::SCHEDPROCESSFORKEVENT => {
+                let event = e.sched_process_fork_event_t.as_mut().ok_or(SerializationError::MissingEvent("sched_process_fork"))?;
+                self.enrichers.apply("sched_process_fork", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("sched_process_fork"), json))
+            },
+            event::EventType::SCHEDPROCESSEXITEVENT => {
+                let event = e.sched_process_exit_event_t.as_mut().ok_or(SerializationError::MissingEvent("sched_process_exit"))?;
+                self.enrichers.apply("sched_process_exit", event)?;
+                let json = event.to_json()?;
+                Ok((String::from("sched_process_exit"), json))
+            },
         }
     }
 }
\ No newline at end of file
@@ -1,40 +1,225 @@
+use chrono::Utc;
 use log::{debug, error};
+use probe_sys::EnricherChain;
+use sled::IVec;
 use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
-use crate::client::Client;
-use crate::globals::global_database;
+use crate::client::BulkStatus;
+use crate::dead_letters;
+use crate::globals::{global_database, global_dead_letters, global_queue, global_stats};
+use crate::indices::rfc3339_timestamp;
+use crate::queue::pending;
+use crate::sinks::{Event, Sinks};
+
+// moves a record that can't be transformed or that a sink rejected out of
+// the main tree, so that it isn't picked up again, and into the dead letter
+// tree
+fn dead_letter(key: &[u8], rejection: Rejection) {
+    let stored = dead_letters::store(
+        global_database(),
+        global_dead_letters(),
+        key,
+        rejection.sink,
+        rejection.destination,
+        rejection.error,
+        rejection.attempts,
+    );
+    if let Err(e) = stored {
+        error!("error dead lettering record {:?}: {}", key, e);
+        return;
+    }
+    if let Err(e) = global_queue().remove(key) {
+        error!("error removing dead lettered record {:?}: {}", key, e);
+    }
+}
+
+// the sink that rejected a record, where the record was headed, and why
+struct Rejection {
+    sink: String,
+    destination: String,
+    error: String,
+    attempts: u32,
+}
+
+// a record along with the sinks that still have to accept it
+struct Pending {
+    key: IVec,
+    event: Event,
+    sinks: Vec<usize>,
+    rejection: Option<Rejection>,
+}
+
+// the record has been accepted or rejected by every sink it's routed to
+fn settle(worker: u32, record: Pending) {
+    match record.rejection {
+        Some(rejection) => dead_letter(&record.key, rejection),
+        None => {
+            if let Err(e) = dead_letters::settled(global_database(), &record.key) {
+                error!(
+                    "worker {}: error settling record {:?}: {}",
+                    worker, record.key, e
+                );
+            }
+            match global_queue().remove(&record.key) {
+                Ok(_) => debug!("worker {}: cleaned record", worker),
+                Err(e) => error!("worker {}: error removing record {:?}", worker, e),
+            }
+        }
+    }
+}
+
+// re-driven records only go back to the sink that rejected them, the sinks
+// that accepted them already have them
+fn routes(sinks: &Sinks, key: &[u8], module: &str) -> (Vec<usize>, Option<Rejection>) {
+    let redriven = dead_letters::redriven_sink(global_database(), key).unwrap_or_else(|e| {
+        error!("error reading re-driven record {:?}: {}", key, e);
+        None
+    });
+    match redriven {
+        Some(name) => match sinks.position(&name) {
+            Some(sink) => (vec![sink], None),
+            None => {
+                let rejection = Rejection {
+                    error: format!("sink {} is not configured", name),
+                    sink: name,
+                    destination: String::new(),
+                    attempts: 0,
+                };
+                (vec![], Some(rejection))
+            }
+        },
+        None => (sinks.routes(module), None),
+    }
+}
+
+fn queued(key: &[u8]) -> bool {
+    global_database().contains_key(key).unwrap_or(true)
+}
+
+// hands the batch to each sink, returning the records that still have to be
+// retried against at least one of them
+fn flush(worker: u32, sinks: &Sinks, mut batch: Vec<Pending>) -> Vec<Pending> {
+    for (s, sink) in sinks.iter().enumerate() {
+        let targets: Vec<usize> = (0..batch.len())
+            .filter(|i| batch[*i].sinks.contains(&s))
+            .collect();
+        if targets.is_empty() {
+            continue;
+        }
+        let events: Vec<&Event> = targets.iter().map(|i| &batch[*i].event).collect();
+        let statuses = sink.send(&events);
+        for (i, status) in targets.into_iter().zip(statuses) {
+            let record = &mut batch[i];
+            match status {
+                BulkStatus::Indexed => record.sinks.retain(|t| *t != s),
+                BulkStatus::Retry(e) => {
+                    debug!(
+                        "worker {}: retrying record {:?} for {}: {}",
+                        worker,
+                        record.key,
+                        sink.name(),
+                        e
+                    );
+                }
+                BulkStatus::Rejected { error, attempts } => {
+                    error!(
+                        "worker {}: record rejected by {}: {}",
+                        worker,
+                        sink.name(),
+                        error
+                    );
+                    record.sinks.retain(|t| *t != s);
+                    record.rejection = Some(Rejection {
+                        sink: sink.name().to_string(),
+                        destination: sink.destination(&record.event),
+                        error,
+                        attempts,
+                    });
+                }
+            }
+        }
+    }
+    let (settled, retries): (Vec<Pending>, Vec<Pending>) =
+        batch.into_iter().partition(|r| r.sinks.is_empty());
+    for record in settled {
+        settle(worker, record);
+    }
+    retries
+}
 
 pub struct Batcher {}
 
 impl Batcher {
     pub fn run(
-        local: bool,
-        client: &Client,
+        sinks: Arc<Sinks>,
         flush_rate: u64,
         max_batch_size: usize,
         max_batch_bytes: usize,
         workers: u32,
+        enrichers: Arc<EnricherChain>,
     ) {
         let (mut tx, rx) = spmc::channel();
         for i in 0..workers {
-            let worker_client = client.clone();
-            let transformer = probe_sys::Transformer::new(crate::handler::Handler {});
+            let sinks = sinks.clone();
+            let transformer = probe_sys::Transformer::new().enrichers(enrichers.clone());
             let rx = rx.clone();
             let flush_timeout = Duration::new(flush_rate, 0);
             std::thread::spawn(move || {
-                let mut batch = Vec::new();
+                let mut batch: Vec<Pending> = Vec::new();
                 let mut current_batch_bytes: usize = 0;
                 let mut last_flush = SystemTime::now();
+                let mut retrying = false;
                 loop {
-                    match rx.recv_timeout(flush_timeout) {
+                    // while a sink is down, leave new records in the queue
+                    // instead of holding an ever growing batch in memory
+                    let received = if retrying && batch.len() >= max_batch_size {
+                        std::thread::sleep(flush_timeout);
+                        Err(RecvTimeoutError::Timeout)
+                    } else {
+                        rx.recv_timeout(flush_timeout)
+                    };
+                    match received {
+                        // dropped to make room while it was waiting in the channel
+                        Ok((key, _)) if !queued(&key) => {}
                         Ok((key, data)) => {
-                            match transformer.transform(data) {
-                                Ok((index, json)) => {
-                                    current_batch_bytes += json.chars().count() + 1; // 1 == newline
-                                    batch.push((key, (index, json)));
+                            match transformer.transform(&data) {
+                                Ok((module, json)) => {
+                                    let (timestamp, json) = rfc3339_timestamp(&json);
+                                    let (routes, rejection) = routes(&sinks, &key, &module);
+                                    let record = Pending {
+                                        key,
+                                        sinks: routes,
+                                        event: Event {
+                                            module,
+                                            timestamp: timestamp
+                                                .unwrap_or_else(|| Utc::now().timestamp()),
+                                            document: json,
+                                        },
+                                        rejection,
+                                    };
+                                    if record.sinks.is_empty() {
+                                        // nothing is routed this event's way,
+                                        // or its sink is gone
+                                        settle(i, record);
+                                    } else {
+                                        current_batch_bytes += record.event.document.len() + 1; // 1 == newline
+                                        batch.push(record);
+                                    }
+                                }
+                                Err(e) => {
+                                    error!("worker {}: dead lettering record: {}", i, e);
+                                    dead_letter(
+                                        &key,
+                                        Rejection {
+                                            sink: String::new(),
+                                            destination: String::new(),
+                                            error: e.to_string(),
+                                            attempts: 1,
+                                        },
+                                    );
                                 }
-                                Err(e) => error!("worker {}: {:?}", i, e),
                             };
                         }
                         Err(RecvTimeoutError::Disconnected) => {
@@ -45,43 +230,68 @@ impl Batcher {
                     }
                     let now = SystemTime::now();
                     let batch_size = batch.len();
+                    // the byte limit applies to what's sent, so estimate how
+                    // well the batch will compress from what we've sent so far
+                    let estimated_bytes =
+                        (current_batch_bytes as f64 * global_stats().compression_ratio()) as usize;
                     // flush immediately if we have a clock reset
                     let elapsed = now
                         .duration_since(last_flush)
                         .unwrap_or(flush_timeout)
                         .as_secs();
-                    if current_batch_bytes >= max_batch_bytes
-                        || batch_size >= max_batch_size
+                    // items that are waiting to be retried don't count towards
+                    // an early flush, otherwise we'd hammer an overloaded sink
+                    if (!retrying
+                        && (estimated_bytes >= max_batch_bytes || batch_size >= max_batch_size))
                         || elapsed > flush_rate
                     {
-                        if !local {
-                            match worker_client.send_batch(&batch) {
-                                Err(e) => error!("error sending batch: {}", e),
-                                _ => {}
-                            }
+                        // records dropped to make room in the queue while
+                        // they were waiting shouldn't be shipped after all
+                        batch.retain(|record| queued(&record.key));
+                        let retries = flush(i, &sinks, std::mem::take(&mut batch));
+                        if !retries.is_empty() {
+                            error!(
+                                "worker {}: {} records couldn't be sent, retrying",
+                                i,
+                                retries.len()
+                            );
                         }
-                        for (k, (_, v)) in &batch {
-                            if local {
-                                println!("{}", v);
-                            }
-                            match global_database().remove(k) {
-                                Ok(_) => debug!("worker {}: cleaned record", i),
-                                Err(e) => error!("worker {}: error removing record {:?}", i, e),
-                            }
-                        }
-                        batch.clear();
-                        current_batch_bytes = 0;
+                        retrying = !retries.is_empty();
+                        current_batch_bytes =
+                            retries.iter().map(|r| r.event.document.len() + 1).sum();
+                        batch = retries;
                         last_flush = now;
                     }
                 }
             });
         }
 
-        let mut subscriber = global_database().watch_prefix(vec![]);
+        let db = global_database();
+        let mut subscriber = db.watch_prefix(vec![]);
+        // anything already queued when we subscribed won't show up as an
+        // event, so it has to be replayed, and anything queued since will
+        // sort after it
+        let replay_until = match db.last() {
+            Ok(last) => last.map(|(key, _)| key),
+            Err(e) => {
+                error!("error reading pending records: {}", e);
+                None
+            }
+        };
+        if let Some(until) = &replay_until {
+            for (key, data) in pending(db, until) {
+                if let Err(e) = tx.send((key, data.to_vec())) {
+                    error!("sender: {}", e.to_string());
+                }
+            }
+        }
         loop {
             match subscriber.next() {
                 Some(event) => {
                     for (_, key, data) in event.into_iter() {
+                        if replay_until.as_ref().map_or(false, |until| &key <= until) {
+                            continue;
+                        }
                         if data.is_some() {
                             let value = data.clone().unwrap().to_vec();
                             let result = tx.send((key.clone(), value));
@@ -1,27 +1,42 @@
 #![allow(dead_code)]
 
-use crate::globals::get_template;
+use crate::globals::{get_template, global_stats};
+use crate::indices::{IndexSettings, LIFECYCLE_POLICY};
+use crate::nodes::{discovered_urls, NodePool};
+use crate::tls::TlsOptions;
 use backoff::backoff::Backoff;
 use backoff::{retry, ExponentialBackoff, SystemClock};
 use base64::encode;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use instant::Instant;
-use sled::IVec;
 use std::format;
+use std::io::Write;
 use std::sync::Arc;
 use std::time::Duration;
 use ureq::{Agent, AgentBuilder, Error};
 
-pub struct SkipVerifier {}
+/// How requests authenticate with Elasticsearch, or another HTTP endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Auth {
+    /// `user:password`
+    Basic(String),
+    /// Either `id:api_key` or its base64 encoding as returned by the API.
+    ApiKey(String),
+    Bearer(String),
+    /// A Splunk HTTP Event Collector token.
+    Splunk(String),
+}
 
-impl rustls::ServerCertVerifier for SkipVerifier {
-    fn verify_server_cert(
-        &self,
-        _roots: &rustls::RootCertStore,
-        _presented_certs: &[rustls::Certificate],
-        _dns_name: webpki::DNSNameRef<'_>,
-        _ocsp_response: &[u8],
-    ) -> Result<rustls::ServerCertVerified, rustls::TLSError> {
-        Ok(rustls::ServerCertVerified::assertion())
+impl Auth {
+    pub fn header(&self) -> String {
+        match self {
+            Auth::Basic(creds) => format!("Basic {}", encode(creds.as_bytes())),
+            Auth::ApiKey(key) if key.contains(':') => format!("ApiKey {}", encode(key.as_bytes())),
+            Auth::ApiKey(key) => format!("ApiKey {}", key),
+            Auth::Bearer(token) => format!("Bearer {}", token),
+            Auth::Splunk(token) => format!("Splunk {}", token),
+        }
     }
 }
 
@@ -30,12 +45,32 @@ const RANDOMIZATION_FACTOR: f64 = 0.5;
 const MULTIPLIER: f64 = 1.5;
 const MAX_INTERVAL_MILLIS: u64 = 2_000;
 const MAX_ELAPSED_TIME_MILLIS: u64 = 15_000;
+const DEFAULT_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
 
 #[derive(Clone)]
 pub struct Client {
     inner: Agent,
-    base: String,
-    creds: Option<String>,
+    nodes: Arc<NodePool>,
+    authorization: Option<String>,
+    compression: Option<Compression>,
+    indices: Arc<IndexSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkStatus {
+    Indexed,
+    /// Failed with a status that may succeed later, i.e. a 429.
+    Retry(String),
+    /// Rejected by Elasticsearch, i.e. because of a mapping conflict.
+    Rejected { error: String, attempts: u32 },
+}
+
+// what came back from a single bulk request
+enum BulkResponse {
+    /// The status and error of every item, in the order they were sent.
+    Items(Vec<(u64, Option<String>)>),
+    /// The request as a whole failed with a status.
+    Failed(u16, String),
 }
 
 fn make_url(base: String, path: String) -> String {
@@ -43,10 +78,11 @@ fn make_url(base: String, path: String) -> String {
 }
 
 fn make_batch_entry(index: &String, data: &String) -> String {
-    format!("{{\"create\":{{ \"_index\" : \"{}-1\"}}\n{}\n", index, data)
+    // data streams only accept create
+    format!("{{\"create\":{{ \"_index\" : \"{}\"}}\n{}\n", index, data)
 }
 
-fn backoff() -> ExponentialBackoff {
+pub fn backoff() -> ExponentialBackoff {
     let mut e = ExponentialBackoff {
         current_interval: Duration::from_millis(INITIAL_INTERVAL_MILLIS),
         initial_interval: Duration::from_millis(INITIAL_INTERVAL_MILLIS),
@@ -63,49 +99,114 @@ fn backoff() -> ExponentialBackoff {
 
 impl Client {
     pub fn new(
-        base: String,
-        creds: Option<String>,
-        ignore_validation: bool,
+        hosts: Vec<String>,
+        auth: Option<Auth>,
+        tls: &TlsOptions,
         timeout: std::time::Duration,
-    ) -> Self {
+    ) -> Result<Self, String> {
         let mut agent_builder = AgentBuilder::new().timeout(timeout);
-        if ignore_validation {
-            let mut client_config = rustls::ClientConfig::new();
-            client_config
-                .dangerous()
-                .set_certificate_verifier(Arc::new(SkipVerifier {}));
-            let tls_config = Arc::new(client_config);
+        if let Some(tls_config) = tls.client_config()? {
             agent_builder = agent_builder.tls_config(tls_config)
         }
-        let encoded = creds.map(|c| encode(c.as_bytes()));
-        Self {
-            base,
-            creds: encoded,
+        Ok(Self {
+            nodes: Arc::new(NodePool::new(hosts, DEFAULT_RECHECK_INTERVAL)),
+            authorization: auth.map(|a| a.header()),
             inner: agent_builder.build(),
+            compression: None,
+            indices: Arc::new(IndexSettings::default()),
+        })
+    }
+
+    /// How long a node that couldn't be reached is taken out of rotation
+    /// before it's checked again.
+    pub fn recheck_interval(mut self, interval: Duration) -> Self {
+        self.nodes = Arc::new(NodePool::new(self.nodes.urls(), interval));
+        self
+    }
+
+    pub fn indices(mut self, indices: IndexSettings) -> Self {
+        self.indices = Arc::new(indices);
+        self
+    }
+
+    /// The index or data stream an event from the module is written to.
+    pub fn index_name(&self, module: &str, timestamp: i64) -> String {
+        self.indices.index(module, timestamp)
+    }
+
+    /// Gzips bulk request bodies at the given level (0-9).
+    pub fn compression(mut self, level: Option<u32>) -> Self {
+        self.compression = level.map(|level| Compression::new(level.min(9)));
+        self
+    }
+
+    fn compress(&self, payload: String) -> Result<(Vec<u8>, bool), String> {
+        match self.compression {
+            Some(level) => {
+                let mut encoder = GzEncoder::new(Vec::new(), level);
+                encoder
+                    .write_all(payload.as_bytes())
+                    .map_err(|e| e.to_string())?;
+                let compressed = encoder.finish().map_err(|e| e.to_string())?;
+                Ok((compressed, true))
+            }
+            None => Ok((payload.into_bytes(), false)),
         }
     }
 
-    fn construct_request(&self, method: &str, content_type: &str, url: &String) -> ureq::Request {
-        let path = format!("{}{}", self.base, url);
+    fn construct_request(
+        &self,
+        node: &str,
+        method: &str,
+        content_type: &str,
+        url: &String,
+    ) -> ureq::Request {
+        let path = make_url(node.to_string(), url.to_string());
         let request = self
             .inner
             .request(method, &path)
             .set("Content-Type", content_type);
-        match &self.creds {
-            Some(creds) => {
-                let header = format!("Basic {}", creds);
-                request.set("Authorization", &header)
-            }
+        match &self.authorization {
+            Some(header) => request.set("Authorization", header),
             None => request,
         }
     }
 
-    fn construct_basic_request(&self, method: &str, url: &String) -> ureq::Request {
-        self.construct_request(method, "application/json", url)
+    fn construct_basic_request(&self, node: &str, method: &str, url: &String) -> ureq::Request {
+        self.construct_request(node, method, "application/json", url)
     }
 
-    fn construct_bulk_request(&self, method: &str, url: &String) -> ureq::Request {
-        self.construct_request(method, "application/x-ndjson", url)
+    fn construct_bulk_request(&self, node: &str, method: &str, url: &String) -> ureq::Request {
+        self.construct_request(node, method, "application/x-ndjson", url)
+    }
+
+    // any response at all means the node is reachable again
+    fn ping(&self, node: &str) -> bool {
+        match self
+            .construct_basic_request(node, "GET", &String::from("/"))
+            .call()
+        {
+            Ok(_) | Err(Error::Status(..)) => true,
+            Err(_) => false,
+        }
+    }
+
+    // makes a single attempt at a request against the next node, taking the
+    // node out of rotation when it can't be reached so that the retry goes
+    // to another one
+    fn attempt<F>(&self, send: F) -> Result<ureq::Response, backoff::Error<Error>>
+    where
+        F: FnOnce(&str) -> Result<ureq::Response, Error>,
+    {
+        let node = self.nodes.pick(|node| self.ping(node));
+        match send(&node) {
+            Ok(r) => Ok(r),
+            Err(Error::Status(_, r)) => Ok(r),
+            Err(e) => {
+                self.nodes.mark_down(&node);
+                Err(backoff::Error::Transient(e))
+            }
+        }
     }
 
     fn do_request(
@@ -116,22 +217,13 @@ impl Client {
     ) -> Result<String, String> {
         let response = match data {
             Some(payload) => retry(backoff(), || {
-                let resp = self
-                    .construct_basic_request(method, url)
-                    .send_bytes(payload);
-                match resp {
-                    Ok(r) => Ok(r),
-                    Err(Error::Status(_, r)) => Ok(r),
-                    Err(e) => Err(backoff::Error::Transient(e)),
-                }
+                self.attempt(|node| {
+                    self.construct_basic_request(node, method, url)
+                        .send_bytes(payload)
+                })
             }),
             None => retry(backoff(), || {
-                let resp = self.construct_basic_request(method, url).call();
-                match resp {
-                    Ok(r) => Ok(r),
-                    Err(Error::Status(_, r)) => Ok(r),
-                    Err(e) => Err(backoff::Error::Transient(e)),
-                }
+                self.attempt(|node| self.construct_basic_request(node, method, url).call())
             }),
         };
         let response_text = response
@@ -145,6 +237,29 @@ impl Client {
         }
     }
 
+    /// Sends a request to the next healthy node, moving on to the others
+    /// when it can't be reached, and returns the status and body of whatever
+    /// response comes back.
+    pub fn request(
+        &self,
+        method: &str,
+        url: &str,
+        content_type: &str,
+        body: &[u8],
+    ) -> Result<(u16, String), String> {
+        let url = url.to_string();
+        let response = retry(backoff(), || {
+            self.attempt(|node| {
+                self.construct_request(node, method, content_type, &url)
+                    .send_bytes(body)
+            })
+        })
+        .map_err(|e| e.to_string())?;
+        let status = response.status();
+        let text = response.into_string().map_err(|e| e.to_string())?;
+        Ok((status, text))
+    }
+
     fn get(&self, url: &String) -> Result<String, String> {
         self.do_request("GET", url, None)
     }
@@ -153,44 +268,215 @@ impl Client {
         self.do_request("PUT", url, Some(data))
     }
 
-    pub fn ensure_template(&self, name: &str) -> Result<(), String> {
-        let url = format!("/_index_template/{}", name);
-        match self.get(&url) {
-            Ok(_) => Ok(()), // we already have the template, don't bother creating a new one
-            Err(_) => {
-                // assume we don't have the template installed, so attempt to install it
-                let template = get_template(name)?;
-                self.put(&url, template)?;
-                Ok(())
-            }
+    pub fn nodes(&self) -> Vec<String> {
+        self.nodes.urls()
+    }
+
+    /// Adds the nodes of the cluster that have HTTP enabled to the configured
+    /// hosts, returning how many were found.
+    pub fn discover(&self) -> Result<usize, String> {
+        let response = self.get(&String::from("/_nodes/http"))?;
+        let seed = self.nodes.urls().into_iter().next().unwrap_or_default();
+        let scheme = seed.find("://").map_or("http", |i| &seed[..i]);
+        let urls = discovered_urls(&response, scheme);
+        if urls.is_empty() {
+            return Err(String::from("no nodes with HTTP enabled were found"));
         }
+        let found = urls.len();
+        self.nodes.discovered(urls);
+        Ok(found)
     }
 
-    pub fn send_batch(&self, batch: &Vec<(IVec, (String, String))>) -> Result<(), String> {
-        if batch.is_empty() {
-            return Ok(())
+    // installs a template unless the same version of it is already there
+    fn ensure_versioned(&self, url: &String, version_path: &str, body: &[u8]) -> Result<(), String> {
+        let version = ajson::get(&String::from_utf8_lossy(body), "version").map(|v| v.to_u64());
+        let installed = self
+            .get(url)
+            .ok()
+            .and_then(|response| ajson::get(&response, version_path))
+            .map(|v| v.to_u64());
+        if version.is_some() && installed == version {
+            return Ok(());
+        }
+        self.put(url, body)?;
+        Ok(())
+    }
+
+    /// Installs the lifecycle policy, the shared settings, and the mappings
+    /// and index template of every module, upgrading any that changed.
+    pub fn ensure_templates(&self, modules: &[&str]) -> Result<(), String> {
+        if let Some(lifecycle) = &self.indices.lifecycle {
+            let url = format!("/_ilm/policy/{}", LIFECYCLE_POLICY);
+            self.put(&url, lifecycle.policy().as_bytes())?;
+        }
+        let url = format!(
+            "/_component_template/{}",
+            IndexSettings::settings_template_name()
+        );
+        self.ensure_versioned(
+            &url,
+            "component_templates.0.component_template.version",
+            self.indices.settings_template().as_bytes(),
+        )?;
+        for module in modules {
+            let url = format!(
+                "/_component_template/{}",
+                IndexSettings::mappings_template_name(module)
+            );
+            self.ensure_versioned(
+                &url,
+                "component_templates.0.component_template.version",
+                get_template(module)?,
+            )?;
+            let url = format!("/_index_template/{}", module);
+            self.ensure_versioned(
+                &url,
+                "index_templates.0.index_template.version",
+                self.indices.index_template(module).as_bytes(),
+            )?;
         }
+        Ok(())
+    }
+
+    // sends a single bulk request, returning the status and error of each item
+    fn send_bulk<'a, I>(&self, items: I) -> Result<BulkResponse, String>
+    where
+        I: Iterator<Item = &'a (String, String)>,
+    {
         let url = String::from("/_bulk");
-        let payload = batch
-            .iter()
-            .map(|(_, (index, data))| make_batch_entry(index, data))
+        let payload = items
+            .map(|(index, data)| make_batch_entry(index, data))
             .collect::<String>();
+        let uncompressed_bytes = payload.len();
+        let (payload, compressed) = self.compress(payload)?;
+        let stats = global_stats();
+        stats.record_bytes(uncompressed_bytes, payload.len());
         let response = retry(backoff(), || {
-            let resp = self.construct_bulk_request("POST", &url).send_bytes(payload.as_bytes());
-            match resp {
-                Ok(r) => Ok(r),
-                Err(Error::Status(_, r)) => Ok(r),
-                Err(e) => Err(backoff::Error::Transient(e)),
-            }
-        });
-        let response_text = response
-            .map_err(|e| e.to_string())?
-            .into_string()
-            .map_err(|e| e.to_string())?;
+            self.attempt(|node| {
+                let mut request = self.construct_bulk_request(node, "POST", &url);
+                if compressed {
+                    request = request.set("Content-Encoding", "gzip");
+                }
+                request.send_bytes(&payload)
+            })
+        })
+        .map_err(|e| e.to_string())?;
+        let status = response.status();
+        let response_text = response.into_string().map_err(|e| e.to_string())?;
         let error_message = ajson::get(&response_text, "error.reason");
-        match error_message {
-            Some(message) => Err(message.to_string()),
-            None => Ok(()),
+        if !(200..=299).contains(&status) {
+            let error = match error_message {
+                Some(message) => format!("status {}: {}", status, message),
+                None => format!("status {}", status),
+            };
+            return Ok(BulkResponse::Failed(status, error));
+        }
+        if let Some(message) = error_message {
+            return Err(message.to_string());
+        }
+        let items = ajson::get(&response_text, "items")
+            .ok_or_else(|| String::from("bulk response is missing items"))?;
+        Ok(BulkResponse::Items(
+            items
+                .to_vec()
+                .iter()
+                .map(|item| {
+                    let status = item.get("create.status").map_or(0, |s| s.to_u64());
+                    let error = item.get("create.error").map(|e| {
+                        match (e.get("type"), e.get("reason")) {
+                            (Some(kind), Some(reason)) => format!("{}: {}", kind, reason),
+                            _ => e.to_string(),
+                        }
+                    });
+                    (status, error)
+                })
+                .collect(),
+        ))
+    }
+
+    /// Ships a batch, retrying the items that failed with a retryable status
+    /// until they're indexed or the backoff gives up. The returned statuses
+    /// line up with the batch.
+    pub fn send_batch(&self, batch: &[(String, String)]) -> Vec<BulkStatus> {
+        let mut statuses = vec![BulkStatus::Indexed; batch.len()];
+        self.send_items(batch, (0..batch.len()).collect(), &mut statuses);
+        statuses
+    }
+
+    // sends the items of the batch at the given positions, splitting them in
+    // half whenever the request is too large for the cluster
+    fn send_items(
+        &self,
+        batch: &[(String, String)],
+        mut pending: Vec<usize>,
+        statuses: &mut [BulkStatus],
+    ) {
+        let mut attempts = 0;
+        let mut backoff = backoff();
+        while !pending.is_empty() {
+            attempts += 1;
+            let mut retries = vec![];
+            match self.send_bulk(pending.iter().map(|i| &batch[*i])) {
+                Ok(BulkResponse::Items(items)) if items.len() == pending.len() => {
+                    for (i, (status, error)) in pending.iter().zip(items) {
+                        let error = error.unwrap_or_else(|| format!("status {}", status));
+                        statuses[*i] = match status {
+                            200..=299 => BulkStatus::Indexed,
+                            429 | 500..=599 => {
+                                retries.push(*i);
+                                BulkStatus::Retry(error)
+                            }
+                            _ => BulkStatus::Rejected { error, attempts },
+                        };
+                    }
+                }
+                // there's no telling which items made it, and sending them
+                // again wouldn't line up any better
+                Ok(BulkResponse::Items(_)) => {
+                    let error = String::from("bulk response items don't match the request");
+                    for i in &pending {
+                        statuses[*i] = BulkStatus::Rejected {
+                            error: error.clone(),
+                            attempts,
+                        };
+                    }
+                }
+                Ok(BulkResponse::Failed(413, _)) if pending.len() > 1 => {
+                    let second_half = pending.split_off(pending.len() / 2);
+                    self.send_items(batch, pending, statuses);
+                    self.send_items(batch, second_half, statuses);
+                    return;
+                }
+                // a single document that's too large won't ever fit
+                Ok(BulkResponse::Failed(413, error)) => {
+                    for i in &pending {
+                        statuses[*i] = BulkStatus::Rejected {
+                            error: error.clone(),
+                            attempts,
+                        };
+                    }
+                }
+                // failures of the whole request, i.e. expired credentials or
+                // missing privileges, say nothing about the documents, so
+                // they're kept until the cluster accepts them
+                Ok(BulkResponse::Failed(_, error)) => {
+                    for i in &pending {
+                        statuses[*i] = BulkStatus::Retry(error.clone());
+                    }
+                    retries = pending;
+                }
+                Err(error) => {
+                    for i in &pending {
+                        statuses[*i] = BulkStatus::Retry(error.clone());
+                    }
+                    retries = pending;
+                }
+            }
+            pending = retries;
+            match backoff.next_backoff() {
+                Some(wait) if !pending.is_empty() => std::thread::sleep(wait),
+                _ => break,
+            }
         }
     }
 }
@@ -0,0 +1,176 @@
+use sled::{Db, IVec, Tree};
+use std::convert::TryInto;
+
+use crate::queue::{is_denial, key_sequence, next_sequence, Queue};
+
+pub const DEAD_LETTERS_TREE: &str = "dead_letters";
+// records that were re-driven out of the dead letter tree, along with how
+// many attempts they had already used up and the sink they're headed for
+const REDRIVEN_TREE: &str = "redriven";
+
+/// A record that couldn't be transformed or shipped, along with why. The
+/// payload is the record as it was queued, so re-driving it runs it through
+/// the whole pipeline again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadLetter {
+    /// The sink that rejected the record, empty when it couldn't be
+    /// transformed.
+    pub sink: String,
+    pub index: String,
+    pub error: String,
+    pub attempts: u32,
+    pub denied: bool,
+    pub payload: Vec<u8>,
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)?
+        .try_into()
+        .ok()
+        .map(u32::from_be_bytes)
+}
+
+fn read_string(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    let length = read_u32(data, offset)? as usize;
+    let start = offset + 4;
+    let value = data.get(start..start + length)?;
+    Some((String::from_utf8_lossy(value).to_string(), start + length))
+}
+
+impl DeadLetter {
+    // attempts and whether it's a denial, then the length prefixed sink,
+    // index, and error, then the payload
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(
+            17 + self.sink.len() + self.index.len() + self.error.len() + self.payload.len(),
+        );
+        data.extend_from_slice(&self.attempts.to_be_bytes());
+        data.push(self.denied as u8);
+        for value in &[&self.sink, &self.index, &self.error] {
+            data.extend_from_slice(&(value.len() as u32).to_be_bytes());
+            data.extend_from_slice(value.as_bytes());
+        }
+        data.extend_from_slice(&self.payload);
+        data
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let attempts = read_u32(data, 0)?;
+        let denied = *data.get(4)? != 0;
+        let (sink, offset) = read_string(data, 5)?;
+        let (index, offset) = read_string(data, offset)?;
+        let (error, offset) = read_string(data, offset)?;
+        Some(Self {
+            sink,
+            index,
+            error,
+            attempts,
+            denied,
+            payload: data[offset..].to_vec(),
+        })
+    }
+}
+
+/// Moves a queued record into the dead letter tree. The caller is still
+/// responsible for removing it from the queue.
+pub fn store(
+    db: &Db,
+    dead_letters: &Tree,
+    key: &[u8],
+    sink: String,
+    index: String,
+    error: String,
+    attempts: u32,
+) -> sled::Result<()> {
+    let payload = match db.get(key)? {
+        Some(payload) => payload.to_vec(),
+        // dropped to make room in the meantime
+        None => return Ok(()),
+    };
+    let previous_attempts = match db.open_tree(REDRIVEN_TREE)?.remove(key)? {
+        Some(attempts) => read_u32(&attempts, 0).unwrap_or(0),
+        None => 0,
+    };
+    let letter = DeadLetter {
+        sink,
+        index,
+        error,
+        attempts: previous_attempts + attempts,
+        denied: is_denial(db, key)?,
+        payload,
+    };
+    dead_letters.insert(key, letter.to_bytes())?;
+    Ok(())
+}
+
+/// All of the dead letters along with the sequence they were queued with.
+pub fn list(dead_letters: &Tree) -> impl Iterator<Item = (IVec, DeadLetter)> {
+    dead_letters.iter().filter_map(|entry| {
+        let (key, data) = entry.ok()?;
+        let letter = DeadLetter::from_bytes(&data)?;
+        Some((key, letter))
+    })
+}
+
+pub fn sequence(key: &[u8]) -> u64 {
+    key_sequence(key).unwrap_or(0)
+}
+
+pub fn find(dead_letters: &Tree, sequence: u64) -> Option<(IVec, DeadLetter)> {
+    let (key, data) = dead_letters
+        .scan_prefix(sequence.to_be_bytes())
+        .next()?
+        .ok()?;
+    let letter = DeadLetter::from_bytes(&data)?;
+    Some((key, letter))
+}
+
+/// Puts a dead letter back on the queue under a new sequence, so that it
+/// sorts after everything that's already pending, headed only for the sink
+/// that rejected it. Returns the new key, or nothing when there's no such
+/// dead letter or the queue has no room for it.
+pub fn redrive(
+    queue: &Queue,
+    db: &Db,
+    dead_letters: &Tree,
+    key: &[u8],
+) -> sled::Result<Option<Vec<u8>>> {
+    let letter = match dead_letters
+        .get(key)?
+        .and_then(|d| DeadLetter::from_bytes(&d))
+    {
+        Some(letter) => letter,
+        None => return Ok(None),
+    };
+    let sequence = next_sequence(db)?;
+    // keep the original uuid
+    let new_key = [&sequence.to_be_bytes()[..], key.get(8..).unwrap_or(&[])].concat();
+    let redriven = db.open_tree(REDRIVEN_TREE)?;
+    redriven.insert(
+        &new_key,
+        [&letter.attempts.to_be_bytes()[..], letter.sink.as_bytes()].concat(),
+    )?;
+    if !queue.push(new_key.clone(), letter.payload, letter.denied)? {
+        redriven.remove(&new_key)?;
+        return Ok(None);
+    }
+    dead_letters.remove(key)?;
+    Ok(Some(new_key))
+}
+
+/// The sink a re-driven record is headed for, if it was rejected by one.
+pub fn redriven_sink(db: &Db, key: &[u8]) -> sled::Result<Option<String>> {
+    let redriven = db.open_tree(REDRIVEN_TREE)?.get(key)?;
+    Ok(redriven
+        .and_then(|data| {
+            data.get(4..)
+                .map(|sink| String::from_utf8_lossy(sink).to_string())
+        })
+        .filter(|sink| !sink.is_empty()))
+}
+
+/// Forgets that a record was re-driven once it has been shipped.
+pub fn settled(db: &Db, key: &[u8]) -> sled::Result<()> {
+    db.open_tree(REDRIVEN_TREE)?.remove(key)?;
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use seahorse::{Command, Context, Flag, FlagType};
+use sled::{Db, Tree};
+use std::sync::Arc;
+
+use crate::dead_letters::{self, DeadLetter, DEAD_LETTERS_TREE};
+use crate::queue::{self, Queue};
+use crate::queue_limits;
+use crate::stats::Stats;
+
+// sled only allows a single process to open the database, so these have to
+// be run while the agent is stopped, re-driven records are shipped once it's
+// started again
+fn open(c: &Context) -> (Db, Tree) {
+    let data_dir = match c.string_flag("data-dir") {
+        Ok(data_dir) => data_dir,
+        Err(_) => {
+            eprintln!("--data-dir is required");
+            std::process::exit(1);
+        }
+    };
+    let db = match queue::open(Some(&data_dir)) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("could not open database, is the agent still running? {}", e);
+            std::process::exit(1);
+        }
+    };
+    let letters = db
+        .open_tree(DEAD_LETTERS_TREE)
+        .expect("dead letters could not be opened");
+    (db, letters)
+}
+
+// the dead letters named by sequence in the arguments, or all of them
+fn selected(c: &Context, letters: &Tree) -> Vec<(sled::IVec, DeadLetter)> {
+    if c.bool_flag("all") {
+        return dead_letters::list(letters).collect();
+    }
+    if c.args.is_empty() {
+        eprintln!("pass the sequence of a dead letter or --all");
+        std::process::exit(1);
+    }
+    c.args
+        .iter()
+        .filter_map(|arg| {
+            let found = arg
+                .parse()
+                .ok()
+                .and_then(|sequence| dead_letters::find(letters, sequence));
+            if found.is_none() {
+                eprintln!("no dead letter {}", arg);
+            }
+            found
+        })
+        .collect()
+}
+
+fn data_dir_flag() -> Flag {
+    Flag::new("data-dir", FlagType::String).description("Directory the agent keeps its data in")
+}
+
+fn all_flag() -> Flag {
+    Flag::new("all", FlagType::Bool).description("Apply to every dead letter")
+}
+
+// re-driven records are subject to the same limits as the agent's queue
+fn limit_flags(command: Command) -> Command {
+    command
+        .flag(
+            Flag::new("max-queue-records", FlagType::Int)
+                .description("Maximum number of events waiting to be shipped (default: unlimited)"),
+        )
+        .flag(
+            Flag::new("max-queue-size", FlagType::Int)
+                .description("Maximum size (in bytes) of events waiting to be shipped (default: unlimited)"),
+        )
+        .flag(
+            Flag::new("drop-policy", FlagType::String)
+                .description("Events to drop once the queue is full: oldest, newest, or preserve-denials (default: oldest)"),
+        )
+}
+
+fn or_dash(value: &str) -> &str {
+    if value.is_empty() {
+        "-"
+    } else {
+        value
+    }
+}
+
+fn list(c: &Context) {
+    let (_, letters) = open(c);
+    for (key, letter) in dead_letters::list(&letters) {
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            dead_letters::sequence(&key),
+            or_dash(&letter.sink),
+            or_dash(&letter.index),
+            letter.attempts,
+            letter.error
+        );
+    }
+}
+
+fn show(c: &Context) {
+    let (_, letters) = open(c);
+    let transformer = probe_sys::Transformer::new();
+    for (key, letter) in selected(c, &letters) {
+        println!("sequence: {}", dead_letters::sequence(&key));
+        println!("sink: {}", letter.sink);
+        println!("index: {}", letter.index);
+        println!("attempts: {}", letter.attempts);
+        println!("error: {}", letter.error);
+        match transformer.transform(&letter.payload) {
+            Ok((_, json)) => println!("payload: {}", json),
+            Err(e) => println!("payload: {} bytes ({})", letter.payload.len(), e),
+        }
+    }
+}
+
+fn retry(c: &Context) {
+    let (db, letters) = open(c);
+    let queue = match queue_limits(c).and_then(|limits| {
+        Queue::new(db.clone(), limits, Arc::new(Stats::default())).map_err(|e| e.to_string())
+    }) {
+        Ok(queue) => queue,
+        Err(e) => {
+            eprintln!("could not open queue: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for (key, _) in selected(c, &letters) {
+        match dead_letters::redrive(&queue, &db, &letters, &key) {
+            Ok(Some(new_key)) => println!(
+                "{} requeued as {}",
+                dead_letters::sequence(&key),
+                dead_letters::sequence(&new_key)
+            ),
+            Ok(None) => eprintln!(
+                "could not requeue {}: the queue is full",
+                dead_letters::sequence(&key)
+            ),
+            Err(e) => eprintln!("could not requeue {}: {}", dead_letters::sequence(&key), e),
+        }
+    }
+}
+
+fn purge(c: &Context) {
+    let (_, letters) = open(c);
+    for (key, _) in selected(c, &letters) {
+        if let Err(e) = letters.remove(&key) {
+            eprintln!("could not purge {}: {}", dead_letters::sequence(&key), e);
+        }
+    }
+}
+
+pub fn command() -> Command {
+    Command::new("dlq")
+        .description("Inspect and re-drive events that couldn't be transformed or shipped")
+        .usage("probe dlq [list|show|retry|purge] --data-dir [dir]")
+        .command(
+            Command::new("list")
+                .description("List dead letters by sequence, sink, index, attempts, and error")
+                .action(list)
+                .flag(data_dir_flag()),
+        )
+        .command(
+            Command::new("show")
+                .description("Show dead letters along with their payload")
+                .usage("probe dlq show --data-dir [dir] [sequence...]")
+                .action(show)
+                .flag(data_dir_flag())
+                .flag(all_flag()),
+        )
+        .command(limit_flags(
+            Command::new("retry")
+                .description("Put dead letters back on the queue for the sink that rejected them")
+                .usage("probe dlq retry --data-dir [dir] [sequence...]")
+                .action(retry)
+                .flag(data_dir_flag())
+                .flag(all_flag()),
+        ))
+        .command(
+            Command::new("purge")
+                .description("Delete dead letters")
+                .usage("probe dlq purge --data-dir [dir] [sequence...]")
+                .action(purge)
+                .flag(data_dir_flag())
+                .flag(all_flag()),
+        )
+}
@@ -1,11 +1,19 @@
 use std::collections::HashMap;
 use std::format;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use once_cell::sync::{Lazy, OnceCell};
-use sled::Db;
+use sled::{Db, Tree};
+
+use crate::dead_letters::DEAD_LETTERS_TREE;
+use crate::queue::{Queue, QueueLimits};
+use crate::stats::Stats;
 
 static DB_INSTANCE: OnceCell<Db> = OnceCell::new();
+static QUEUE: OnceCell<Queue> = OnceCell::new();
+static STATS: Lazy<Arc<Stats>> = Lazy::new(|| Arc::new(Stats::default()));
+static HASH_CACHE: OnceCell<Tree> = OnceCell::new();
+static DEAD_LETTERS: OnceCell<Tree> = OnceCell::new();
 
 pub fn global_database() -> &'static Db {
     DB_INSTANCE.get().expect("database is not initialized")
@@ -17,12 +25,62 @@ pub fn initialize_global_database(db: Db) {
         .expect("database could not be initialized");
 }
 
+pub fn global_queue() -> &'static Queue {
+    QUEUE.get().expect("queue is not initialized")
+}
+
+pub fn initialize_global_queue(limits: QueueLimits) {
+    let queue = Queue::new(global_database().clone(), limits, global_stats().clone())
+        .expect("queue could not be opened");
+    if QUEUE.set(queue).is_err() {
+        panic!("queue could not be initialized");
+    }
+}
+
+pub fn global_stats() -> &'static Arc<Stats> {
+    &STATS
+}
+
+pub fn global_hash_cache() -> &'static Tree {
+    // kept out of the default tree so the batcher never sees cache writes
+    HASH_CACHE.get_or_init(|| {
+        global_database()
+            .open_tree("executable_hashes")
+            .expect("hash cache could not be opened")
+    })
+}
+
+pub fn global_dead_letters() -> &'static Tree {
+    // records that couldn't be transformed or shipped, along with the reason why
+    DEAD_LETTERS.get_or_init(|| {
+        global_database()
+            .open_tree(DEAD_LETTERS_TREE)
+            .expect("dead letters could not be opened")
+    })
+}
+
 static TEMPLATES: Lazy<Mutex<HashMap<&'static str, &'static [u8]>>> = Lazy::new(|| {
     let mut m = HashMap::new();
     let bprm_check_security_data = include_bytes!("../elasticsearch/bprm_check_security.json");
     m.insert("bprm_check_security", &bprm_check_security_data[..]);
     let inode_unlink_data = include_bytes!("../elasticsearch/inode_unlink.json");
     m.insert("inode_unlink", &inode_unlink_data[..]);
+    let sb_mount_data = include_bytes!("../elasticsearch/sb_mount.json");
+    m.insert("sb_mount", &sb_mount_data[..]);
+    let sb_umount_data = include_bytes!("../elasticsearch/sb_umount.json");
+    m.insert("sb_umount", &sb_umount_data[..]);
+    let task_kill_data = include_bytes!("../elasticsearch/task_kill.json");
+    m.insert("task_kill", &task_kill_data[..]);
+    let mmap_file_data = include_bytes!("../elasticsearch/mmap_file.json");
+    m.insert("mmap_file", &mmap_file_data[..]);
+    let file_mprotect_data = include_bytes!("../elasticsearch/file_mprotect.json");
+    m.insert("file_mprotect", &file_mprotect_data[..]);
+    let sock_graft_data = include_bytes!("../elasticsearch/sock_graft.json");
+    m.insert("sock_graft", &sock_graft_data[..]);
+    let sched_process_fork_data = include_bytes!("../elasticsearch/sched_process_fork.json");
+    m.insert("sched_process_fork", &sched_process_fork_data[..]);
+    let sched_process_exit_data = include_bytes!("../elasticsearch/sched_process_exit.json");
+    m.insert("sched_process_exit", &sched_process_exit_data[..]);
     Mutex::new(m)
 });
 
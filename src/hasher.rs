@@ -0,0 +1,185 @@
+use chrono::Utc;
+use log::debug;
+use probe_sys::{Enricher, FieldAccess, FieldValue, SerializableResult};
+use sha2::{Digest, Sha256};
+use sled::Tree;
+use std::convert::TryInto;
+use std::fs::{File, Metadata};
+use std::io::{self, Read};
+use std::os::unix::fs::MetadataExt;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+use crate::globals::global_hash_cache;
+
+// how often expired hashes are swept out of the cache
+const PRUNE_INTERVAL: i64 = 60 * 60;
+
+// an executable is only rehashed when its device, inode, or mtime change
+fn cache_key(metadata: &Metadata) -> Vec<u8> {
+    [
+        metadata.dev().to_be_bytes(),
+        metadata.ino().to_be_bytes(),
+        metadata.mtime().to_be_bytes(),
+        metadata.mtime_nsec().to_be_bytes(),
+    ]
+    .concat()
+}
+
+// cached hashes are prefixed with when they were computed
+fn cached_at(value: &[u8]) -> Option<i64> {
+    value
+        .get(..8)
+        .map(|t| i64::from_be_bytes(t.try_into().unwrap()))
+}
+
+/// The path of an executable as seen from the agent, going through the
+/// root and working directory of the process since it may be running in
+/// another mount namespace.
+pub fn process_path(pid: u64, executable: &str) -> String {
+    if executable.starts_with('/') {
+        format!("/proc/{}/root{}", pid, executable)
+    } else {
+        format!("/proc/{}/cwd/{}", pid, executable)
+    }
+}
+
+/// The SHA256 of the file at the path, unless it's larger than `max_size`,
+/// served from the cache when it was hashed less than `max_age` ago.
+pub fn executable_hash(
+    cache: &Tree,
+    path: &str,
+    max_size: u64,
+    max_age: Duration,
+) -> Option<String> {
+    if path.is_empty() {
+        return None;
+    }
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            debug!("could not open executable {}: {}", path, e);
+            return None;
+        }
+    };
+    let metadata = file.metadata().ok().filter(|m| m.is_file())?;
+    if metadata.len() > max_size {
+        debug!(
+            "not hashing executable {} of {} bytes",
+            path,
+            metadata.len()
+        );
+        return None;
+    }
+    let key = cache_key(&metadata);
+    let now = Utc::now().timestamp();
+    if let Ok(Some(value)) = cache.get(&key) {
+        let fresh = cached_at(&value).map_or(false, |t| now - t < max_age.as_secs() as i64);
+        if fresh {
+            return Some(String::from_utf8_lossy(&value[8..]).to_string());
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    // the file could have grown since its metadata was read
+    if let Err(e) = io::copy(&mut file.take(max_size), &mut hasher) {
+        debug!("could not hash executable {}: {}", path, e);
+        return None;
+    }
+    let hash = format!("{:x}", hasher.finalize());
+    let value = [&now.to_be_bytes()[..], hash.as_bytes()].concat();
+    if let Err(e) = cache.insert(key, value) {
+        debug!("could not cache hash for {}: {}", path, e);
+    }
+    Some(hash)
+}
+
+/// Removes the hashes computed more than `max_age` ago, returning how many
+/// were removed.
+pub fn prune(cache: &Tree, max_age: Duration) -> usize {
+    let cutoff = Utc::now().timestamp() - max_age.as_secs() as i64;
+    let mut pruned = 0;
+    for (key, value) in cache.iter().flatten() {
+        if cached_at(&value).map_or(true, |t| t <= cutoff) && cache.remove(key).is_ok() {
+            pruned += 1;
+        }
+    }
+    pruned
+}
+
+/// Fills in process.hash.sha256 and process.parent.hash.sha256.
+pub struct ExecutableHashes {
+    max_size: u64,
+    max_age: Duration,
+    last_pruned: AtomicI64,
+}
+
+impl Default for ExecutableHashes {
+    fn default() -> Self {
+        Self {
+            max_size: 100 << 20,
+            max_age: Duration::from_secs(24 * 60 * 60),
+            last_pruned: AtomicI64::new(Utc::now().timestamp()),
+        }
+    }
+}
+
+impl ExecutableHashes {
+    /// Executables larger than this many bytes aren't hashed.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// How long a hash is cached before the executable is read again.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    // only one worker sweeps the cache at a time
+    fn prune_if_due(&self) {
+        let now = Utc::now().timestamp();
+        let last_pruned = self.last_pruned.load(Ordering::Relaxed);
+        if now - last_pruned < PRUNE_INTERVAL
+            || self
+                .last_pruned
+                .compare_exchange(last_pruned, now, Ordering::Relaxed, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+        let pruned = prune(global_hash_cache(), self.max_age);
+        debug!("pruned {} expired executable hashes", pruned);
+    }
+}
+
+impl Enricher for ExecutableHashes {
+    fn name(&self) -> &str {
+        "hash"
+    }
+
+    fn enrich(&self, _module: &str, event: &mut dyn FieldAccess) -> SerializableResult<()> {
+        for process in &["process", "process.parent"] {
+            let executable = match event.get_field(&format!("{}.executable", process)) {
+                Some(FieldValue::String(executable)) if !executable.is_empty() => executable,
+                _ => continue,
+            };
+            let pid = match event.get_field(&format!("{}.pid", process)) {
+                Some(FieldValue::Number(pid)) if pid > 0 => pid,
+                _ => continue,
+            };
+            let path = process_path(pid, &executable);
+            if let Some(hash) =
+                executable_hash(global_hash_cache(), &path, self.max_size, self.max_age)
+            {
+                event.set_field(
+                    &format!("{}.hash.sha256", process),
+                    FieldValue::String(hash),
+                )?;
+            }
+        }
+        self.prune_if_due();
+        Ok(())
+    }
+}
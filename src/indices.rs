@@ -0,0 +1,223 @@
+use chrono::{SecondsFormat, TimeZone, Utc};
+use sha2::{Digest, Sha256};
+use std::convert::TryInto;
+
+const TIMESTAMP_KEY: &str = "\"@timestamp\":";
+const SETTINGS_TEMPLATE: &str = "probe-settings";
+pub const LIFECYCLE_POLICY: &str = "probe";
+
+/// The ILM policy installed for the probe's indices.
+#[derive(Debug, Clone, Default)]
+pub struct Lifecycle {
+    /// Roll over once the write index is this old, i.e. `1d`.
+    pub rollover_age: Option<String>,
+    /// Roll over once the primary shards are this big, i.e. `50gb`.
+    pub rollover_size: Option<String>,
+    /// Delete indices this long after they were rolled over, i.e. `30d`.
+    pub retention: Option<String>,
+}
+
+impl Lifecycle {
+    pub fn policy(&self) -> String {
+        let mut rollover = vec![];
+        if let Some(age) = &self.rollover_age {
+            rollover.push(format!("\"max_age\":\"{}\"", age));
+        }
+        if let Some(size) = &self.rollover_size {
+            rollover.push(format!("\"max_primary_shard_size\":\"{}\"", size));
+        }
+        let mut phases = vec![];
+        if !rollover.is_empty() {
+            phases.push(format!(
+                "\"hot\":{{\"actions\":{{\"rollover\":{{{}}}}}}}",
+                rollover.join(",")
+            ));
+        }
+        if let Some(retention) = &self.retention {
+            phases.push(format!(
+                "\"delete\":{{\"min_age\":\"{}\",\"actions\":{{\"delete\":{{}}}}}}",
+                retention
+            ));
+        }
+        format!("{{\"policy\":{{\"phases\":{{{}}}}}}}", phases.join(","))
+    }
+}
+
+/// How events are written to indices.
+#[derive(Debug, Clone)]
+pub struct IndexSettings {
+    /// `{module}`, `{namespace}`, and `{date}` are filled in per event.
+    pub pattern: String,
+    pub namespace: String,
+    pub data_streams: bool,
+    pub lifecycle: Option<Lifecycle>,
+}
+
+impl Default for IndexSettings {
+    fn default() -> Self {
+        Self {
+            pattern: String::from("{module}-1"),
+            namespace: String::from("default"),
+            data_streams: false,
+            lifecycle: None,
+        }
+    }
+}
+
+// everything we put into index names or templates without escaping
+fn check_name(kind: &str, value: &str) -> Result<(), String> {
+    let invalid = |c: char| {
+        c.is_uppercase()
+            || c.is_whitespace()
+            || matches!(
+                c,
+                '"' | '\\' | '/' | '*' | '?' | '<' | '>' | '|' | ',' | '#' | ':'
+            )
+    };
+    if value.is_empty() || value.chars().any(invalid) {
+        return Err(format!("invalid {} {}", kind, value));
+    }
+    Ok(())
+}
+
+impl IndexSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.pattern.contains("{module}") {
+            return Err(String::from("index patterns need to contain {module}"));
+        }
+        if self.data_streams && self.pattern.contains("{date}") {
+            return Err(String::from(
+                "data streams roll over on their own, {date} can't be used with them",
+            ));
+        }
+        let rolls_over = self.lifecycle.as_ref().map_or(false, |l| {
+            l.rollover_age.is_some() || l.rollover_size.is_some()
+        });
+        if rolls_over && !self.data_streams {
+            return Err(String::from("rolling over indices requires data streams"));
+        }
+        check_name(
+            "index pattern",
+            &self.pattern.replace('{', "").replace('}', ""),
+        )?;
+        check_name("namespace", &self.namespace)?;
+        if let Some(lifecycle) = &self.lifecycle {
+            for value in [
+                &lifecycle.rollover_age,
+                &lifecycle.rollover_size,
+                &lifecycle.retention,
+            ]
+            .iter()
+            .filter_map(|v| v.as_ref())
+            {
+                check_name("lifecycle setting", value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The index or data stream an event is written to.
+    pub fn index(&self, module: &str, timestamp: i64) -> String {
+        self.pattern
+            .replace("{module}", module)
+            .replace("{namespace}", &self.namespace)
+            .replace(
+                "{date}",
+                &Utc.timestamp(timestamp, 0).format("%Y.%m.%d").to_string(),
+            )
+    }
+
+    fn index_pattern(&self, module: &str) -> String {
+        let pattern = self
+            .pattern
+            .replace("{module}", module)
+            .replace("{namespace}", &self.namespace)
+            .replace("{date}", "*");
+        if self.data_streams || pattern.contains('*') {
+            pattern
+        } else {
+            // rollovers of a classic index increment its suffix
+            format!("{}*", pattern.trim_end_matches(char::is_numeric))
+        }
+    }
+
+    pub fn mappings_template_name(module: &str) -> String {
+        format!("{}-mappings", module)
+    }
+
+    pub fn settings_template_name() -> &'static str {
+        SETTINGS_TEMPLATE
+    }
+
+    /// The component template shared by every index.
+    pub fn settings_template(&self) -> String {
+        let settings = match &self.lifecycle {
+            Some(_) => format!("{{\"index.lifecycle.name\":\"{}\"}}", LIFECYCLE_POLICY),
+            None => String::from("{}"),
+        };
+        with_version(&format!("\"template\":{{\"settings\":{}}}", settings))
+    }
+
+    pub fn index_template(&self, module: &str) -> String {
+        let data_stream = if self.data_streams {
+            "\"data_stream\":{},"
+        } else {
+            ""
+        };
+        with_version(&format!(
+            "\"index_patterns\":[\"{}\"],{}\"composed_of\":[\"{}\",\"{}\"],\"priority\":1",
+            self.index_pattern(module),
+            data_stream,
+            Self::mappings_template_name(module),
+            SETTINGS_TEMPLATE
+        ))
+    }
+}
+
+/// A version for a template that changes whenever its content does.
+pub fn template_version(content: &str) -> u64 {
+    let digest = Sha256::digest(content.as_bytes());
+    let bytes: [u8; 4] = digest[..4].try_into().unwrap_or_default();
+    u64::from(u32::from_be_bytes(bytes) & 0x7fff_ffff)
+}
+
+fn with_version(content: &str) -> String {
+    format!("{{\"version\":{},{}}}", template_version(content), content)
+}
+
+/// Rewrites the epoch seconds in `@timestamp` as RFC 3339, returning the
+/// timestamp along with the rewritten document.
+pub fn rfc3339_timestamp(json: &str) -> (Option<i64>, String) {
+    let unchanged = (None, json.to_string());
+    let key = match json.find(TIMESTAMP_KEY) {
+        Some(key) => key + TIMESTAMP_KEY.len(),
+        None => return unchanged,
+    };
+    let rest = &json[key..];
+    let value_start = key + rest.len() - rest.trim_start().len();
+    let value = &json[value_start..];
+    // 64 bit integers are quoted in protobuf's JSON
+    let digits: String = value
+        .trim_start_matches('"')
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    let timestamp = match digits.parse::<i64>() {
+        Ok(timestamp) => timestamp,
+        Err(_) => return unchanged,
+    };
+    let quotes = if value.starts_with('"') { 2 } else { 0 };
+    let value_end = value_start + digits.len() + quotes;
+    let formatted = Utc
+        .timestamp(timestamp, 0)
+        .to_rfc3339_opts(SecondsFormat::Secs, true);
+    (
+        Some(timestamp),
+        format!(
+            "{}\"{}\"{}",
+            &json[..value_start],
+            formatted,
+            &json[value_end..]
+        ),
+    )
+}
@@ -1,26 +1,62 @@
-use log::error;
+use log::{debug, error, info};
+use probe_sys::EnricherChain;
 use seahorse::{App, Context, Flag, FlagType};
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::client::Client;
+use crate::client::{Auth, Client};
+use crate::indices::{IndexSettings, Lifecycle};
+use crate::queue::{DropPolicy, QueueLimits};
+use crate::sinks::{
+    parse_facility, parse_routes, FileSink, ModuleMapping, OtlpSink, Rotation, Sink, Sinks,
+    SplunkSink, StdoutSink, SyslogAddress, SyslogFormat, SyslogSink, WebhookSink,
+    DEFAULT_MAX_DATAGRAM_SIZE,
+};
+use crate::tls::TlsOptions;
 
 mod batcher;
 mod client;
+mod dead_letters;
+mod dlq;
 mod errors;
 mod globals;
 mod handler;
+mod hasher;
+mod indices;
 mod logging;
+mod nodes;
+mod queue;
+mod sinks;
+mod stats;
+mod tls;
 
 #[cfg(test)]
 mod tests;
 
+const STATS_INTERVAL: Duration = Duration::from_secs(60);
+const DISCOVERY_INTERVAL: Duration = Duration::from_secs(300);
+const MODULES: [&str; 10] = [
+    "bprm_check_security",
+    "inode_unlink",
+    "sb_mount",
+    "sb_umount",
+    "task_kill",
+    "mmap_file",
+    "file_mprotect",
+    "sock_graft",
+    "sched_process_fork",
+    "sched_process_exit",
+];
+
 fn main() {
     let args: Vec<String> = std::env::args().collect();
     let app = App::new(env!("CARGO_PKG_NAME"))
         .description(env!("CARGO_PKG_DESCRIPTION"))
         .version(env!("CARGO_PKG_VERSION"))
         .action(run)
+        .command(dlq::command())
         .flag(
             Flag::new("filter", FlagType::String)
                 .description("Apply filter to the probe")
@@ -54,25 +90,237 @@ fn main() {
         .flag(
             Flag::new("host", FlagType::String)
                 .description(
-                    "Elasticsearch host that data is sent to (default: 'http://localhost:9200')",
+                    "Comma separated Elasticsearch hosts that data is sent to (default: 'http://localhost:9200')",
                 )
                 .alias("H"),
         )
+        .flag(
+            Flag::new("data-streams", FlagType::Bool)
+                .description("Write events to Elasticsearch data streams"),
+        )
+        .flag(
+            Flag::new("index-pattern", FlagType::String)
+                .description("Index name with {module}, {namespace}, and {date} placeholders (default: '{module}-1', '{module}-{namespace}' with data streams)"),
+        )
+        .flag(
+            Flag::new("namespace", FlagType::String)
+                .description("Namespace for index names (default: 'default')"),
+        )
+        .flag(
+            Flag::new("rollover-age", FlagType::String)
+                .description("Roll data streams over once they're this old, i.e. 1d"),
+        )
+        .flag(
+            Flag::new("rollover-size", FlagType::String)
+                .description("Roll data streams over once their primary shards are this big, i.e. 50gb"),
+        )
+        .flag(
+            Flag::new("retention", FlagType::String)
+                .description("Delete indices this long after they were rolled over, i.e. 30d"),
+        )
+        .flag(
+            Flag::new("discover", FlagType::Bool)
+                .description("Discover the other Elasticsearch nodes of the cluster"),
+        )
+        .flag(
+            Flag::new("recheck", FlagType::Int)
+                .description("Seconds before an unreachable Elasticsearch node is checked again (default: 30s)"),
+        )
         .flag(
             Flag::new("creds", FlagType::String)
                 .description("Credentials for Elasticsearch host")
                 .alias("c"),
         )
+        .flag(
+            Flag::new("api-key", FlagType::String)
+                .description("API key for Elasticsearch host, either id:key or base64 encoded"),
+        )
+        .flag(
+            Flag::new("bearer", FlagType::String)
+                .description("Bearer token for Elasticsearch host"),
+        )
         .flag(
             Flag::new("insecure", FlagType::Bool)
                 .description("Allow for insecure https connections to Elasticsearch host")
                 .alias("i"),
         )
+        .flag(
+            Flag::new("ca", FlagType::String)
+                .description("PEM bundle of CAs to trust for Elasticsearch host"),
+        )
+        .flag(
+            Flag::new("client-cert", FlagType::String)
+                .description("PEM client certificate for Elasticsearch host"),
+        )
+        .flag(
+            Flag::new("client-key", FlagType::String)
+                .description("PEM private key of the client certificate"),
+        )
+        .flag(
+            Flag::new("fingerprint", FlagType::String)
+                .description("Only trust an Elasticsearch host with this SHA256 certificate fingerprint"),
+        )
+        .flag(
+            Flag::new("compression", FlagType::Int)
+                .description("Gzip bulk requests at this level, 1-9 (default: uncompressed)"),
+        )
         .flag(
             Flag::new("timeout", FlagType::Int)
                 .description("Request timeout for Elasticsearch client (default: 5s)")
                 .alias("t"),
         )
+        .flag(
+            Flag::new("command-line", FlagType::Bool)
+                .description("Capture full command lines of up to 4096 bytes"),
+        )
+        .flag(
+            Flag::new("env", FlagType::String)
+                .description("Comma separated list of environment variables to capture (max 8)")
+                .alias("e"),
+        )
+        .flag(
+            Flag::new("ancestry", FlagType::Int)
+                .description("Maximum number of ancestors recorded for each process (default: 8)")
+                .alias("a"),
+        )
+        .flag(
+            Flag::new("hash", FlagType::Bool)
+                .description("Compute SHA256 hashes of process executables"),
+        )
+        .flag(
+            Flag::new("hash-max-size", FlagType::Int)
+                .description("Size (in bytes) of the largest executable hashed (default: 100MB)"),
+        )
+        .flag(
+            Flag::new("hash-max-age", FlagType::Int)
+                .description("Seconds executable hashes are cached for (default: 1 day)"),
+        )
+        .flag(Flag::new("enrichers", FlagType::String).description(
+            "Comma separated list of enrichers to run, in order (default: user,entity_id,ancestry,container,host,ecs,command_line,file,kill,network)",
+        ))
+        .flag(Flag::new("data-dir", FlagType::String).description(
+            "Directory to keep pending events in across restarts (default: in memory)",
+        ))
+        .flag(
+            Flag::new("max-queue-records", FlagType::Int)
+                .description("Maximum number of events waiting to be shipped (default: unlimited)"),
+        )
+        .flag(
+            Flag::new("max-queue-size", FlagType::Int)
+                .description("Maximum size (in bytes) of events waiting to be shipped (default: unlimited)"),
+        )
+        .flag(
+            Flag::new("drop-policy", FlagType::String)
+                .description("Events to drop once the queue is full: oldest, newest, or preserve-denials (default: oldest)"),
+        )
+        .flag(
+            Flag::new("sinks", FlagType::String)
+                .description("Comma separated sinks to ship events to: elasticsearch, stdout, file, webhook, syslog, splunk, or otlp (default: elasticsearch)"),
+        )
+        .flag(
+            Flag::new("route", FlagType::String)
+                .description("Modules to send to each sink, i.e. 'file=bprm_check_security,task_kill;webhook=task_kill' (default: every module)"),
+        )
+        .flag(
+            Flag::new("file-path", FlagType::String)
+                .description("File the file sink appends events to"),
+        )
+        .flag(
+            Flag::new("file-max-size", FlagType::Int)
+                .description("Size (in bytes) the file sink rotates at (default: 100MB)"),
+        )
+        .flag(
+            Flag::new("file-max-age", FlagType::Int)
+                .description("Seconds the file sink rotates after (default: 1 day)"),
+        )
+        .flag(
+            Flag::new("file-gzip", FlagType::Bool)
+                .description("Gzip files once the file sink rotates them"),
+        )
+        .flag(
+            Flag::new("webhook-url", FlagType::String)
+                .description("URL the webhook sink POSTs batches to"),
+        )
+        .flag(
+            Flag::new("webhook-bearer", FlagType::String)
+                .description("Bearer token for the webhook sink"),
+        )
+        .flag(
+            Flag::new("webhook-insecure", FlagType::Bool)
+                .description("Allow for insecure https connections to the webhook host"),
+        )
+        .flag(
+            Flag::new("webhook-ca", FlagType::String)
+                .description("PEM bundle of CAs to trust for the webhook host"),
+        )
+        .flag(
+            Flag::new("webhook-fingerprint", FlagType::String)
+                .description("Only trust the webhook host with this SHA256 certificate fingerprint"),
+        )
+        .flag(
+            Flag::new("syslog-address", FlagType::String)
+                .description("Where the syslog sink sends messages: unix://<path>, udp://<host:port>, or tcp://<host:port> (default: 'unix:///dev/log')"),
+        )
+        .flag(
+            Flag::new("syslog-format", FlagType::String)
+                .description("Syslog messages as json or structured data (default: json)"),
+        )
+        .flag(
+            Flag::new("syslog-facility", FlagType::String)
+                .description("Facility of syslog messages, i.e. auth or local0 (default: auth)"),
+        )
+        .flag(
+            Flag::new("syslog-max-datagram-size", FlagType::Int)
+                .description("Bytes syslog messages sent to a unix or udp address are truncated to (default: 8192)"),
+        )
+        .flag(
+            Flag::new("splunk-url", FlagType::String)
+                .description("Comma separated Splunk HTTP Event Collector URLs for the splunk sink"),
+        )
+        .flag(
+            Flag::new("splunk-token", FlagType::String)
+                .description("Token for the Splunk HTTP Event Collector"),
+        )
+        .flag(
+            Flag::new("splunk-insecure", FlagType::Bool)
+                .description("Allow for insecure https connections to the Splunk HTTP Event Collector"),
+        )
+        .flag(
+            Flag::new("splunk-ca", FlagType::String)
+                .description("PEM bundle of CAs to trust for the Splunk HTTP Event Collector"),
+        )
+        .flag(
+            Flag::new("splunk-fingerprint", FlagType::String)
+                .description("Only trust the Splunk HTTP Event Collector with this SHA256 certificate fingerprint"),
+        )
+        .flag(
+            Flag::new("splunk-sourcetypes", FlagType::String)
+                .description("Sourcetype for every module, or per module, i.e. 'bprm_check_security=probe:exec' (default: 'probe:<module>')"),
+        )
+        .flag(
+            Flag::new("splunk-indexes", FlagType::String)
+                .description("Splunk index for every module, or per module, i.e. 'security,sb_mount=mounts' (default: the token's index)"),
+        )
+        .flag(
+            Flag::new("otlp-url", FlagType::String)
+                .description("Comma separated OTLP/HTTP collector URLs for the otlp sink, i.e. 'http://localhost:4318'"),
+        )
+        .flag(
+            Flag::new("otlp-bearer", FlagType::String)
+                .description("Bearer token for the OTLP/HTTP collector"),
+        )
+        .flag(
+            Flag::new("otlp-insecure", FlagType::Bool)
+                .description("Allow for insecure https connections to the OTLP/HTTP collector"),
+        )
+        .flag(
+            Flag::new("otlp-ca", FlagType::String)
+                .description("PEM bundle of CAs to trust for the OTLP/HTTP collector"),
+        )
+        .flag(
+            Flag::new("otlp-fingerprint", FlagType::String)
+                .description("Only trust the OTLP/HTTP collector with this SHA256 certificate fingerprint"),
+        )
         .flag(
             Flag::new("local", FlagType::Bool)
                 .description(
@@ -85,10 +333,6 @@ fn main() {
 }
 
 fn run(c: &Context) {
-    let config = sled::Config::new().temporary(true);
-    let db = config.open().expect("could not open database");
-    globals::initialize_global_database(db);
-
     let debug = c.bool_flag("debug");
     logging::setup_logger(if debug {
         log::LevelFilter::Debug
@@ -96,6 +340,18 @@ fn run(c: &Context) {
         log::LevelFilter::Info
     });
 
+    let data_dir = c.string_flag("data-dir").ok();
+    let db = queue::open(data_dir.as_deref()).expect("could not open database");
+    globals::initialize_global_database(db);
+    let limits = match queue_limits(c) {
+        Ok(limits) => limits,
+        Err(e) => {
+            error!("error setting up queue: {}", e);
+            std::process::exit(1);
+        }
+    };
+    globals::initialize_global_queue(limits);
+
     let mut filters: Vec<&str> = vec![];
     let filter = c.string_flag("filter").unwrap_or_else(|_| String::from(""));
     if !filter.is_empty() {
@@ -119,30 +375,176 @@ fn run(c: &Context) {
         .int_flag("flush")
         .map_or(30, |w| u64::try_from(w).unwrap_or(30));
 
-    let host = c
+    let hosts: Vec<String> = c
         .string_flag("host")
-        .unwrap_or_else(|_| String::from("http://localhost:9200"));
-    let creds = c.string_flag("creds").ok();
-    let insecure = c.bool_flag("insecure");
+        .unwrap_or_else(|_| String::from("http://localhost:9200"))
+        .split(',')
+        .map(String::from)
+        .collect();
+    let recheck = c
+        .int_flag("recheck")
+        .map_or(30, |r| u64::try_from(r).unwrap_or(30));
+    let mut auths = vec![];
+    if let Ok(creds) = c.string_flag("creds") {
+        auths.push(Auth::Basic(creds));
+    }
+    if let Ok(key) = c.string_flag("api-key") {
+        auths.push(Auth::ApiKey(key));
+    }
+    if let Ok(token) = c.string_flag("bearer") {
+        auths.push(Auth::Bearer(token));
+    }
+    if auths.len() > 1 {
+        error!("only one of --creds, --api-key, and --bearer can be used");
+        std::process::exit(1);
+    }
+    let auth = auths.pop();
+    let tls = TlsOptions {
+        insecure: c.bool_flag("insecure"),
+        ca: c.string_flag("ca").ok(),
+        client_cert: c.string_flag("client-cert").ok(),
+        client_key: c.string_flag("client-key").ok(),
+        fingerprint: c.string_flag("fingerprint").ok(),
+    };
     let timeout = c
         .int_flag("timeout")
         .map_or(5, |t| u64::try_from(t).unwrap_or(5));
     let local = c.bool_flag("local");
+    let mut enricher_names: Vec<String> = c.string_flag("enrichers").map_or_else(
+        |_| {
+            EnricherChain::builtin()
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect()
+        },
+        |e| e.split(',').map(String::from).collect(),
+    );
+    if c.bool_flag("hash") && !enricher_names.iter().any(|name| name == "hash") {
+        enricher_names.push("hash".to_string());
+    }
+    let mut available_enrichers = EnricherChain::builtin();
+    let mut hashes = hasher::ExecutableHashes::default();
+    if let Some(max_size) = c
+        .int_flag("hash-max-size")
+        .ok()
+        .and_then(|m| u64::try_from(m).ok())
+    {
+        hashes = hashes.max_size(max_size);
+    }
+    if let Some(max_age) = c
+        .int_flag("hash-max-age")
+        .ok()
+        .and_then(|m| u64::try_from(m).ok())
+    {
+        hashes = hashes.max_age(Duration::from_secs(max_age));
+    }
+    available_enrichers.push(Box::new(hashes));
+    let enrichers = match EnricherChain::configure(&enricher_names, available_enrichers) {
+        Ok(enrichers) => Arc::new(enrichers),
+        Err(e) => {
+            error!("error setting up enrichers: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let ancestry_depth = c
+        .int_flag("ancestry")
+        .map_or(8, |d| usize::try_from(d).unwrap_or(8));
+    let command_line = c.bool_flag("command-line");
+    let environment: Vec<String> = c
+        .string_flag("env")
+        .map_or(vec![], |e| e.split(',').map(String::from).collect());
 
-    let client = Client::new(host, creds, insecure, Duration::new(timeout, 0));
-    match setup_templates(local, &client) {
+    let compression = c
+        .int_flag("compression")
+        .ok()
+        .and_then(|l| u32::try_from(l).ok())
+        .filter(|l| *l > 0);
+    let data_streams = c.bool_flag("data-streams");
+    let lifecycle = Lifecycle {
+        rollover_age: c.string_flag("rollover-age").ok(),
+        rollover_size: c.string_flag("rollover-size").ok(),
+        retention: c.string_flag("retention").ok(),
+    };
+    let has_lifecycle = lifecycle.rollover_age.is_some()
+        || lifecycle.rollover_size.is_some()
+        || lifecycle.retention.is_some();
+    let indices = IndexSettings {
+        pattern: c.string_flag("index-pattern").unwrap_or_else(|_| {
+            String::from(if data_streams {
+                "{module}-{namespace}"
+            } else {
+                "{module}-1"
+            })
+        }),
+        namespace: c
+            .string_flag("namespace")
+            .unwrap_or_else(|_| String::from("default")),
+        data_streams,
+        lifecycle: if has_lifecycle { Some(lifecycle) } else { None },
+    };
+    if let Err(e) = indices.validate() {
+        error!("error setting up indices: {}", e);
+        std::process::exit(1);
+    }
+    let client = match Client::new(hosts, auth, &tls, Duration::new(timeout, 0)) {
+        Ok(client) => client
+            .indices(indices)
+            .compression(compression)
+            .recheck_interval(Duration::from_secs(recheck)),
+        Err(e) => {
+            error!("error setting up client: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let sinks = match setup_sinks(c, local, &client, Duration::new(timeout, 0)) {
+        Ok(sinks) => Arc::new(sinks),
+        Err(e) => {
+            error!("error setting up sinks: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let elasticsearch = sinks.contains("elasticsearch");
+    if c.bool_flag("discover") && elasticsearch {
+        discover(&client);
+        let discovery_client = client.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(DISCOVERY_INTERVAL);
+            discover(&discovery_client);
+        });
+    }
+    match setup_templates(elasticsearch, &client) {
         Err(e) => {
             error!("error setting up templates: {}", e);
             std::process::exit(1);
         }
         _ => {}
     }
+    std::thread::spawn(|| loop {
+        std::thread::sleep(STATS_INTERVAL);
+        let queue = globals::global_queue();
+        info!(
+            "queue.records={} queue.bytes={} {}",
+            queue.records(),
+            queue.bytes(),
+            globals::global_stats()
+        );
+    });
     std::thread::spawn(move || loop {
-        batcher::Batcher::run(local, &client, flush_rate, batch_size, batch_bytes, workers)
+        batcher::Batcher::run(
+            sinks.clone(),
+            flush_rate,
+            batch_size,
+            batch_bytes,
+            workers,
+            enrichers.clone(),
+        )
     });
 
     match probe_sys::Probe::new()
         .debug(debug)
+        .command_line(command_line)
+        .environment(environment)
+        .ancestry_depth(ancestry_depth)
         .run(handler::Handler {})
     {
         Ok(probe) => match probe.apply(filters) {
@@ -161,10 +563,175 @@ fn run(c: &Context) {
     }
 }
 
-fn setup_templates(local: bool, client: &Client) -> Result<(), String> {
-    if !local {
-        client.ensure_template("bprm_check_security")?;
-        client.ensure_template("inode_unlink")?;
+fn discover(client: &Client) {
+    match client.discover() {
+        Ok(found) => debug!("discovered {} nodes: {:?}", found, client.nodes()),
+        Err(e) => error!("error discovering nodes: {}", e),
+    }
+}
+
+fn setup_sinks(
+    c: &Context,
+    local: bool,
+    client: &Client,
+    timeout: Duration,
+) -> Result<Sinks, String> {
+    let names = c
+        .string_flag("sinks")
+        .unwrap_or_else(|_| String::from(if local { "stdout" } else { "elasticsearch" }));
+    let mut routes = c
+        .string_flag("route")
+        .map_or(Ok(HashMap::new()), |r| parse_routes(&r))?;
+    if let Some(module) = routes
+        .values()
+        .flatten()
+        .find(|module| !MODULES.contains(&module.as_str()))
+    {
+        return Err(format!("invalid module {}", module));
+    }
+    let mut sinks = Sinks::default();
+    for name in names.split(',') {
+        let sink: Box<dyn Sink> = match name {
+            "elasticsearch" => Box::new(client.clone()),
+            "stdout" => Box::new(StdoutSink::default()),
+            "file" => {
+                let path = c
+                    .string_flag("file-path")
+                    .map_err(|_| String::from("the file sink requires --file-path"))?;
+                let defaults = Rotation::default();
+                let rotation = Rotation {
+                    max_bytes: c.int_flag("file-max-size").map_or(defaults.max_bytes, |m| {
+                        u64::try_from(m).unwrap_or(defaults.max_bytes)
+                    }),
+                    max_age: c
+                        .int_flag("file-max-age")
+                        .ok()
+                        .and_then(|m| u64::try_from(m).ok())
+                        .map_or(defaults.max_age, Duration::from_secs),
+                    gzip: c.bool_flag("file-gzip"),
+                };
+                Box::new(FileSink::new(&path, rotation)?)
+            }
+            "webhook" => {
+                let url = c
+                    .string_flag("webhook-url")
+                    .map_err(|_| String::from("the webhook sink requires --webhook-url"))?;
+                let auth = c.string_flag("webhook-bearer").ok().map(Auth::Bearer);
+                Box::new(WebhookSink::new(&url, &sink_tls(c, "webhook"), timeout)?.auth(auth))
+            }
+            "syslog" => {
+                let address = c
+                    .string_flag("syslog-address")
+                    .unwrap_or_else(|_| String::from("unix:///dev/log"))
+                    .parse::<SyslogAddress>()?;
+                let format = c
+                    .string_flag("syslog-format")
+                    .map_or(Ok(SyslogFormat::Json), |f| f.parse())?;
+                let facility = parse_facility(
+                    &c.string_flag("syslog-facility")
+                        .unwrap_or_else(|_| String::from("auth")),
+                )?;
+                let max_datagram_size = c
+                    .int_flag("syslog-max-datagram-size")
+                    .map_or(DEFAULT_MAX_DATAGRAM_SIZE, |s| {
+                        usize::try_from(s).unwrap_or(DEFAULT_MAX_DATAGRAM_SIZE)
+                    });
+                Box::new(
+                    SyslogSink::new(address, format, timeout)
+                        .facility(facility)
+                        .max_datagram_size(max_datagram_size),
+                )
+            }
+            "splunk" => {
+                let urls = c
+                    .string_flag("splunk-url")
+                    .map_err(|_| String::from("the splunk sink requires --splunk-url"))?;
+                let token = c
+                    .string_flag("splunk-token")
+                    .map_err(|_| String::from("the splunk sink requires --splunk-token"))?;
+                let sourcetypes = module_mapping(c, "splunk-sourcetypes")?;
+                let indexes = module_mapping(c, "splunk-indexes")?;
+                let client = Client::new(
+                    urls.split(',').map(String::from).collect(),
+                    Some(Auth::Splunk(token)),
+                    &sink_tls(c, "splunk"),
+                    timeout,
+                )?;
+                Box::new(
+                    SplunkSink::new(client)
+                        .sourcetypes(sourcetypes)
+                        .indexes(indexes),
+                )
+            }
+            "otlp" => {
+                let urls = c
+                    .string_flag("otlp-url")
+                    .map_err(|_| String::from("the otlp sink requires --otlp-url"))?;
+                let auth = c.string_flag("otlp-bearer").ok().map(Auth::Bearer);
+                let client = Client::new(
+                    urls.split(',').map(String::from).collect(),
+                    auth,
+                    &sink_tls(c, "otlp"),
+                    timeout,
+                )?;
+                Box::new(OtlpSink::new(client))
+            }
+            _ => return Err(format!("invalid sink {}", name)),
+        };
+        if sinks.contains(name) {
+            return Err(format!("sink {} is configured more than once", name));
+        }
+        sinks.add(sink, routes.remove(name));
+    }
+    if let Some(name) = routes.keys().next() {
+        return Err(format!("route for unconfigured sink {}", name));
+    }
+    Ok(sinks)
+}
+
+// the Elasticsearch TLS options don't carry over to sinks on other hosts
+fn sink_tls(c: &Context, sink: &str) -> TlsOptions {
+    TlsOptions {
+        insecure: c.bool_flag(&format!("{}-insecure", sink)),
+        ca: c.string_flag(&format!("{}-ca", sink)).ok(),
+        fingerprint: c.string_flag(&format!("{}-fingerprint", sink)).ok(),
+        ..TlsOptions::default()
+    }
+}
+
+fn queue_limits(c: &Context) -> Result<QueueLimits, String> {
+    let policy = c
+        .string_flag("drop-policy")
+        .map_or(Ok(DropPolicy::Oldest), |p| p.parse())?;
+    Ok(QueueLimits {
+        max_records: c
+            .int_flag("max-queue-records")
+            .ok()
+            .and_then(|m| u64::try_from(m).ok()),
+        max_bytes: c
+            .int_flag("max-queue-size")
+            .ok()
+            .and_then(|m| u64::try_from(m).ok()),
+        policy,
+    })
+}
+
+fn module_mapping(c: &Context, flag: &str) -> Result<ModuleMapping, String> {
+    let mapping = c
+        .string_flag(flag)
+        .map_or(Ok(ModuleMapping::default()), |m| ModuleMapping::parse(&m))?;
+    if let Some(module) = mapping
+        .modules()
+        .find(|module| !MODULES.contains(&module.as_str()))
+    {
+        return Err(format!("invalid module {} in --{}", module, flag));
+    }
+    Ok(mapping)
+}
+
+fn setup_templates(elasticsearch: bool, client: &Client) -> Result<(), String> {
+    if elasticsearch {
+        client.ensure_templates(&MODULES)?;
     }
     Ok(())
 }
@@ -0,0 +1,149 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+struct Node {
+    url: String,
+    // when a node that failed should be checked again
+    down_until: Option<Instant>,
+}
+
+/// The Elasticsearch nodes requests are spread across.
+pub struct NodePool {
+    // the configured hosts, which are kept after discovery so there's always
+    // a way back to the cluster
+    seeds: Vec<String>,
+    nodes: RwLock<Vec<Node>>,
+    next: AtomicUsize,
+    recheck_interval: Duration,
+}
+
+impl NodePool {
+    pub fn new(urls: Vec<String>, recheck_interval: Duration) -> Self {
+        let nodes = urls
+            .iter()
+            .map(|url| Node {
+                url: url.clone(),
+                down_until: None,
+            })
+            .collect();
+        Self {
+            seeds: urls,
+            nodes: RwLock::new(nodes),
+            next: AtomicUsize::new(0),
+            recheck_interval,
+        }
+    }
+
+    pub fn urls(&self) -> Vec<String> {
+        let nodes = self.nodes.read().unwrap_or_else(PoisonError::into_inner);
+        nodes.iter().map(|n| n.url.clone()).collect()
+    }
+
+    /// Picks the next healthy node round-robin. Nodes that are due for a
+    /// re-check are only picked once `healthy` says they're back, and when
+    /// every node is down we fall back to the next one anyway so that the
+    /// caller's backoff keeps trying.
+    pub fn pick<F>(&self, healthy: F) -> String
+    where
+        F: Fn(&str) -> bool,
+    {
+        let now = Instant::now();
+        let (candidates, fallback) = {
+            let nodes = self.nodes.read().unwrap_or_else(PoisonError::into_inner);
+            if nodes.is_empty() {
+                return String::new();
+            }
+            let start = self.next.fetch_add(1, Ordering::Relaxed);
+            let ordered: Vec<&Node> = (0..nodes.len())
+                .map(|offset| &nodes[(start + offset) % nodes.len()])
+                .collect();
+            if let Some(node) = ordered.iter().find(|n| n.down_until.is_none()) {
+                return node.url.clone();
+            }
+            let candidates: Vec<String> = ordered
+                .iter()
+                .filter(|n| n.down_until.map_or(false, |until| now >= until))
+                .map(|n| n.url.clone())
+                .collect();
+            (candidates, ordered[0].url.clone())
+        };
+        // the checks happen without holding the lock
+        for url in candidates {
+            if healthy(&url) {
+                self.mark_up(&url);
+                return url;
+            }
+            self.mark_down(&url);
+        }
+        fallback
+    }
+
+    pub fn mark_down(&self, url: &str) {
+        self.set_down_until(url, Some(Instant::now() + self.recheck_interval));
+    }
+
+    pub fn mark_up(&self, url: &str) {
+        self.set_down_until(url, None);
+    }
+
+    fn set_down_until(&self, url: &str, down_until: Option<Instant>) {
+        let mut nodes = self.nodes.write().unwrap_or_else(PoisonError::into_inner);
+        for node in nodes.iter_mut().filter(|n| n.url == url) {
+            node.down_until = down_until;
+        }
+    }
+
+    /// Swaps in the nodes found by discovery alongside the configured ones,
+    /// keeping the state of the ones we already knew about. Finding nothing
+    /// leaves the nodes as they are.
+    pub fn discovered(&self, urls: Vec<String>) {
+        if urls.is_empty() {
+            return;
+        }
+        let mut urls: Vec<String> = self.seeds.iter().cloned().chain(urls).collect();
+        urls.sort();
+        urls.dedup();
+        let mut nodes = self.nodes.write().unwrap_or_else(PoisonError::into_inner);
+        let mut replaced: Vec<Node> = urls
+            .into_iter()
+            .map(|url| Node {
+                down_until: nodes
+                    .iter()
+                    .find(|n| n.url == url)
+                    .and_then(|n| n.down_until),
+                url,
+            })
+            .collect();
+        std::mem::swap(&mut *nodes, &mut replaced);
+    }
+}
+
+/// Turns a `_nodes/http` response into node urls, using the scheme of the
+/// node that was asked.
+pub fn discovered_urls(response: &str, scheme: &str) -> Vec<String> {
+    let mut urls: Vec<String> = ajson::get(response, "nodes")
+        .map(|nodes| nodes.to_object())
+        .unwrap_or_default()
+        .values()
+        .filter_map(|node| node.get("http.publish_address"))
+        .map(|address| {
+            // publish addresses look like `hostname/10.0.0.1:9200` when the
+            // node was configured with a hostname, which is what its
+            // certificate is issued for
+            let address = address.to_string();
+            let address = match address.find('/') {
+                Some(0) => address[1..].to_string(),
+                Some(i) => {
+                    let port = address.rsplit(':').next().unwrap_or_default();
+                    format!("{}:{}", &address[..i], port)
+                }
+                None => address,
+            };
+            format!("{}://{}", scheme, address)
+        })
+        .collect();
+    urls.sort();
+    urls.dedup();
+    urls
+}
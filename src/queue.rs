@@ -0,0 +1,268 @@
+use log::info;
+use sled::{Config, Db, IVec, Tree};
+use std::convert::TryInto;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::stats::Stats;
+
+const METADATA_TREE: &str = "metadata";
+// the keys of queued denials, so they're never evicted for other records
+const DENIALS_TREE: &str = "denials";
+const SEQUENCE_KEY: &[u8] = b"sequence";
+
+// records are keyed by their big endian sequence number followed by their
+// uuid, so the queue is always ordered by sequence
+pub fn key_sequence(key: &[u8]) -> Option<u64> {
+    key.get(..8)?.try_into().ok().map(u64::from_be_bytes)
+}
+
+fn decode_sequence(value: Option<&[u8]>) -> Option<u64> {
+    value.and_then(key_sequence)
+}
+
+fn metadata(db: &Db) -> sled::Result<Tree> {
+    db.open_tree(METADATA_TREE)
+}
+
+/// Opens the queue, in `data_dir` when given so that pending records survive
+/// restarts, otherwise in a temporary database.
+pub fn open(data_dir: Option<&str>) -> sled::Result<Db> {
+    let config = match data_dir {
+        Some(path) => Config::new().path(path),
+        None => Config::new().temporary(true),
+    };
+    let db = config.open()?;
+    restore_sequence(&db)?;
+    let pending = db.len();
+    if pending > 0 {
+        info!("replaying {} pending records", pending);
+    }
+    Ok(db)
+}
+
+/// Makes sure the next sequence sorts after every record still in the queue,
+/// even when the database was written by something that didn't persist its
+/// sequence.
+pub fn restore_sequence(db: &Db) -> sled::Result<()> {
+    let last = match db.last()? {
+        Some((key, _)) => key_sequence(&key),
+        None => None,
+    };
+    if let Some(last) = last {
+        metadata(db)?.fetch_and_update(SEQUENCE_KEY, |current| {
+            let current = decode_sequence(current);
+            let restored = current.map_or(last, |current| current.max(last));
+            Some(restored.to_be_bytes().to_vec())
+        })?;
+    }
+    Ok(())
+}
+
+/// Returns the next sequence number. Unlike `Db::generate_id` every
+/// sequence is persisted, so they stay strictly increasing across restarts.
+pub fn next_sequence(db: &Db) -> sled::Result<u64> {
+    let next = metadata(db)?.update_and_fetch(SEQUENCE_KEY, |current| {
+        let next = decode_sequence(current).map_or(0, |current| current + 1);
+        Some(next.to_be_bytes().to_vec())
+    })?;
+    Ok(decode_sequence(next.as_deref()).unwrap_or(0))
+}
+
+/// Whether a queued record is a denial.
+pub fn is_denial(db: &Db, key: &[u8]) -> sled::Result<bool> {
+    db.open_tree(DENIALS_TREE)?.contains_key(key)
+}
+
+/// Records that were already queued when `until` was the last key, i.e.
+/// the ones left over from a previous run.
+pub fn pending(db: &Db, until: &IVec) -> impl Iterator<Item = (IVec, IVec)> {
+    db.range(..=until).filter_map(Result::ok)
+}
+
+/// What to do with events once the queue is full.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropPolicy {
+    /// Make room by dropping the oldest queued events.
+    Oldest,
+    /// Drop incoming events until there's room again.
+    Newest,
+    /// Drop incoming events unless they're denials, which make room by
+    /// dropping the oldest queued event that isn't a denial.
+    PreserveDenials,
+}
+
+impl FromStr for DropPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "oldest" => Ok(DropPolicy::Oldest),
+            "newest" => Ok(DropPolicy::Newest),
+            "preserve-denials" => Ok(DropPolicy::PreserveDenials),
+            _ => Err(format!("invalid drop policy {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct QueueLimits {
+    pub max_records: Option<u64>,
+    pub max_bytes: Option<u64>,
+    pub policy: DropPolicy,
+}
+
+impl Default for QueueLimits {
+    fn default() -> Self {
+        Self {
+            max_records: None,
+            max_bytes: None,
+            policy: DropPolicy::Oldest,
+        }
+    }
+}
+
+/// The records waiting to be shipped, bounded by `QueueLimits`.
+pub struct Queue {
+    db: Db,
+    denials: Tree,
+    limits: QueueLimits,
+    stats: Arc<Stats>,
+    records: AtomicU64,
+    bytes: AtomicU64,
+    // held while checking for room and inserting, so concurrent pushes
+    // can't both take the last spot
+    push_lock: Mutex<()>,
+}
+
+impl Queue {
+    pub fn new(db: Db, limits: QueueLimits, stats: Arc<Stats>) -> sled::Result<Self> {
+        let (records, bytes) = db
+            .iter()
+            .values()
+            .filter_map(Result::ok)
+            .fold((0, 0), |(records, bytes), data| {
+                (records + 1, bytes + data.len() as u64)
+            });
+        Ok(Self {
+            denials: db.open_tree(DENIALS_TREE)?,
+            db,
+            limits,
+            stats,
+            records: AtomicU64::new(records),
+            bytes: AtomicU64::new(bytes),
+            push_lock: Mutex::new(()),
+        })
+    }
+
+    pub fn records(&self) -> u64 {
+        self.records.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    fn has_room_for(&self, size: u64) -> bool {
+        let records_ok = self
+            .limits
+            .max_records
+            .map_or(true, |max| self.records() < max);
+        let bytes_ok = self
+            .limits
+            .max_bytes
+            .map_or(true, |max| self.bytes() + size <= max);
+        records_ok && bytes_ok
+    }
+
+    // drops the oldest queued record that isn't a denial, returning whether
+    // there was one
+    fn evict_oldest_allowed(&self) -> sled::Result<bool> {
+        for key in self.db.iter().keys() {
+            let key = key?;
+            if self.denials.contains_key(&key)? {
+                continue;
+            }
+            // it may have been shipped in the meantime
+            if let Some(data) = self.db.remove(&key)? {
+                self.forget(data.len() as u64);
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Queues a record, applying the drop policy when the queue is full.
+    /// Returns whether the record was queued.
+    pub fn push(&self, key: Vec<u8>, data: Vec<u8>, denied: bool) -> sled::Result<bool> {
+        let size = data.len() as u64;
+        let _guard = self
+            .push_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if !self.has_room_for(size) {
+            match self.limits.policy {
+                DropPolicy::Oldest => {
+                    while !self.has_room_for(size) {
+                        match self.db.pop_min()? {
+                            Some((oldest_key, oldest)) => {
+                                self.denials.remove(oldest_key)?;
+                                self.forget(oldest.len() as u64);
+                                Stats::increment(&self.stats.dropped_oldest);
+                            }
+                            // the record is bigger than the whole queue
+                            None => {
+                                Stats::increment(&self.stats.dropped_oldest);
+                                return Ok(false);
+                            }
+                        }
+                    }
+                }
+                DropPolicy::Newest => {
+                    Stats::increment(&self.stats.dropped_newest);
+                    return Ok(false);
+                }
+                DropPolicy::PreserveDenials if !denied => {
+                    Stats::increment(&self.stats.dropped_allowed);
+                    return Ok(false);
+                }
+                DropPolicy::PreserveDenials => {
+                    while !self.has_room_for(size) {
+                        if !self.evict_oldest_allowed()? {
+                            // the queue is all denials, which are kept over
+                            // newer ones
+                            Stats::increment(&self.stats.dropped_denials);
+                            return Ok(false);
+                        }
+                        Stats::increment(&self.stats.evicted_allowed);
+                    }
+                }
+            }
+        }
+        if denied {
+            self.denials.insert(&key, &b""[..])?;
+        }
+        if let Some(previous) = self.db.insert(key, data)? {
+            self.forget(previous.len() as u64);
+        }
+        self.records.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+        Ok(true)
+    }
+
+    /// Removes a record once it has been handled.
+    pub fn remove(&self, key: &[u8]) -> sled::Result<()> {
+        self.denials.remove(key)?;
+        // the record may already have been dropped to make room
+        if let Some(data) = self.db.remove(key)? {
+            self.forget(data.len() as u64);
+        }
+        Ok(())
+    }
+
+    fn forget(&self, size: u64) {
+        self.records.fetch_sub(1, Ordering::Relaxed);
+        self.bytes.fetch_sub(size, Ordering::Relaxed);
+    }
+}
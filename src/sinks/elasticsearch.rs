@@ -0,0 +1,20 @@
+use super::{Event, Sink};
+use crate::client::{BulkStatus, Client};
+
+impl Sink for Client {
+    fn name(&self) -> &str {
+        "elasticsearch"
+    }
+
+    fn destination(&self, event: &Event) -> String {
+        self.index_name(&event.module, event.timestamp)
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let documents: Vec<(String, String)> = events
+            .iter()
+            .map(|event| (self.destination(event), event.document.clone()))
+            .collect();
+        self.send_batch(&documents)
+    }
+}
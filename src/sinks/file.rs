@@ -0,0 +1,141 @@
+use chrono::Utc;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+
+use super::{ndjson, Event, Sink};
+use crate::client::BulkStatus;
+
+/// When the file that's being written to is rotated out.
+#[derive(Debug, Clone)]
+pub struct Rotation {
+    pub max_bytes: u64,
+    pub max_age: Duration,
+    /// Gzip files once they've been rotated out.
+    pub gzip: bool,
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self {
+            max_bytes: 100 << 20,
+            max_age: Duration::from_secs(24 * 60 * 60),
+            gzip: false,
+        }
+    }
+}
+
+struct Current {
+    file: File,
+    bytes: u64,
+    opened: SystemTime,
+}
+
+/// Appends events to a local file as newline delimited JSON, rotating it to
+/// `<path>.<timestamp>` once it gets too big or too old.
+pub struct FileSink {
+    path: PathBuf,
+    rotation: Rotation,
+    current: Mutex<Option<Current>>,
+}
+
+fn gzip(path: &Path) -> io::Result<()> {
+    let mut compressed = path.as_os_str().to_owned();
+    compressed.push(".gz");
+    let mut encoder = GzEncoder::new(File::create(compressed)?, Compression::default());
+    io::copy(&mut BufReader::new(File::open(path)?), &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    fs::remove_file(path)
+}
+
+impl FileSink {
+    pub fn new(path: &str, rotation: Rotation) -> Result<Self, String> {
+        let path = PathBuf::from(path);
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {}", parent.display(), e))?;
+        }
+        Ok(Self {
+            path,
+            rotation,
+            current: Mutex::new(None),
+        })
+    }
+
+    fn open(&self) -> io::Result<Current> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let metadata = file.metadata()?;
+        Ok(Current {
+            bytes: metadata.len(),
+            // a file left over from a previous run keeps its age
+            opened: metadata.created().unwrap_or_else(|_| SystemTime::now()),
+            file,
+        })
+    }
+
+    fn due(&self, current: &Current, incoming: u64) -> bool {
+        let age = SystemTime::now()
+            .duration_since(current.opened)
+            .unwrap_or_default();
+        current.bytes > 0
+            && (current.bytes + incoming > self.rotation.max_bytes || age >= self.rotation.max_age)
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        let mut rotated = self.path.as_os_str().to_owned();
+        rotated.push(format!(".{}", Utc::now().format("%Y%m%dT%H%M%S%.3f")));
+        let rotated = PathBuf::from(rotated);
+        fs::rename(&self.path, &rotated)?;
+        if self.rotation.gzip {
+            gzip(&rotated)?;
+        }
+        Ok(())
+    }
+
+    fn write(&self, data: &[u8]) -> io::Result<()> {
+        let mut current = self.current.lock().unwrap_or_else(PoisonError::into_inner);
+        if current.is_none() {
+            *current = Some(self.open()?);
+        }
+        if current
+            .as_ref()
+            .map_or(false, |c| self.due(c, data.len() as u64))
+        {
+            // close the file before it's moved out of the way
+            *current = None;
+            self.rotate()?;
+            *current = Some(self.open()?);
+        }
+        if let Some(current) = current.as_mut() {
+            current.file.write_all(data)?;
+            current.file.flush()?;
+            current.bytes += data.len() as u64;
+        }
+        Ok(())
+    }
+}
+
+impl Sink for FileSink {
+    fn name(&self) -> &str {
+        "file"
+    }
+
+    fn destination(&self, _event: &Event) -> String {
+        self.path.display().to_string()
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let status = match self.write(ndjson(events).as_bytes()) {
+            Ok(_) => BulkStatus::Indexed,
+            Err(e) => BulkStatus::Retry(format!("{}: {}", self.path.display(), e)),
+        };
+        vec![status; events.len()]
+    }
+}
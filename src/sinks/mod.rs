@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::client::BulkStatus;
+
+mod elasticsearch;
+mod file;
+mod otlp;
+mod splunk;
+mod stdout;
+mod syslog;
+mod webhook;
+
+pub use file::{FileSink, Rotation};
+pub use otlp::OtlpSink;
+pub use splunk::{ModuleMapping, SplunkSink};
+pub use stdout::StdoutSink;
+pub use syslog::{
+    parse_facility, SyslogAddress, SyslogFormat, SyslogSink, DEFAULT_MAX_DATAGRAM_SIZE,
+};
+pub use webhook::WebhookSink;
+
+/// A transformed event on its way to the sinks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// The module that produced the event, i.e. `bprm_check_security`.
+    pub module: String,
+    /// Seconds since the epoch.
+    pub timestamp: i64,
+    /// The event as ECS JSON.
+    pub document: String,
+}
+
+/// Somewhere batches of events are shipped to.
+pub trait Sink: Send + Sync {
+    /// The name the sink is configured and routed by.
+    fn name(&self) -> &str;
+
+    /// Where an event ends up, recorded alongside dead letters.
+    fn destination(&self, _event: &Event) -> String {
+        self.name().to_string()
+    }
+
+    /// Ships a batch, the returned statuses line up with the events.
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus>;
+}
+
+/// The configured sinks along with the modules routed to each of them.
+#[derive(Default)]
+pub struct Sinks {
+    sinks: Vec<(Box<dyn Sink>, Option<Vec<String>>)>,
+}
+
+impl Sinks {
+    /// Adds a sink that receives events from the given modules, or from
+    /// every module when there's no route for it.
+    pub fn add(&mut self, sink: Box<dyn Sink>, modules: Option<Vec<String>>) {
+        self.sinks.push((sink, modules));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Sink> {
+        self.sinks.iter().map(|(sink, _)| sink.as_ref())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.iter().any(|sink| sink.name() == name)
+    }
+
+    /// The index of the sink with the name, if it's configured.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.iter().position(|sink| sink.name() == name)
+    }
+
+    /// The indices of the sinks an event from the module is routed to.
+    pub fn routes(&self, module: &str) -> Vec<usize> {
+        self.sinks
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, modules))| {
+                modules
+                    .as_ref()
+                    .map_or(true, |modules| modules.iter().any(|m| m == module))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Parses routes like `file=bprm_check_security,task_kill;webhook=task_kill`
+/// into the modules for each sink.
+pub fn parse_routes(routes: &str) -> Result<HashMap<String, Vec<String>>, String> {
+    let mut parsed = HashMap::new();
+    for route in routes.split(';').filter(|r| !r.is_empty()) {
+        let (sink, modules) = match route.find('=') {
+            Some(i) => (&route[..i], &route[i + 1..]),
+            None => return Err(format!("invalid route {}", route)),
+        };
+        let modules: Vec<String> = modules
+            .split(',')
+            .filter(|m| !m.is_empty())
+            .map(String::from)
+            .collect();
+        if sink.is_empty() || modules.is_empty() {
+            return Err(format!("invalid route {}", route));
+        }
+        parsed
+            .entry(sink.to_string())
+            .or_insert_with(Vec::new)
+            .extend(modules);
+    }
+    Ok(parsed)
+}
+
+// newline delimited JSON, as written by the stdout, file, and webhook sinks
+fn ndjson(events: &[&Event]) -> String {
+    events
+        .iter()
+        .map(|event| format!("{}\n", event.document))
+        .collect()
+}
+
+// the leaf fields of a JSON document by their dotted path, sorted
+fn fields(document: &str) -> Vec<(String, ajson::Value)> {
+    fn flatten(prefix: &str, value: ajson::Value, fields: &mut Vec<(String, ajson::Value)>) {
+        if value.is_object() {
+            for (key, child) in value.to_object() {
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&path, child, fields);
+            }
+        } else {
+            fields.push((prefix.to_string(), value));
+        }
+    }
+    let mut fields = vec![];
+    if let Some(document) = ajson::parse(document) {
+        flatten("", document, &mut fields);
+    }
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    fields
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
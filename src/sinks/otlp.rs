@@ -0,0 +1,159 @@
+use backoff::backoff::Backoff;
+use chrono::Utc;
+
+use super::{fields, json_string, Event, Sink};
+use crate::client::{backoff, BulkStatus, Client};
+use crate::handler::is_denied;
+
+const LOGS_PATH: &str = "/v1/logs";
+const SERVICE_NAME: &str = "probe";
+// severity numbers from the OpenTelemetry logs data model
+const SEVERITY_INFO: (u8, &str) = (9, "INFO");
+const SEVERITY_WARN: (u8, &str) = (13, "WARN");
+
+// an AnyValue in OTLP's JSON encoding, where 64 bit integers are strings
+fn any_value(value: &ajson::Value) -> String {
+    if value.is_array() {
+        let values: Vec<String> = value.to_vec().iter().map(any_value).collect();
+        format!("{{\"arrayValue\":{{\"values\":[{}]}}}}", values.join(","))
+    } else if value.is_bool() {
+        format!("{{\"boolValue\":{}}}", value.to_bool())
+    } else if value.is_number() {
+        let number = value.to_string();
+        if number.parse::<i64>().is_ok() {
+            format!("{{\"intValue\":\"{}\"}}", number)
+        } else {
+            format!("{{\"doubleValue\":{}}}", number)
+        }
+    } else {
+        format!("{{\"stringValue\":{}}}", json_string(&value.to_string()))
+    }
+}
+
+fn key_value(key: &str, value: String) -> String {
+    format!("{{\"key\":{},\"value\":{}}}", json_string(key), value)
+}
+
+// ECS host fields along with their resource semantic convention names
+fn resource_key(field: &str) -> String {
+    match field {
+        "host.hostname" => String::from("host.name"),
+        "host.os.type" => String::from("os.type"),
+        "host.os.name" => String::from("os.name"),
+        _ => field.to_string(),
+    }
+}
+
+/// Exports events as OpenTelemetry log records over OTLP/HTTP, with the
+/// `host` fields of each event describing its resource and the rest of the
+/// event as attributes.
+pub struct OtlpSink {
+    client: Client,
+}
+
+impl OtlpSink {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    // the resource attributes and log record of an event
+    fn log_record(event: &Event) -> (String, String) {
+        let (host, attributes): (Vec<_>, Vec<_>) = fields(&event.document)
+            .into_iter()
+            .filter(|(key, _)| key != "@timestamp")
+            .partition(|(key, _)| key.starts_with("host."));
+        let mut resource = vec![key_value(
+            "service.name",
+            format!("{{\"stringValue\":\"{}\"}}", SERVICE_NAME),
+        )];
+        resource.extend(
+            host.iter()
+                .map(|(key, value)| key_value(&resource_key(key), any_value(value))),
+        );
+        let action = attributes
+            .iter()
+            .find(|(key, _)| key == "event.action")
+            .map_or_else(|| event.module.clone(), |(_, value)| value.to_string());
+        let (severity_number, severity_text) = if is_denied(&action) {
+            SEVERITY_WARN
+        } else {
+            SEVERITY_INFO
+        };
+        let attributes: Vec<String> = attributes
+            .iter()
+            .map(|(key, value)| key_value(key, any_value(value)))
+            .collect();
+        let record = format!(
+            "{{\"timeUnixNano\":\"{}\",\"observedTimeUnixNano\":\"{}\",\"severityNumber\":{},\"severityText\":\"{}\",\"body\":{{\"stringValue\":{}}},\"attributes\":[{}]}}",
+            i128::from(event.timestamp) * 1_000_000_000,
+            Utc::now().timestamp_nanos(),
+            severity_number,
+            severity_text,
+            json_string(&action),
+            attributes.join(",")
+        );
+        (resource.join(","), record)
+    }
+
+    /// An export request with a resource for every distinct set of host
+    /// fields in the batch.
+    pub fn export_request(events: &[&Event]) -> String {
+        let mut resources: Vec<(String, Vec<String>)> = vec![];
+        for event in events {
+            let (resource, record) = Self::log_record(event);
+            match resources.iter_mut().find(|(r, _)| *r == resource) {
+                Some((_, records)) => records.push(record),
+                None => resources.push((resource, vec![record])),
+            }
+        }
+        let resource_logs: Vec<String> = resources
+            .iter()
+            .map(|(resource, records)| {
+                format!(
+                    "{{\"resource\":{{\"attributes\":[{}]}},\"scopeLogs\":[{{\"scope\":{{\"name\":\"{}\",\"version\":\"{}\"}},\"logRecords\":[{}]}}]}}",
+                    resource,
+                    SERVICE_NAME,
+                    env!("CARGO_PKG_VERSION"),
+                    records.join(",")
+                )
+            })
+            .collect();
+        format!("{{\"resourceLogs\":[{}]}}", resource_logs.join(","))
+    }
+}
+
+impl Sink for OtlpSink {
+    fn name(&self) -> &str {
+        "otlp"
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let body = Self::export_request(events);
+        let mut attempts = 0;
+        let mut backoff = backoff();
+        let status = loop {
+            attempts += 1;
+            let response =
+                self.client
+                    .request("POST", LOGS_PATH, "application/json", body.as_bytes());
+            let error = match response {
+                Ok((200..=299, _)) => break BulkStatus::Indexed,
+                // the statuses OTLP/HTTP exporters are supposed to retry
+                Ok((status @ 429, _)) | Ok((status @ 502..=504, _)) => format!("status {}", status),
+                Ok((status, response)) => {
+                    let error = match ajson::get(&response, "message") {
+                        Some(message) => format!("status {}: {}", status, message),
+                        None => format!("status {}", status),
+                    };
+                    break BulkStatus::Rejected { error, attempts };
+                }
+                Err(error) => error,
+            };
+            match backoff.next_backoff() {
+                Some(wait) => std::thread::sleep(wait),
+                None => break BulkStatus::Retry(error),
+            }
+        };
+        vec![status; events.len()]
+    }
+}
@@ -0,0 +1,169 @@
+use backoff::backoff::Backoff;
+use std::collections::HashMap;
+
+use super::{json_string, Event, Sink};
+use crate::client::{backoff, BulkStatus, Client};
+
+const EVENT_PATH: &str = "/services/collector/event";
+const SOURCE: &str = "probe";
+
+/// A value per module, i.e. `security` or
+/// `bprm_check_security=exec,inode_unlink=files`, where a value without a
+/// module applies to every module that isn't listed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleMapping {
+    default: Option<String>,
+    modules: HashMap<String, String>,
+}
+
+impl ModuleMapping {
+    pub fn parse(mapping: &str) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        for entry in mapping.split(',').filter(|e| !e.is_empty()) {
+            match entry.find('=') {
+                Some(i) if i > 0 && i + 1 < entry.len() => {
+                    parsed
+                        .modules
+                        .insert(entry[..i].to_string(), entry[i + 1..].to_string());
+                }
+                None if parsed.default.is_none() => parsed.default = Some(entry.to_string()),
+                _ => return Err(format!("invalid mapping {}", entry)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn get(&self, module: &str) -> Option<&str> {
+        self.modules
+            .get(module)
+            .or_else(|| self.default.as_ref())
+            .map(|v| v.as_str())
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = &String> {
+        self.modules.keys()
+    }
+}
+
+/// Sends events to a Splunk HTTP Event Collector, through a client that's
+/// set up with the collector's URLs and token.
+pub struct SplunkSink {
+    client: Client,
+    sourcetypes: ModuleMapping,
+    indexes: ModuleMapping,
+}
+
+impl SplunkSink {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            sourcetypes: ModuleMapping::default(),
+            indexes: ModuleMapping::default(),
+        }
+    }
+
+    /// Sourcetypes by module, `probe:<module>` unless set.
+    pub fn sourcetypes(mut self, sourcetypes: ModuleMapping) -> Self {
+        self.sourcetypes = sourcetypes;
+        self
+    }
+
+    /// Indexes by module, the token's default index unless set.
+    pub fn indexes(mut self, indexes: ModuleMapping) -> Self {
+        self.indexes = indexes;
+        self
+    }
+
+    fn envelope(&self, event: &Event) -> String {
+        let sourcetype = self
+            .sourcetypes
+            .get(&event.module)
+            .map_or_else(|| format!("probe:{}", event.module), String::from);
+        let mut envelope = format!(
+            "{{\"time\":{},\"source\":\"{}\",\"sourcetype\":{}",
+            event.timestamp,
+            SOURCE,
+            json_string(&sourcetype)
+        );
+        if let Some(host) = ajson::get(&event.document, "host.hostname") {
+            envelope.push_str(&format!(",\"host\":{}", json_string(&host.to_string())));
+        }
+        if let Some(index) = self.indexes.get(&event.module) {
+            envelope.push_str(&format!(",\"index\":{}", json_string(index)));
+        }
+        format!("{},\"event\":{}}}\n", envelope, event.document)
+    }
+}
+
+impl Sink for SplunkSink {
+    fn name(&self) -> &str {
+        "splunk"
+    }
+
+    fn destination(&self, event: &Event) -> String {
+        self.indexes
+            .get(&event.module)
+            .unwrap_or("splunk")
+            .to_string()
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let mut statuses = vec![BulkStatus::Indexed; events.len()];
+        let mut pending: Vec<usize> = (0..events.len()).collect();
+        let mut attempts = 0;
+        let mut backoff = backoff();
+        while !pending.is_empty() {
+            attempts += 1;
+            let body: String = pending.iter().map(|i| self.envelope(events[*i])).collect();
+            let mut retries = vec![];
+            match self
+                .client
+                .request("POST", EVENT_PATH, "application/json", body.as_bytes())
+            {
+                Ok((200..=299, _)) => {}
+                Ok((status, response)) => {
+                    let error = match ajson::get(&response, "text") {
+                        Some(text) => format!("status {}: {}", status, text),
+                        None => format!("status {}", status),
+                    };
+                    let retryable = status == 429 || status >= 500;
+                    // the collector indexes everything up to the first
+                    // event it can't handle and drops the rest, which are
+                    // sent again
+                    let invalid = ajson::get(&response, "invalid-event-number")
+                        .map(|n| n.to_u64() as usize)
+                        .filter(|n| !retryable && *n < pending.len());
+                    for (position, i) in pending.iter().enumerate() {
+                        statuses[*i] = match invalid {
+                            Some(n) if position < n => BulkStatus::Indexed,
+                            Some(n) if position > n => {
+                                retries.push(*i);
+                                BulkStatus::Retry(String::from("an earlier event was invalid"))
+                            }
+                            _ if retryable => {
+                                retries.push(*i);
+                                BulkStatus::Retry(error.clone())
+                            }
+                            _ => BulkStatus::Rejected {
+                                error: error.clone(),
+                                attempts,
+                            },
+                        };
+                    }
+                }
+                Err(error) => {
+                    for i in &pending {
+                        statuses[*i] = BulkStatus::Retry(error.clone());
+                    }
+                    retries = pending;
+                }
+            }
+            pending = retries;
+            match backoff.next_backoff() {
+                Some(wait) if !pending.is_empty() => std::thread::sleep(wait),
+                _ => break,
+            }
+        }
+        statuses
+    }
+}
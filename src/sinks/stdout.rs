@@ -0,0 +1,27 @@
+use std::io::Write;
+
+use super::{ndjson, Event, Sink};
+use crate::client::BulkStatus;
+
+/// Echoes events to stdout as newline delimited JSON.
+#[derive(Default)]
+pub struct StdoutSink {}
+
+impl Sink for StdoutSink {
+    fn name(&self) -> &str {
+        "stdout"
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let stdout = std::io::stdout();
+        let mut stdout = stdout.lock();
+        let status = match stdout
+            .write_all(ndjson(events).as_bytes())
+            .and_then(|_| stdout.flush())
+        {
+            Ok(_) => BulkStatus::Indexed,
+            Err(e) => BulkStatus::Retry(e.to_string()),
+        };
+        vec![status; events.len()]
+    }
+}
@@ -0,0 +1,306 @@
+use chrono::{SecondsFormat, TimeZone, Utc};
+use sha2::{Digest, Sha256};
+use std::io::{self, Write};
+use std::net::{TcpStream, UdpSocket};
+use std::os::unix::net::UnixDatagram;
+use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use super::{fields, Event, Sink};
+use crate::client::BulkStatus;
+use crate::handler::is_denied;
+
+const APP_NAME: &str = "probe";
+// the enterprise number set aside for documentation, until we have our own
+const SD_ID: &str = "probe@32473";
+const SEVERITY_WARNING: u8 = 4;
+const SEVERITY_INFORMATIONAL: u8 = 6;
+// structured data parameter names are limited to 32 characters
+const MAX_NAME_LENGTH: usize = 32;
+/// The default size messages sent over a datagram socket are truncated to.
+pub const DEFAULT_MAX_DATAGRAM_SIZE: usize = 8192;
+// EMSGSIZE on Linux
+const MESSAGE_TOO_LONG: i32 = 90;
+
+/// Where syslog messages are sent, i.e. `unix:///dev/log`,
+/// `udp://127.0.0.1:514`, or `tcp://127.0.0.1:601`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyslogAddress {
+    Unix(String),
+    Udp(String),
+    Tcp(String),
+}
+
+impl FromStr for SyslogAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scheme, address) = match s.find("://") {
+            Some(i) => (&s[..i], &s[i + 3..]),
+            None => return Err(format!("invalid syslog address {}", s)),
+        };
+        match scheme {
+            "unix" => Ok(SyslogAddress::Unix(address.to_string())),
+            "udp" => Ok(SyslogAddress::Udp(address.to_string())),
+            "tcp" => Ok(SyslogAddress::Tcp(address.to_string())),
+            _ => Err(format!("invalid syslog address {}", s)),
+        }
+    }
+}
+
+/// How events are laid out in a message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyslogFormat {
+    /// The ECS JSON of the event as the message.
+    Json,
+    /// The fields of the event as structured data, with the action as the
+    /// message.
+    Structured,
+}
+
+impl FromStr for SyslogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(SyslogFormat::Json),
+            "structured" => Ok(SyslogFormat::Structured),
+            _ => Err(format!("invalid syslog format {}", s)),
+        }
+    }
+}
+
+const FACILITIES: [&str; 24] = [
+    "kern",
+    "user",
+    "mail",
+    "daemon",
+    "auth",
+    "syslog",
+    "lpr",
+    "news",
+    "uucp",
+    "cron",
+    "authpriv",
+    "ftp",
+    "ntp",
+    "security",
+    "console",
+    "solaris-cron",
+    "local0",
+    "local1",
+    "local2",
+    "local3",
+    "local4",
+    "local5",
+    "local6",
+    "local7",
+];
+
+pub fn parse_facility(facility: &str) -> Result<u8, String> {
+    FACILITIES
+        .iter()
+        .position(|f| *f == facility)
+        .map(|f| f as u8)
+        .ok_or_else(|| format!("invalid syslog facility {}", facility))
+}
+
+enum Connection {
+    Unix(UnixDatagram),
+    Udp(UdpSocket),
+    Tcp(TcpStream),
+}
+
+impl Connection {
+    fn open(address: &SyslogAddress, timeout: Duration) -> io::Result<Self> {
+        match address {
+            SyslogAddress::Unix(path) => {
+                let socket = UnixDatagram::unbound()?;
+                socket.connect(path)?;
+                socket.set_write_timeout(Some(timeout))?;
+                Ok(Connection::Unix(socket))
+            }
+            SyslogAddress::Udp(address) => {
+                let socket = UdpSocket::bind("0.0.0.0:0")?;
+                socket.connect(address)?;
+                socket.set_write_timeout(Some(timeout))?;
+                Ok(Connection::Udp(socket))
+            }
+            SyslogAddress::Tcp(address) => {
+                let stream = TcpStream::connect(address)?;
+                stream.set_write_timeout(Some(timeout))?;
+                Ok(Connection::Tcp(stream))
+            }
+        }
+    }
+
+    fn send(&mut self, message: &str, max_datagram_size: usize) -> io::Result<()> {
+        match self {
+            Connection::Unix(socket) => socket
+                .send(truncate(message, max_datagram_size).as_bytes())
+                .map(|_| ()),
+            Connection::Udp(socket) => socket
+                .send(truncate(message, max_datagram_size).as_bytes())
+                .map(|_| ()),
+            // octet counted framing from RFC 6587
+            Connection::Tcp(stream) => {
+                stream.write_all(format!("{} {}", message.len(), message).as_bytes())
+            }
+        }
+    }
+}
+
+// cuts a message down to at most max bytes, on a character boundary
+fn truncate(message: &str, max: usize) -> &str {
+    if message.len() <= max {
+        return message;
+    }
+    let mut end = max;
+    while !message.is_char_boundary(end) {
+        end -= 1;
+    }
+    &message[..end]
+}
+
+// shortens a structured data parameter name that's too long, keeping as much
+// of it as fits along with a hash of the whole name so that it stays unique
+fn parameter_name(name: &str) -> String {
+    if name.len() <= MAX_NAME_LENGTH {
+        return name.to_string();
+    }
+    let hash = format!("{:x}", Sha256::digest(name.as_bytes()));
+    format!("{}~{}", truncate(name, MAX_NAME_LENGTH - 9), &hash[..8])
+}
+
+// escapes a structured data parameter value
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(']', "\\]")
+}
+
+/// Writes events as RFC 5424 syslog messages, with denials logged at a
+/// higher severity than everything else.
+pub struct SyslogSink {
+    address: SyslogAddress,
+    format: SyslogFormat,
+    facility: u8,
+    timeout: Duration,
+    max_datagram_size: usize,
+    connection: Mutex<Option<Connection>>,
+}
+
+impl SyslogSink {
+    pub fn new(address: SyslogAddress, format: SyslogFormat, timeout: Duration) -> Self {
+        Self {
+            address,
+            format,
+            facility: 4, // auth
+            timeout,
+            max_datagram_size: DEFAULT_MAX_DATAGRAM_SIZE,
+            connection: Mutex::new(None),
+        }
+    }
+
+    pub fn facility(mut self, facility: u8) -> Self {
+        self.facility = facility;
+        self
+    }
+
+    /// Truncates messages sent to a unix or udp address to this many bytes.
+    pub fn max_datagram_size(mut self, max_datagram_size: usize) -> Self {
+        self.max_datagram_size = max_datagram_size;
+        self
+    }
+
+    pub fn message(&self, event: &Event) -> String {
+        let field = |path: &str| {
+            ajson::get(&event.document, path)
+                .map(|v| v.to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let action = field("event.action").unwrap_or_else(|| event.module.clone());
+        let severity = if is_denied(&action) {
+            SEVERITY_WARNING
+        } else {
+            SEVERITY_INFORMATIONAL
+        };
+        let header = format!(
+            "<{}>1 {} {} {} {} {}",
+            self.facility * 8 + severity,
+            Utc.timestamp(event.timestamp, 0)
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+            field("host.hostname").unwrap_or_else(|| String::from("-")),
+            APP_NAME,
+            std::process::id(),
+            event.module
+        );
+        match self.format {
+            SyslogFormat::Json => format!("{} - {}", header, event.document),
+            SyslogFormat::Structured => {
+                let parameters: String = fields(&event.document)
+                    .into_iter()
+                    .map(|(name, value)| {
+                        format!(
+                            " {}=\"{}\"",
+                            parameter_name(&name),
+                            escape(&value.to_string())
+                        )
+                    })
+                    .collect();
+                format!("{} [{}{}] {}", header, SD_ID, parameters, action)
+            }
+        }
+    }
+}
+
+impl Sink for SyslogSink {
+    fn name(&self) -> &str {
+        "syslog"
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut statuses = Vec::with_capacity(events.len());
+        for event in events {
+            if connection.is_none() {
+                match Connection::open(&self.address, self.timeout) {
+                    Ok(opened) => *connection = Some(opened),
+                    Err(e) => {
+                        statuses.resize(events.len(), BulkStatus::Retry(e.to_string()));
+                        break;
+                    }
+                }
+            }
+            let sent = connection.as_mut().map_or(Ok(()), |c| {
+                c.send(&self.message(event), self.max_datagram_size)
+            });
+            match sent {
+                Ok(_) => statuses.push(BulkStatus::Indexed),
+                // the socket won't take a datagram this large no matter how
+                // many times it's sent
+                Err(e) if e.raw_os_error() == Some(MESSAGE_TOO_LONG) => {
+                    statuses.push(BulkStatus::Rejected {
+                        error: format!(
+                            "message is too large for the socket, lower --syslog-max-datagram-size: {}",
+                            e
+                        ),
+                        attempts: 1,
+                    })
+                }
+                Err(e) => {
+                    // reconnect on the next attempt
+                    *connection = None;
+                    statuses.resize(events.len(), BulkStatus::Retry(e.to_string()));
+                    break;
+                }
+            }
+        }
+        statuses
+    }
+}
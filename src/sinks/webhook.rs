@@ -0,0 +1,81 @@
+use backoff::{retry, Error as Backoff};
+use std::cell::Cell;
+use std::time::Duration;
+use ureq::{Agent, AgentBuilder, Error};
+
+use super::{ndjson, Event, Sink};
+use crate::client::{backoff, Auth, BulkStatus};
+use crate::tls::TlsOptions;
+
+/// POSTs each batch to a URL as newline delimited JSON.
+pub struct WebhookSink {
+    url: String,
+    agent: Agent,
+    authorization: Option<String>,
+}
+
+impl WebhookSink {
+    pub fn new(url: &str, tls: &TlsOptions, timeout: Duration) -> Result<Self, String> {
+        let mut agent_builder = AgentBuilder::new().timeout(timeout);
+        if let Some(tls_config) = tls.client_config()? {
+            agent_builder = agent_builder.tls_config(tls_config)
+        }
+        Ok(Self {
+            url: url.to_string(),
+            agent: agent_builder.build(),
+            authorization: None,
+        })
+    }
+
+    pub fn auth(mut self, auth: Option<Auth>) -> Self {
+        self.authorization = auth.map(|a| a.header());
+        self
+    }
+
+    // throttling, server errors, and unreachable hosts are retried, anything
+    // else means the batch won't ever be accepted
+    fn post(&self, body: &str) -> Result<(), Backoff<String>> {
+        let mut request = self
+            .agent
+            .post(&self.url)
+            .set("Content-Type", "application/x-ndjson");
+        if let Some(header) = &self.authorization {
+            request = request.set("Authorization", header);
+        }
+        match request.send_string(body) {
+            Ok(_) => Ok(()),
+            Err(Error::Status(status, _)) if status == 429 || status >= 500 => {
+                Err(Backoff::Transient(format!("status {}", status)))
+            }
+            Err(Error::Status(status, _)) => Err(Backoff::Permanent(format!("status {}", status))),
+            Err(e) => Err(Backoff::Transient(e.to_string())),
+        }
+    }
+}
+
+impl Sink for WebhookSink {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    fn destination(&self, _event: &Event) -> String {
+        self.url.clone()
+    }
+
+    fn send(&self, events: &[&Event]) -> Vec<BulkStatus> {
+        let body = ndjson(events);
+        let attempts = Cell::new(0);
+        let status = match retry(backoff(), || {
+            attempts.set(attempts.get() + 1);
+            self.post(&body)
+        }) {
+            Ok(_) => BulkStatus::Indexed,
+            Err(Backoff::Permanent(error)) => BulkStatus::Rejected {
+                error,
+                attempts: attempts.get(),
+            },
+            Err(Backoff::Transient(error)) => BulkStatus::Retry(error),
+        };
+        vec![status; events.len()]
+    }
+}
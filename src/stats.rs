@@ -0,0 +1,59 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters describing what the agent has done with the events it saw.
+#[derive(Default)]
+pub struct Stats {
+    pub dropped_oldest: AtomicU64,
+    pub dropped_newest: AtomicU64,
+    pub dropped_allowed: AtomicU64,
+    pub dropped_denials: AtomicU64,
+    pub evicted_allowed: AtomicU64,
+    pub bytes_uncompressed: AtomicU64,
+    pub bytes_sent: AtomicU64,
+}
+
+impl Stats {
+    pub fn increment(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_bytes(&self, uncompressed: usize, sent: usize) {
+        self.bytes_uncompressed
+            .fetch_add(uncompressed as u64, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(sent as u64, Ordering::Relaxed);
+    }
+
+    /// How many bytes are sent for every byte of uncompressed payload.
+    pub fn compression_ratio(&self) -> f64 {
+        let uncompressed = self.bytes_uncompressed.load(Ordering::Relaxed);
+        if uncompressed == 0 {
+            return 1.0;
+        }
+        self.bytes_sent.load(Ordering::Relaxed) as f64 / uncompressed as f64
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.dropped_oldest.load(Ordering::Relaxed)
+            + self.dropped_newest.load(Ordering::Relaxed)
+            + self.dropped_allowed.load(Ordering::Relaxed)
+            + self.dropped_denials.load(Ordering::Relaxed)
+            + self.evicted_allowed.load(Ordering::Relaxed)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "dropped.oldest={} dropped.newest={} dropped.allowed={} dropped.denials={} evicted.allowed={} bytes.uncompressed={} bytes.sent={}",
+            self.dropped_oldest.load(Ordering::Relaxed),
+            self.dropped_newest.load(Ordering::Relaxed),
+            self.dropped_allowed.load(Ordering::Relaxed),
+            self.dropped_denials.load(Ordering::Relaxed),
+            self.evicted_allowed.load(Ordering::Relaxed),
+            self.bytes_uncompressed.load(Ordering::Relaxed),
+            self.bytes_sent.load(Ordering::Relaxed),
+        )
+    }
+}
@@ -28,4 +28,1993 @@ mod compiler {
                 .unwrap();
         assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
     }
+
+    #[test]
+    fn test_ok_mount() {
+        let rule =
+            compile(r#"REJECT sb_mount WHEN mount.fstype == "proc" and mount.target == "/mnt""#)
+                .unwrap();
+        assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
+    }
+
+    #[test]
+    fn test_ok_kill() {
+        let rule = compile(
+            r#"REJECT task_kill WHEN signal.number == 9 and target.process.name == "probe""#,
+        )
+        .unwrap();
+        assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
+    }
+
+    #[test]
+    fn test_ok_anonymous_mapping() {
+        let rule =
+            compile(r#"REJECT mmap_file WHEN memory.anonymous == 1 and process.name == "node""#)
+                .unwrap();
+        assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
+    }
+
+    #[test]
+    fn test_ok_accept() {
+        let rule =
+            compile(r#"FILTER sock_graft WHEN destination.port == 22 and process.name == "sshd""#)
+                .unwrap();
+        assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
+    }
+
+    #[test]
+    fn test_ok_fork() {
+        let rule =
+            compile(r#"FILTER sched_process_fork WHEN process.parent.name == "cron""#).unwrap();
+        assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
+    }
+
+    #[test]
+    fn test_ok_container() {
+        let rule = compile(r#"REJECT bprm_check_security WHEN container.id == "3f4a8c1b9d2e" and process.name == "nc""#)
+            .unwrap();
+        assert!(rule.encode(&BpfQueryWriterFactory::empty()).is_ok());
+    }
+}
+
+mod hasher {
+    use crate::hasher::{executable_hash, process_path, prune};
+    use std::fs;
+    use std::time::Duration;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    #[test]
+    fn test_executable_hash() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let cache = db.open_tree("executable_hashes").unwrap();
+        let path = std::env::temp_dir().join(format!("probe-hash-{}", std::process::id()));
+        fs::write(&path, b"hello").unwrap();
+
+        let expected = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        // opened the way the process that ran it sees it
+        let path = process_path(u64::from(std::process::id()), path.to_str().unwrap());
+        assert_eq!(
+            executable_hash(&cache, &path, 1024, DAY).as_deref(),
+            Some(expected)
+        );
+        assert_eq!(cache.len(), 1);
+        // a second lookup is served from the cache
+        assert_eq!(
+            executable_hash(&cache, &path, 1024, DAY).as_deref(),
+            Some(expected)
+        );
+        assert_eq!(cache.len(), 1);
+        // files over the size limit are skipped
+        assert!(executable_hash(&cache, &path, 4, DAY).is_none());
+
+        fs::remove_file(&path).unwrap();
+        assert!(executable_hash(&cache, "", 1024, DAY).is_none());
+    }
+
+    #[test]
+    fn test_prune() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let cache = db.open_tree("executable_hashes").unwrap();
+        let path = std::env::temp_dir().join(format!("probe-prune-{}", std::process::id()));
+        fs::write(&path, b"hello").unwrap();
+
+        let path = path.to_str().unwrap();
+        assert!(executable_hash(&cache, path, 1024, DAY).is_some());
+        assert_eq!(prune(&cache, DAY), 0);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(prune(&cache, Duration::from_secs(0)), 1);
+        assert!(cache.is_empty());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_process_path() {
+        assert_eq!(process_path(42, "/usr/bin/ls"), "/proc/42/root/usr/bin/ls");
+        assert_eq!(process_path(42, "./run.sh"), "/proc/42/cwd/./run.sh");
+    }
+}
+
+mod transformer {
+    use probe_sys::{SerializationError, Transformer};
+
+    #[test]
+    fn test_error_missing_event() {
+        let transformer = Transformer::new();
+        assert!(matches!(
+            transformer.transform(&[]),
+            Err(SerializationError::MissingEvent(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_corrupt_record() {
+        let transformer = Transformer::new();
+        assert!(transformer.transform(&[0xff, 0xff, 0xff, 0xff]).is_err());
+    }
+}
+
+mod process_start {
+    use probe_sys::process_start;
+
+    #[test]
+    fn test_process_start() {
+        // a command name with spaces and parentheses doesn't shift the fields
+        let stat =
+            "4242 (a) b (c)) S 1 4242 4242 0 -1 4194560 164 0 0 0 0 0 0 0 20 0 1 0 12345 1 2";
+        // 123.45s after boot, moved to the epoch by the adjustment
+        assert_eq!(
+            process_start(stat, 1_000_000_000_000_000_000),
+            Some(1_000_000_123)
+        );
+        assert_eq!(process_start(stat, 550_000_000), Some(124));
+        assert_eq!(process_start("4242 (a) S 1", 0), None);
+        assert_eq!(process_start("", 0), None);
+    }
+}
+
+mod enrichers {
+    use probe_sys::{
+        BprmCheckSecurityEvent, EnricherChain, FieldAccess, FieldValue, SerializationError,
+        SockGraftEvent, TaskKillEvent,
+    };
+
+    #[test]
+    fn test_default_enrichers() {
+        let mut event = BprmCheckSecurityEvent::default();
+        let args = vec!["ls".to_string(), "-la".to_string()];
+        event
+            .set_field("process.args", FieldValue::Strings(args))
+            .unwrap();
+
+        EnricherChain::default()
+            .apply("bprm_check_security", &mut event)
+            .unwrap();
+        assert_eq!(
+            event.get_field("event.category"),
+            Some(FieldValue::String("process".to_string()))
+        );
+        assert_eq!(
+            event.get_field("event.provider"),
+            Some(FieldValue::String("bprm-check-security".to_string()))
+        );
+        assert_eq!(
+            event.get_field("process.command_line"),
+            Some(FieldValue::String("ls -la".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_kill_enricher() {
+        let mut event = TaskKillEvent::default();
+        event
+            .set_field("signal.number", FieldValue::Number(9))
+            .unwrap();
+        let args = vec!["sleep".to_string(), "60".to_string()];
+        event
+            .set_field("target.process.args", FieldValue::Strings(args))
+            .unwrap();
+
+        EnricherChain::default()
+            .apply("task_kill", &mut event)
+            .unwrap();
+        assert_eq!(
+            event.get_field("signal.name"),
+            Some(FieldValue::String("SIGKILL".to_string()))
+        );
+        assert_eq!(
+            event.get_field("target.process.command_line"),
+            Some(FieldValue::String("sleep 60".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_network_enricher() {
+        let mut event = SockGraftEvent::default();
+        event
+            .set_field("source.ip", FieldValue::String("::1".to_string()))
+            .unwrap();
+        event
+            .set_field("network.iana_number", FieldValue::String("6".to_string()))
+            .unwrap();
+
+        EnricherChain::default()
+            .apply("sock_graft", &mut event)
+            .unwrap();
+        assert_eq!(
+            event.get_field("network.direction"),
+            Some(FieldValue::String("ingress".to_string()))
+        );
+        assert_eq!(
+            event.get_field("network.transport"),
+            Some(FieldValue::String("tcp".to_string()))
+        );
+        assert_eq!(
+            event.get_field("network.type"),
+            Some(FieldValue::String("ipv6".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_common_enrichers() {
+        let mut event = BprmCheckSecurityEvent::default();
+        event
+            .set_field("user.id", FieldValue::String("0".to_string()))
+            .unwrap();
+
+        let names = vec!["user".to_string(), "host".to_string()];
+        EnricherChain::configure(&names, EnricherChain::builtin())
+            .unwrap()
+            .apply("bprm_check_security", &mut event)
+            .unwrap();
+        assert_eq!(
+            event.get_field("user.name"),
+            Some(FieldValue::String("root".to_string()))
+        );
+        assert_eq!(
+            event.get_field("host.os.type"),
+            Some(FieldValue::String("linux".to_string()))
+        );
+
+        // and they can be left out like any other enricher
+        let mut event = BprmCheckSecurityEvent::default();
+        event
+            .set_field("user.id", FieldValue::String("0".to_string()))
+            .unwrap();
+        let names = vec!["ecs".to_string()];
+        EnricherChain::configure(&names, EnricherChain::builtin())
+            .unwrap()
+            .apply("bprm_check_security", &mut event)
+            .unwrap();
+        assert_eq!(
+            event.get_field("user.name"),
+            Some(FieldValue::String(String::new()))
+        );
+        assert_eq!(event.get_field("host.os.type"), None);
+    }
+
+    #[test]
+    fn test_configure_order() {
+        let names = vec!["command_line".to_string(), "ecs".to_string()];
+        let chain = EnricherChain::configure(&names, EnricherChain::builtin()).unwrap();
+        assert_eq!(chain.names(), vec!["command_line", "ecs"]);
+    }
+
+    #[test]
+    fn test_error_unknown_enricher() {
+        let names = vec!["ecs".to_string(), "missing".to_string()];
+        assert!(EnricherChain::configure(&names, EnricherChain::builtin()).is_err());
+    }
+
+    #[test]
+    fn test_error_field_type() {
+        let mut event = BprmCheckSecurityEvent::default();
+        assert!(matches!(
+            event.set_field("process.pid", FieldValue::String("1".to_string())),
+            Err(SerializationError::FieldType(..))
+        ));
+        assert!(matches!(
+            event.set_field("process.missing", FieldValue::Number(1)),
+            Err(SerializationError::UnknownField(_))
+        ));
+    }
+}
+
+mod accounts {
+    use probe_sys::{AccountCache, AccountSource};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct FakeDatabase {
+        users: HashMap<u32, String>,
+        groups: HashMap<u32, String>,
+        changed: bool,
+        lookups: usize,
+    }
+
+    #[derive(Clone, Default)]
+    struct FakeAccounts(Arc<Mutex<FakeDatabase>>);
+
+    impl FakeAccounts {
+        fn add_user(&self, uid: u32, name: &str) {
+            let mut database = self.0.lock().unwrap();
+            database.users.insert(uid, name.to_string());
+            database.changed = true;
+        }
+
+        fn lookups(&self) -> usize {
+            self.0.lock().unwrap().lookups
+        }
+    }
+
+    impl AccountSource for FakeAccounts {
+        fn user_name(&self, uid: u32) -> Option<String> {
+            let mut database = self.0.lock().unwrap();
+            database.lookups += 1;
+            database.users.get(&uid).cloned()
+        }
+
+        fn group_name(&self, gid: u32) -> Option<String> {
+            let mut database = self.0.lock().unwrap();
+            database.lookups += 1;
+            database.groups.get(&gid).cloned()
+        }
+
+        fn changed(&mut self) -> bool {
+            let mut database = self.0.lock().unwrap();
+            std::mem::replace(&mut database.changed, false)
+        }
+    }
+
+    fn cache(accounts: &FakeAccounts) -> AccountCache<FakeAccounts> {
+        AccountCache::new(accounts.clone()).check_interval(Duration::from_secs(0))
+    }
+
+    #[test]
+    fn test_ok_cached() {
+        let accounts = FakeAccounts::default();
+        accounts.add_user(1000, "alice");
+        let mut cache = cache(&accounts);
+        assert_eq!(cache.user_name(1000), Some("alice".to_string()));
+        assert_eq!(cache.user_name(1000), Some("alice".to_string()));
+        assert_eq!(accounts.lookups(), 1);
+    }
+
+    #[test]
+    fn test_ok_invalidated_on_change() {
+        let accounts = FakeAccounts::default();
+        accounts.add_user(1000, "alice");
+        let mut cache = cache(&accounts);
+        assert_eq!(cache.user_name(1000), Some("alice".to_string()));
+
+        accounts.add_user(1000, "bob");
+        assert_eq!(cache.user_name(1000), Some("bob".to_string()));
+    }
+
+    #[test]
+    fn test_ok_negative_cache() {
+        let accounts = FakeAccounts::default();
+        let mut cache = cache(&accounts).negative_ttl(Duration::from_secs(3600));
+        assert_eq!(cache.group_name(1000), None);
+        assert_eq!(cache.group_name(1000), None);
+        assert_eq!(accounts.lookups(), 1);
+
+        // a new user is picked up once the databases change
+        accounts.add_user(1001, "carol");
+        assert_eq!(cache.user_name(1001), Some("carol".to_string()));
+    }
+
+    #[test]
+    fn test_ok_negative_cache_expired() {
+        let accounts = FakeAccounts::default();
+        let mut cache = cache(&accounts).negative_ttl(Duration::from_secs(0));
+        assert_eq!(cache.user_name(1000), None);
+        // bypass change detection so that only the expired entry is retried
+        accounts
+            .0
+            .lock()
+            .unwrap()
+            .users
+            .insert(1000, "dave".to_string());
+        assert_eq!(cache.user_name(1000), Some("dave".to_string()));
+        assert_eq!(accounts.lookups(), 2);
+    }
+}
+
+mod containers {
+    use probe_sys::{Container, ContainerCache};
+    use std::fs;
+
+    const ID: &str = "3f4b6a0c9d8e7f1a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192";
+
+    #[test]
+    fn test_kubepods_cgroupfs() {
+        let root = std::env::temp_dir().join(format!("probe-containers-{}", std::process::id()));
+        let state = root
+            .join("run/containerd/io.containerd.runtime.v2.task/k8s.io")
+            .join(ID);
+        fs::create_dir_all(&state).unwrap();
+        fs::write(
+            state.join("config.json"),
+            r#"{"annotations":{"io.kubernetes.cri.container-name":"nginx","io.kubernetes.cri.image-name":"docker.io/library/nginx:1.19"}}"#,
+        )
+        .unwrap();
+
+        // no runtime in the path, so it's found from the runtime's state
+        let path = format!(
+            "/kubepods/burstable/pod0c5c2d4b-5e1a-4a8f-9f3e-2b7d6c1a9e04/{}",
+            ID
+        );
+        let mut cache = ContainerCache::with_root(&root);
+        assert_eq!(
+            cache.get(ID, &path),
+            Container {
+                runtime: Some("containerd".to_string()),
+                name: Some("nginx".to_string()),
+                image_name: Some("docker.io/library/nginx".to_string()),
+                image_tag: Some("1.19".to_string()),
+            }
+        );
+        // a container no runtime knows about
+        let unknown = ID.replace('3', "4");
+        assert_eq!(cache.get(&unknown, &path), Container::default());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
+
+mod queue {
+    use crate::queue::{
+        key_sequence, next_sequence, open, pending, DropPolicy, Queue, QueueLimits,
+    };
+    use crate::stats::Stats;
+    use std::fs;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    fn data_dir(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("probe-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path.to_str().unwrap().to_string()
+    }
+
+    fn record_key(sequence: u64) -> Vec<u8> {
+        [&sequence.to_be_bytes()[..], &[0; 16][..]].concat()
+    }
+
+    #[test]
+    fn test_sequence_survives_restart() {
+        let path = data_dir("sequence");
+        {
+            let db = open(Some(&path)).unwrap();
+            assert_eq!(next_sequence(&db).unwrap(), 0);
+            assert_eq!(next_sequence(&db).unwrap(), 1);
+            db.flush().unwrap();
+        }
+        let db = open(Some(&path)).unwrap();
+        assert_eq!(next_sequence(&db).unwrap(), 2);
+        drop(db);
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_pending_records_replayed() {
+        let path = data_dir("pending");
+        {
+            let db = open(Some(&path)).unwrap();
+            // written without going through next_sequence
+            db.insert(record_key(41), "a").unwrap();
+            db.insert(record_key(42), "b").unwrap();
+            db.flush().unwrap();
+        }
+        let db = open(Some(&path)).unwrap();
+        let (last, _) = db.last().unwrap().unwrap();
+        let replayed: Vec<_> = pending(&db, &last)
+            .map(|(key, _)| key_sequence(&key).unwrap())
+            .collect();
+        assert_eq!(replayed, vec![41, 42]);
+        // new records always sort after the pending ones
+        assert_eq!(next_sequence(&db).unwrap(), 43);
+        drop(db);
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    fn bounded(policy: DropPolicy) -> (Queue, Arc<Stats>) {
+        let db = open(None).unwrap();
+        let stats = Arc::new(Stats::default());
+        let limits = QueueLimits {
+            max_records: Some(2),
+            max_bytes: None,
+            policy,
+        };
+        (Queue::new(db, limits, stats.clone()).unwrap(), stats)
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let (queue, stats) = bounded(DropPolicy::Oldest);
+        for sequence in 0..3 {
+            assert!(queue.push(record_key(sequence), vec![0; 4], false).unwrap());
+        }
+        assert_eq!(queue.records(), 2);
+        assert_eq!(queue.bytes(), 8);
+        assert_eq!(stats.dropped_oldest.load(Ordering::Relaxed), 1);
+
+        queue.remove(&record_key(1)).unwrap();
+        queue.remove(&record_key(2)).unwrap();
+        // already dropped to make room
+        queue.remove(&record_key(0)).unwrap();
+        assert_eq!(queue.records(), 0);
+        assert_eq!(queue.bytes(), 0);
+    }
+
+    #[test]
+    fn test_drop_newest() {
+        let (queue, stats) = bounded(DropPolicy::Newest);
+        assert!(queue.push(record_key(0), vec![0; 4], false).unwrap());
+        assert!(queue.push(record_key(1), vec![0; 4], true).unwrap());
+        assert!(!queue.push(record_key(2), vec![0; 4], true).unwrap());
+        assert_eq!(queue.records(), 2);
+        assert_eq!(stats.dropped_newest.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_drop_preserve_denials() {
+        let (queue, stats) = bounded(DropPolicy::PreserveDenials);
+        assert!(queue.push(record_key(0), vec![0; 4], false).unwrap());
+        assert!(queue.push(record_key(1), vec![0; 4], true).unwrap());
+        assert!(!queue.push(record_key(2), vec![0; 4], false).unwrap());
+        // the oldest allowed record makes room for the denial
+        assert!(queue.push(record_key(3), vec![0; 4], true).unwrap());
+        assert_eq!(queue.records(), 2);
+        assert_eq!(stats.dropped_allowed.load(Ordering::Relaxed), 1);
+        assert_eq!(stats.evicted_allowed.load(Ordering::Relaxed), 1);
+
+        // with nothing but denials queued, newer ones are turned away
+        assert!(!queue.push(record_key(4), vec![0; 4], true).unwrap());
+        assert_eq!(queue.records(), 2);
+        assert_eq!(stats.dropped_denials.load(Ordering::Relaxed), 1);
+        assert_eq!(stats.dropped(), 3);
+
+        // shipped records are forgotten, making room again
+        queue.remove(&record_key(1)).unwrap();
+        assert!(queue.push(record_key(5), vec![0; 4], false).unwrap());
+        // the evicted record is already gone
+        queue.remove(&record_key(0)).unwrap();
+        assert_eq!(queue.records(), 2);
+    }
+
+    #[test]
+    fn test_concurrent_pushes_bounded() {
+        let (queue, _) = bounded(DropPolicy::Newest);
+        let queue = Arc::new(queue);
+        let threads: Vec<_> = (0..8)
+            .map(|sequence| {
+                let queue = queue.clone();
+                std::thread::spawn(move || queue.push(record_key(sequence), vec![0; 4], false))
+            })
+            .collect();
+        let queued = threads
+            .into_iter()
+            .filter(|t| t.join().unwrap().unwrap())
+            .count();
+        assert_eq!(queued, 2);
+        assert_eq!(queue.records(), 2);
+    }
+
+    #[test]
+    fn test_error_drop_policy() {
+        assert_eq!("newest".parse(), Ok(DropPolicy::Newest));
+        assert!("random".parse::<DropPolicy>().is_err());
+    }
+}
+
+// shared by the tests below, a minimal HTTP server standing in for the
+// services we ship events to along with shorthands for clients and events
+mod helpers {
+    use crate::client::{Auth, Client};
+    use crate::sinks::Event;
+    use crate::tls::TlsOptions;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread::JoinHandle;
+    use std::time::Duration;
+
+    // a client for a stand-in
+    pub fn client_for(address: String, auth: Option<Auth>) -> Client {
+        Client::new(
+            vec![address],
+            auth,
+            &TlsOptions::default(),
+            Duration::from_secs(5),
+        )
+        .unwrap()
+    }
+
+    pub fn event(module: &str, document: &str) -> Event {
+        Event {
+            module: module.to_string(),
+            timestamp: 1613149814,
+            document: document.to_string(),
+        }
+    }
+
+    // an empty directory of its own for a test
+    pub fn directory(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("probe-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    pub struct Request {
+        pub request_line: String,
+        pub headers: Vec<(String, String)>,
+        pub body: Vec<u8>,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+
+        pub fn text(&self) -> String {
+            String::from_utf8_lossy(&self.body).to_string()
+        }
+    }
+
+    // answers each request with the next canned status and body, returning
+    // the requests once all of them have been answered
+    pub fn serve<B: Into<String>>(responses: Vec<(u16, B)>) -> (String, JoinHandle<Vec<Request>>) {
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.into()))
+            .collect();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, response) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(i) = line.find(':') {
+                        headers.push((line[..i].to_string(), line[i + 1..].trim().to_string()));
+                    }
+                }
+                let mut request = Request {
+                    request_line: request_line.trim_end().to_string(),
+                    headers,
+                    body: vec![],
+                };
+                let content_length = request
+                    .header("content-length")
+                    .map_or(0, |l| l.parse().unwrap());
+                request.body = vec![0; content_length];
+                reader.read_exact(&mut request.body).unwrap();
+                requests.push(request);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} Stand-In\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (address, handle)
+    }
+}
+
+mod client {
+    use super::helpers::{client_for, serve};
+    use crate::client::{Auth, BulkStatus};
+    use crate::tls::{FingerprintVerifier, TlsOptions};
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    fn batch(documents: &[&str]) -> Vec<(String, String)> {
+        documents
+            .iter()
+            .map(|d| ("index".to_string(), d.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_bulk_item_statuses() {
+        let (address, server) = serve(vec![
+            (
+                200,
+                r#"{"errors":true,"items":[{"create":{"status":201}},{"create":{"status":400,"error":{"type":"mapper_parsing_exception","reason":"failed to parse"}}},{"create":{"status":429,"error":{"type":"es_rejected_execution_exception","reason":"queue full"}}}]}"#,
+            ),
+            (
+                200,
+                r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+            ),
+        ]);
+        let client = client_for(address, None);
+        let statuses = client.send_batch(&batch(&["{}", "{\"a\":1}", "{\"b\":2}"]));
+        assert_eq!(
+            statuses,
+            vec![
+                BulkStatus::Indexed,
+                BulkStatus::Rejected {
+                    error: "mapper_parsing_exception: failed to parse".to_string(),
+                    attempts: 1
+                },
+                BulkStatus::Indexed,
+            ]
+        );
+
+        // only the throttled document is sent again
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].text().contains("{\"b\":2}"));
+        assert!(!requests[1].text().contains("{\"a\":1}"));
+    }
+
+    #[test]
+    fn test_bulk_request_statuses() {
+        let (address, server) = serve(vec![
+            (503, "{}"),
+            (
+                200,
+                r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+            ),
+            (
+                401,
+                r#"{"error":{"type":"security_exception","reason":"missing authentication credentials"}}"#,
+            ),
+            (
+                200,
+                r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+            ),
+            (413, ""),
+        ]);
+        let client = client_for(address, None);
+        // unavailable at first, then indexed once it's back
+        assert_eq!(
+            client.send_batch(&batch(&["{}"])),
+            vec![BulkStatus::Indexed]
+        );
+        // nothing is dead lettered over credentials, they're sent again
+        // until they're accepted
+        assert_eq!(
+            client.send_batch(&batch(&["{}"])),
+            vec![BulkStatus::Indexed]
+        );
+        // a document that's too large on its own is rejected
+        assert!(matches!(
+            client.send_batch(&batch(&["{}"]))[0],
+            BulkStatus::Rejected { attempts: 1, .. }
+        ));
+        assert_eq!(server.join().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_bulk_split_when_too_large() {
+        let (address, server) = serve(vec![
+            (413, ""),
+            (
+                200,
+                r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+            ),
+            (413, ""),
+            (
+                200,
+                r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+            ),
+            (
+                200,
+                r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+            ),
+        ]);
+        let client = client_for(address, None);
+        let statuses = client.send_batch(&batch(&["{}", "{\"a\":1}", "{\"b\":2}"]));
+        assert_eq!(statuses, vec![BulkStatus::Indexed; 3]);
+
+        // halved until every request fits
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 5);
+        assert!(requests[1].text().contains("{}"));
+        assert!(!requests[1].text().contains("{\"a\":1}"));
+        assert!(requests[3].text().contains("{\"a\":1}"));
+        assert!(requests[4].text().contains("{\"b\":2}"));
+    }
+
+    #[test]
+    fn test_bulk_compression() {
+        let (address, server) = serve(vec![(
+            200,
+            r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+        )]);
+        let client = client_for(address, None).compression(Some(6));
+        let statuses = client.send_batch(&batch(&["{\"a\":1}"]));
+        assert_eq!(statuses, vec![BulkStatus::Indexed]);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].header("content-encoding"), Some("gzip"));
+        let mut body = String::new();
+        GzDecoder::new(&requests[0].body[..])
+            .read_to_string(&mut body)
+            .unwrap();
+        assert!(body.contains("{\"a\":1}"));
+    }
+
+    #[test]
+    fn test_auth_headers() {
+        assert_eq!(
+            Auth::Basic("elastic:changeme".to_string()).header(),
+            "Basic ZWxhc3RpYzpjaGFuZ2VtZQ=="
+        );
+        assert_eq!(
+            Auth::ApiKey("id:key".to_string()).header(),
+            "ApiKey aWQ6a2V5"
+        );
+        assert_eq!(
+            Auth::ApiKey("aWQ6a2V5".to_string()).header(),
+            "ApiKey aWQ6a2V5"
+        );
+        assert_eq!(Auth::Bearer("token".to_string()).header(), "Bearer token");
+
+        let (address, server) = serve(vec![(
+            200,
+            r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+        )]);
+        let auth = Some(Auth::Bearer("token".to_string()));
+        let client = client_for(address, auth);
+        client.send_batch(&batch(&["{}"]));
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].header("authorization"), Some("Bearer token"));
+    }
+
+    #[test]
+    fn test_error_tls_options() {
+        assert!(FingerprintVerifier::new(&"ab:".repeat(31)).is_err());
+        assert!(FingerprintVerifier::new(&"AB".repeat(32)).is_ok());
+        // multi-byte characters can't split a pair of hex digits
+        assert!(FingerprintVerifier::new(&format!("é{}", "a".repeat(62))).is_err());
+        assert!(FingerprintVerifier::new(&"+f".repeat(32)).is_err());
+
+        let missing_key = TlsOptions {
+            client_cert: Some("/nonexistent/cert.pem".to_string()),
+            ..Default::default()
+        };
+        assert!(missing_key.client_config().is_err());
+        let missing_ca = TlsOptions {
+            ca: Some("/nonexistent/ca.pem".to_string()),
+            ..Default::default()
+        };
+        assert!(missing_ca.client_config().is_err());
+        assert!(TlsOptions::default().client_config().unwrap().is_none());
+    }
+}
+
+mod dead_letters {
+    use crate::dead_letters::{
+        find, list, redrive, redriven_sink, sequence, settled, store, DeadLetter,
+    };
+    use crate::queue::{
+        is_denial, key_sequence, next_sequence, open, DropPolicy, Queue, QueueLimits,
+    };
+    use crate::stats::Stats;
+    use std::sync::Arc;
+
+    fn queue(limits: QueueLimits) -> (sled::Db, Queue) {
+        let db = open(None).unwrap();
+        let queue = Queue::new(db.clone(), limits, Arc::new(Stats::default())).unwrap();
+        (db, queue)
+    }
+
+    fn record_key(db: &sled::Db) -> Vec<u8> {
+        [&next_sequence(db).unwrap().to_be_bytes()[..], &[7; 16][..]].concat()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let letter = DeadLetter {
+            sink: "elasticsearch".to_string(),
+            index: "bprm_check_security".to_string(),
+            error: "mapper_parsing_exception: failed to parse".to_string(),
+            attempts: 3,
+            denied: true,
+            payload: b"{\"a\":1}".to_vec(),
+        };
+        assert_eq!(DeadLetter::from_bytes(&letter.to_bytes()), Some(letter));
+        assert_eq!(DeadLetter::from_bytes(&[0, 0, 0, 1, 0, 0, 0, 9]), None);
+    }
+
+    #[test]
+    fn test_redrive() {
+        let (db, queue) = queue(QueueLimits::default());
+        let letters = db.open_tree("dead_letters").unwrap();
+        let key = record_key(&db);
+        queue.push(key.clone(), b"payload".to_vec(), true).unwrap();
+
+        store(
+            &db,
+            &letters,
+            &key,
+            "webhook".into(),
+            "webhook".into(),
+            "rejected".into(),
+            2,
+        )
+        .unwrap();
+        queue.remove(&key).unwrap();
+        let (found, letter) = find(&letters, 0).unwrap();
+        assert_eq!(found.as_ref(), &key[..]);
+        assert_eq!(letter.sink, "webhook");
+        assert_eq!(letter.payload, b"payload");
+        assert_eq!(letter.attempts, 2);
+        assert!(letter.denied);
+
+        // re-driven records go to the back of the queue with their uuid,
+        // headed only for the sink that rejected them
+        let new_key = redrive(&queue, &db, &letters, &key).unwrap().unwrap();
+        assert_eq!(key_sequence(&new_key), Some(1));
+        assert_eq!(&new_key[8..], &key[8..]);
+        assert_eq!(db.get(&new_key).unwrap().unwrap().as_ref(), b"payload");
+        assert_eq!(queue.records(), 1);
+        assert!(is_denial(&db, &new_key).unwrap());
+        assert_eq!(
+            redriven_sink(&db, &new_key).unwrap(),
+            Some("webhook".to_string())
+        );
+        assert_eq!(list(&letters).count(), 0);
+
+        // and keep counting attempts when they fail again
+        store(
+            &db,
+            &letters,
+            &new_key,
+            "webhook".into(),
+            "webhook".into(),
+            "rejected".into(),
+            1,
+        )
+        .unwrap();
+        queue.remove(&new_key).unwrap();
+        let (found, letter) = list(&letters).next().unwrap();
+        assert_eq!(sequence(&found), 1);
+        assert_eq!(letter.attempts, 3);
+        assert!(redrive(&queue, &db, &letters, &key).unwrap().is_none());
+
+        // once shipped, the record is no longer tied to the sink
+        let redriven = redrive(&queue, &db, &letters, &found).unwrap().unwrap();
+        settled(&db, &redriven).unwrap();
+        assert_eq!(redriven_sink(&db, &redriven).unwrap(), None);
+    }
+
+    #[test]
+    fn test_redrive_queue_full() {
+        let (db, queue) = queue(QueueLimits {
+            max_records: Some(1),
+            max_bytes: None,
+            policy: DropPolicy::PreserveDenials,
+        });
+        let letters = db.open_tree("dead_letters").unwrap();
+        let key = record_key(&db);
+        queue.push(key.clone(), b"payload".to_vec(), false).unwrap();
+        store(
+            &db,
+            &letters,
+            &key,
+            "file".into(),
+            "file".into(),
+            "rejected".into(),
+            1,
+        )
+        .unwrap();
+        queue.remove(&key).unwrap();
+        queue
+            .push(record_key(&db), b"denied".to_vec(), true)
+            .unwrap();
+
+        // allowed records don't make room in a queue full of denials, so the
+        // dead letter stays put
+        assert!(redrive(&queue, &db, &letters, &key).unwrap().is_none());
+        assert_eq!(list(&letters).count(), 1);
+        assert_eq!(queue.records(), 1);
+    }
+}
+
+mod nodes {
+    use super::helpers::serve;
+    use crate::client::{BulkStatus, Client};
+    use crate::nodes::{discovered_urls, NodePool};
+    use crate::tls::TlsOptions;
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    fn pool(recheck: Duration) -> NodePool {
+        NodePool::new(
+            vec!["http://a".to_string(), "http://b".to_string()],
+            recheck,
+        )
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let nodes = pool(Duration::from_secs(30));
+        let picked: Vec<String> = (0..4).map(|_| nodes.pick(|_| true)).collect();
+        assert_eq!(picked, vec!["http://a", "http://b", "http://a", "http://b"]);
+    }
+
+    #[test]
+    fn test_down_nodes_skipped() {
+        let nodes = pool(Duration::from_secs(30));
+        nodes.mark_down("http://a");
+        assert!((0..4).all(|_| nodes.pick(|_| panic!("not due for a check")) == "http://b"));
+
+        // everything is down, keep trying rather than giving up
+        nodes.mark_down("http://b");
+        assert!(!nodes.pick(|_| panic!("not due for a check")).is_empty());
+    }
+
+    #[test]
+    fn test_down_nodes_rechecked() {
+        let nodes = pool(Duration::from_secs(0));
+        nodes.mark_down("http://a");
+        nodes.mark_down("http://b");
+        assert_eq!(nodes.pick(|url| url == "http://b"), "http://b");
+        // b is back in rotation without another check, a is still down
+        assert!((0..4).all(|_| nodes.pick(|_| false) == "http://b"));
+    }
+
+    #[test]
+    fn test_discovered_urls() {
+        let response = r#"{"nodes":{"x":{"http":{"publish_address":"es-1/10.0.0.1:9200"}},"y":{"http":{"publish_address":"10.0.0.2:9200"}},"z":{"http":{"publish_address":"/10.0.0.3:9200"}}}}"#;
+        assert_eq!(
+            discovered_urls(response, "https"),
+            vec![
+                "https://10.0.0.2:9200",
+                "https://10.0.0.3:9200",
+                "https://es-1:9200"
+            ]
+        );
+        assert!(discovered_urls("{}", "http").is_empty());
+    }
+
+    #[test]
+    fn test_discovered_nodes_merged() {
+        let nodes = pool(Duration::from_secs(30));
+        nodes.mark_down("http://a");
+        nodes.discovered(vec!["http://b".to_string(), "http://c".to_string()]);
+        assert_eq!(nodes.urls(), vec!["http://a", "http://b", "http://c"]);
+        // a is still down
+        assert!((0..4).all(|_| nodes.pick(|_| panic!("not due for a check")) != "http://a"));
+
+        // the seeds survive a partial discovery, and an empty one is ignored
+        nodes.discovered(vec!["http://d".to_string()]);
+        assert_eq!(nodes.urls(), vec!["http://a", "http://b", "http://d"]);
+        nodes.discovered(vec![]);
+        assert_eq!(nodes.urls(), vec!["http://a", "http://b", "http://d"]);
+    }
+
+    #[test]
+    fn test_failover() {
+        // nothing is listening on a port we just released
+        let unreachable = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let (address, server) = serve(vec![(
+            200,
+            r#"{"errors":false,"items":[{"create":{"status":201}}]}"#,
+        )]);
+        let client = Client::new(
+            vec![unreachable.clone(), address],
+            None,
+            &TlsOptions::default(),
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        let batch = vec![("index".to_string(), "{}".to_string())];
+        assert_eq!(client.send_batch(&batch), vec![BulkStatus::Indexed]);
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+}
+
+mod indices {
+    use super::helpers::{client_for, serve};
+    use crate::indices::{rfc3339_timestamp, template_version, IndexSettings, Lifecycle};
+
+    fn data_streams() -> IndexSettings {
+        IndexSettings {
+            pattern: String::from("{module}-{namespace}"),
+            namespace: String::from("prod"),
+            data_streams: true,
+            lifecycle: Some(Lifecycle {
+                rollover_age: Some(String::from("1d")),
+                rollover_size: None,
+                retention: Some(String::from("30d")),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_index_names() {
+        assert_eq!(
+            IndexSettings::default().index("task_kill", 1613149814),
+            "task_kill-1"
+        );
+        assert_eq!(
+            data_streams().index("task_kill", 1613149814),
+            "task_kill-prod"
+        );
+        let daily = IndexSettings {
+            pattern: String::from("probe-{module}-{date}"),
+            ..IndexSettings::default()
+        };
+        assert_eq!(
+            daily.index("sb_mount", 1613149814),
+            "probe-sb_mount-2021.02.12"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(IndexSettings::default().validate().is_ok());
+        assert!(data_streams().validate().is_ok());
+
+        let without_module = IndexSettings {
+            pattern: String::from("events"),
+            ..IndexSettings::default()
+        };
+        assert!(without_module.validate().is_err());
+
+        let dated_stream = IndexSettings {
+            pattern: String::from("{module}-{date}"),
+            ..data_streams()
+        };
+        assert!(dated_stream.validate().is_err());
+
+        let rollover_without_streams = IndexSettings {
+            data_streams: false,
+            pattern: String::from("{module}-1"),
+            ..data_streams()
+        };
+        assert!(rollover_without_streams.validate().is_err());
+
+        let bad_namespace = IndexSettings {
+            namespace: String::from("Prod"),
+            ..IndexSettings::default()
+        };
+        assert!(bad_namespace.validate().is_err());
+    }
+
+    #[test]
+    fn test_index_template() {
+        let template = data_streams().index_template("task_kill");
+        assert_eq!(
+            ajson::get(&template, "index_patterns.0")
+                .unwrap()
+                .to_string(),
+            "task_kill-prod"
+        );
+        assert!(ajson::get(&template, "data_stream").is_some());
+        assert_eq!(
+            ajson::get(&template, "composed_of.0").unwrap().to_string(),
+            "task_kill-mappings"
+        );
+
+        let classic = IndexSettings::default().index_template("task_kill");
+        assert_eq!(
+            ajson::get(&classic, "index_patterns.0")
+                .unwrap()
+                .to_string(),
+            "task_kill-*"
+        );
+        assert!(ajson::get(&classic, "data_stream").is_none());
+    }
+
+    #[test]
+    fn test_template_versions() {
+        assert_eq!(template_version("a"), template_version("a"));
+        assert_ne!(template_version("a"), template_version("b"));
+        assert_ne!(
+            data_streams().settings_template(),
+            IndexSettings::default().settings_template()
+        );
+    }
+
+    #[test]
+    fn test_lifecycle_policy() {
+        let policy = data_streams().lifecycle.unwrap().policy();
+        assert_eq!(
+            ajson::get(&policy, "policy.phases.hot.actions.rollover.max_age")
+                .unwrap()
+                .to_string(),
+            "1d"
+        );
+        assert_eq!(
+            ajson::get(&policy, "policy.phases.delete.min_age")
+                .unwrap()
+                .to_string(),
+            "30d"
+        );
+    }
+
+    #[test]
+    fn test_rfc3339_timestamp() {
+        let (timestamp, json) = rfc3339_timestamp(r#"{"@timestamp": "1613149814", "event": {}}"#);
+        assert_eq!(timestamp, Some(1613149814));
+        assert_eq!(
+            json,
+            r#"{"@timestamp": "2021-02-12T17:10:14Z", "event": {}}"#
+        );
+
+        let (timestamp, json) = rfc3339_timestamp(r#"{"@timestamp":1613149814}"#);
+        assert_eq!(timestamp, Some(1613149814));
+        assert_eq!(json, r#"{"@timestamp":"2021-02-12T17:10:14Z"}"#);
+
+        let (timestamp, json) = rfc3339_timestamp(r#"{"event": {}}"#);
+        assert_eq!(timestamp, None);
+        assert_eq!(json, r#"{"event": {}}"#);
+    }
+
+    #[test]
+    fn test_templates_upgraded() {
+        let settings = IndexSettings::default().settings_template();
+        let version = ajson::get(&settings, "version").unwrap().to_u64();
+        let installed = format!(
+            r#"{{"component_templates":[{{"component_template":{{"version":{}}}}}]}}"#,
+            version
+        );
+
+        // the same version is already there
+        let (address, server) = serve(vec![(200, installed)]);
+        let client = client_for(address, None);
+        client.ensure_templates(&[]).unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].request_line.starts_with("GET "));
+
+        // an older version gets replaced
+        let (address, server) = serve(vec![
+            (
+                200,
+                r#"{"component_templates":[{"component_template":{"version":1}}]}"#,
+            ),
+            (200, r#"{"acknowledged":true}"#),
+        ]);
+        let client = client_for(address, None);
+        client.ensure_templates(&[]).unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1]
+            .request_line
+            .starts_with("PUT /_component_template/probe-settings"));
+        assert_eq!(requests[1].text(), settings);
+    }
+}
+
+mod sinks {
+    use super::helpers::{directory, event, serve};
+    use crate::client::{Auth, BulkStatus};
+    use crate::sinks::{parse_routes, FileSink, Rotation, Sink, Sinks, StdoutSink, WebhookSink};
+    use crate::tls::TlsOptions;
+    use flate2::read::GzDecoder;
+    use std::fs;
+    use std::io::Read;
+    use std::time::Duration;
+
+    #[test]
+    fn test_routes() {
+        let routes = parse_routes("file=bprm_check_security,task_kill;webhook=task_kill").unwrap();
+        assert_eq!(routes["file"], vec!["bprm_check_security", "task_kill"]);
+        assert_eq!(routes["webhook"], vec!["task_kill"]);
+        assert!(parse_routes("file").is_err());
+        assert!(parse_routes("file=").is_err());
+
+        let dir = directory("routes");
+        let path = dir.join("events.ndjson");
+        let mut sinks = Sinks::default();
+        sinks.add(Box::new(StdoutSink::default()), None);
+        sinks.add(
+            Box::new(FileSink::new(path.to_str().unwrap(), Rotation::default()).unwrap()),
+            Some(vec!["task_kill".to_string()]),
+        );
+        assert!(sinks.contains("file"));
+        assert!(!sinks.contains("webhook"));
+        assert_eq!(sinks.routes("task_kill"), vec![0, 1]);
+        assert_eq!(sinks.routes("sb_mount"), vec![0]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_rotation() {
+        let dir = directory("file-sink");
+        let path = dir.join("events.ndjson");
+        let sink = FileSink::new(
+            path.to_str().unwrap(),
+            Rotation {
+                max_bytes: 16,
+                gzip: true,
+                ..Rotation::default()
+            },
+        )
+        .unwrap();
+        let first = event("task_kill", r#"{"a":1}"#);
+        let second = event("task_kill", r#"{"b":2}"#);
+        assert_eq!(
+            sink.send(&[&first, &second]),
+            vec![BulkStatus::Indexed, BulkStatus::Indexed]
+        );
+        // the file is full, so it's rotated out before the next write
+        let third = event("task_kill", r#"{"c":3}"#);
+        assert_eq!(sink.send(&[&third]), vec![BulkStatus::Indexed]);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"c\":3}\n");
+        let rotated: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|p| p != &path)
+            .collect();
+        assert_eq!(rotated.len(), 1);
+        assert!(rotated[0].to_str().unwrap().ends_with(".gz"));
+        let mut contents = String::new();
+        GzDecoder::new(fs::File::open(&rotated[0]).unwrap())
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "{\"a\":1}\n{\"b\":2}\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_webhook() {
+        let (address, server) = serve(vec![(200, "{}"), (400, "{}")]);
+        let sink = WebhookSink::new(
+            &format!("{}/events", address),
+            &TlsOptions::default(),
+            Duration::from_secs(5),
+        )
+        .unwrap()
+        .auth(Some(Auth::Bearer("token".to_string())));
+        let first = event("task_kill", r#"{"a":1}"#);
+        let second = event("sb_mount", r#"{"b":2}"#);
+        assert_eq!(
+            sink.send(&[&first, &second]),
+            vec![BulkStatus::Indexed, BulkStatus::Indexed]
+        );
+        // a client error isn't retried
+        assert_eq!(
+            sink.send(&[&first]),
+            vec![BulkStatus::Rejected {
+                error: "status 400".to_string(),
+                attempts: 1,
+            }]
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].request_line, "POST /events HTTP/1.1");
+        assert_eq!(
+            requests[0].header("content-type"),
+            Some("application/x-ndjson")
+        );
+        assert_eq!(requests[0].header("authorization"), Some("Bearer token"));
+        assert_eq!(requests[0].text(), "{\"a\":1}\n{\"b\":2}\n");
+    }
+}
+
+mod syslog {
+    use super::helpers::event;
+    use crate::client::BulkStatus;
+    use crate::sinks::{parse_facility, Event, Sink, SyslogAddress, SyslogFormat, SyslogSink};
+    use std::io::Read;
+    use std::net::{TcpListener, UdpSocket};
+    use std::os::unix::net::UnixDatagram;
+    use std::time::Duration;
+
+    fn exec(action: &str) -> Event {
+        let document = format!(
+            r#"{{"@timestamp":"2021-02-12T17:10:14Z","event":{{"action":"{}"}},"host":{{"hostname":"ubuntu-hirsute"}},"process":{{"executable":"/usr/bin/\"l]s"}}}}"#,
+            action
+        );
+        event("bprm_check_security", &document)
+    }
+
+    fn syslog_sink(address: SyslogAddress, format: SyslogFormat) -> SyslogSink {
+        SyslogSink::new(address, format, Duration::from_secs(5))
+    }
+
+    fn message(event: &Event) -> String {
+        syslog_sink(SyslogAddress::Udp(String::new()), SyslogFormat::Json).message(event)
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(
+            "unix:///dev/log".parse::<SyslogAddress>(),
+            Ok(SyslogAddress::Unix("/dev/log".to_string()))
+        );
+        assert_eq!(
+            "tcp://127.0.0.1:601".parse::<SyslogAddress>(),
+            Ok(SyslogAddress::Tcp("127.0.0.1:601".to_string()))
+        );
+        assert!("127.0.0.1:514".parse::<SyslogAddress>().is_err());
+        assert!("http://127.0.0.1:514".parse::<SyslogAddress>().is_err());
+        assert_eq!("structured".parse(), Ok(SyslogFormat::Structured));
+        assert_eq!(parse_facility("auth"), Ok(4));
+        assert_eq!(parse_facility("local0"), Ok(16));
+        assert!(parse_facility("nope").is_err());
+    }
+
+    #[test]
+    fn test_severity() {
+        let sink = syslog_sink(SyslogAddress::Udp(String::new()), SyslogFormat::Json);
+        let denied = exec("execution-denied");
+        // auth.warning
+        assert_eq!(
+            sink.message(&denied),
+            format!(
+                "<36>1 2021-02-12T17:10:14Z ubuntu-hirsute probe {} bprm_check_security - {}",
+                std::process::id(),
+                denied.document
+            )
+        );
+        // auth.info
+        assert!(sink
+            .message(&exec("execution-allowed"))
+            .starts_with("<38>1 "));
+    }
+
+    #[test]
+    fn test_structured_data() {
+        let sink = syslog_sink(SyslogAddress::Udp(String::new()), SyslogFormat::Structured)
+            .facility(parse_facility("local0").unwrap());
+        let message = sink.message(&exec("execution-denied"));
+        assert!(message.starts_with("<132>1 "));
+        assert!(message.ends_with(
+            r#" [probe@32473 @timestamp="2021-02-12T17:10:14Z" event.action="execution-denied" host.hostname="ubuntu-hirsute" process.executable="/usr/bin/\"l\]s"] execution-denied"#
+        ));
+    }
+
+    #[test]
+    fn test_long_parameter_names() {
+        let sink = syslog_sink(SyslogAddress::Udp(String::new()), SyslogFormat::Structured);
+        let long = event(
+            "bprm_check_security",
+            r#"{"@timestamp":"2021-02-12T17:10:14Z","process":{"parent":{"thread":{"capabilities":{"effective":"a","permitted":"b"}}}}}"#,
+        );
+        let message = sink.message(&long);
+        // shortened rather than dropped, and the same way every time
+        assert_eq!(message, sink.message(&long));
+        let names: Vec<&str> = message
+            .split(' ')
+            .map(|parameter| parameter.trim_end_matches(']'))
+            .filter_map(|parameter| {
+                parameter
+                    .strip_suffix("=\"a\"")
+                    .or_else(|| parameter.strip_suffix("=\"b\""))
+            })
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert_ne!(names[0], names[1]);
+        for name in names {
+            assert_eq!(name.len(), 32);
+            assert!(name.starts_with("process.parent.thread.c~"));
+        }
+    }
+
+    #[test]
+    fn test_udp() {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = server.local_addr().unwrap().to_string();
+        let sink = syslog_sink(SyslogAddress::Udp(address), SyslogFormat::Json);
+        let denied = exec("execution-denied");
+        assert_eq!(sink.send(&[&denied]), vec![BulkStatus::Indexed]);
+        let mut buffer = [0; 4096];
+        let received = server.recv(&mut buffer).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&buffer[..received]),
+            sink.message(&denied)
+        );
+
+        // truncated to fit in a datagram
+        let sink = sink.max_datagram_size(64);
+        assert_eq!(sink.send(&[&denied]), vec![BulkStatus::Indexed]);
+        let received = server.recv(&mut buffer).unwrap();
+        assert_eq!(received, 64);
+        assert_eq!(
+            String::from_utf8_lossy(&buffer[..received]),
+            &sink.message(&denied)[..64]
+        );
+
+        // more than any udp datagram can carry
+        let sink = sink.max_datagram_size(1 << 20);
+        let large = exec(&"x".repeat(1 << 17));
+        assert!(matches!(
+            sink.send(&[&large, &denied])[..],
+            [BulkStatus::Rejected { .. }, BulkStatus::Indexed]
+        ));
+    }
+
+    #[test]
+    fn test_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let sink = syslog_sink(SyslogAddress::Tcp(address), SyslogFormat::Json);
+        let first = exec("execution-denied");
+        let second = exec("execution-allowed");
+        assert_eq!(
+            sink.send(&[&first, &second]),
+            vec![BulkStatus::Indexed, BulkStatus::Indexed]
+        );
+        drop(sink);
+        let mut received = String::new();
+        listener
+            .accept()
+            .unwrap()
+            .0
+            .read_to_string(&mut received)
+            .unwrap();
+        // octet counted
+        let first = message(&first);
+        let second = message(&second);
+        assert_eq!(
+            received,
+            format!("{} {}{} {}", first.len(), first, second.len(), second)
+        );
+    }
+
+    #[test]
+    fn test_unix_socket() {
+        let path = std::env::temp_dir().join(format!("probe-syslog-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let server = UnixDatagram::bind(&path).unwrap();
+        let sink = syslog_sink(
+            SyslogAddress::Unix(path.to_str().unwrap().to_string()),
+            SyslogFormat::Json,
+        );
+        let denied = exec("execution-denied");
+        assert_eq!(sink.send(&[&denied]), vec![BulkStatus::Indexed]);
+        let mut buffer = [0; 4096];
+        let received = server.recv(&mut buffer).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&buffer[..received]),
+            sink.message(&denied)
+        );
+        std::fs::remove_file(path).unwrap();
+
+        // the relay went away
+        let sink = syslog_sink(
+            SyslogAddress::Unix("/nonexistent/probe.sock".to_string()),
+            SyslogFormat::Json,
+        );
+        assert!(matches!(sink.send(&[&denied])[0], BulkStatus::Retry(_)));
+    }
+}
+
+mod splunk {
+    use super::helpers::{client_for, event, serve};
+    use crate::client::{Auth, BulkStatus};
+    use crate::sinks::{ModuleMapping, Sink, SplunkSink};
+
+    fn document(id: u32) -> String {
+        format!(
+            r#"{{"@timestamp":"2021-02-12T17:10:14Z","event":{{"id":"{}"}},"host":{{"hostname":"ubuntu-hirsute"}}}}"#,
+            id
+        )
+    }
+
+    fn sink(address: String) -> SplunkSink {
+        SplunkSink::new(client_for(address, Some(Auth::Splunk("token".to_string()))))
+    }
+
+    #[test]
+    fn test_module_mapping() {
+        let mapping = ModuleMapping::parse("security,sb_mount=mounts").unwrap();
+        assert_eq!(mapping.get("sb_mount"), Some("mounts"));
+        assert_eq!(mapping.get("task_kill"), Some("security"));
+        assert_eq!(
+            ModuleMapping::parse("sb_mount=mounts")
+                .unwrap()
+                .get("task_kill"),
+            None
+        );
+        assert!(ModuleMapping::parse("a,b").is_err());
+        assert!(ModuleMapping::parse("sb_mount=").is_err());
+    }
+
+    #[test]
+    fn test_events() {
+        let (address, server) = serve(vec![(200, r#"{"text":"Success","code":0}"#)]);
+        let sink = sink(address)
+            .sourcetypes(ModuleMapping::parse("bprm_check_security=probe:exec").unwrap())
+            .indexes(ModuleMapping::parse("inode_unlink=files").unwrap());
+        let exec = event("bprm_check_security", &document(1));
+        let unlink = event("inode_unlink", &document(2));
+        assert_eq!(
+            sink.send(&[&exec, &unlink]),
+            vec![BulkStatus::Indexed, BulkStatus::Indexed]
+        );
+        assert_eq!(sink.destination(&unlink), "files");
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].request_line,
+            "POST /services/collector/event HTTP/1.1"
+        );
+        assert_eq!(requests[0].header("authorization"), Some("Splunk token"));
+        assert_eq!(
+            requests[0].text(),
+            format!(
+                "{{\"time\":1613149814,\"source\":\"probe\",\"sourcetype\":\"probe:exec\",\"host\":\"ubuntu-hirsute\",\"event\":{}}}\n\
+                 {{\"time\":1613149814,\"source\":\"probe\",\"sourcetype\":\"probe:inode_unlink\",\"host\":\"ubuntu-hirsute\",\"index\":\"files\",\"event\":{}}}\n",
+                exec.document, unlink.document
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_event() {
+        let (address, server) = serve(vec![
+            (
+                400,
+                r#"{"text":"Incorrect data format","code":6,"invalid-event-number":1}"#,
+            ),
+            (200, r#"{"text":"Success","code":0}"#),
+        ]);
+        let sink = sink(address);
+        let events = [
+            event("task_kill", &document(1)),
+            event("task_kill", &document(2)),
+            event("task_kill", &document(3)),
+        ];
+        assert_eq!(
+            sink.send(&events.iter().collect::<Vec<_>>()),
+            vec![
+                BulkStatus::Indexed,
+                BulkStatus::Rejected {
+                    error: "status 400: Incorrect data format".to_string(),
+                    attempts: 1,
+                },
+                BulkStatus::Indexed,
+            ]
+        );
+        // only the events after the invalid one are sent again
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].text().lines().count(), 1);
+        assert!(requests[1].text().contains(&events[2].document));
+    }
+
+    #[test]
+    fn test_busy_collector() {
+        let (address, server) = serve(vec![
+            (503, r#"{"text":"Server is busy","code":9}"#),
+            (200, r#"{"text":"Success","code":0}"#),
+        ]);
+        let sink = sink(address);
+        let kill = event("task_kill", &document(1));
+        assert_eq!(sink.send(&[&kill]), vec![BulkStatus::Indexed]);
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+}
+
+mod otlp {
+    use super::helpers::{client_for, event, serve};
+    use crate::client::{Auth, BulkStatus};
+    use crate::sinks::{Event, OtlpSink, Sink};
+
+    fn exec(hostname: &str, action: &str) -> Event {
+        let document = format!(
+            r#"{{"@timestamp":"2021-02-12T17:10:14Z","event":{{"action":"{}"}},"host":{{"hostname":"{}","ip":["10.0.2.15"]}},"process":{{"pid":214904,"args":["ls","--color=auto"]}}}}"#,
+            action, hostname
+        );
+        event("bprm_check_security", &document)
+    }
+
+    fn sink(address: String) -> OtlpSink {
+        OtlpSink::new(client_for(address, Some(Auth::Bearer("token".to_string()))))
+    }
+
+    #[test]
+    fn test_export_request() {
+        let denied = exec("ubuntu-hirsute", "execution-denied");
+        let allowed = exec("ubuntu-hirsute", "execution-allowed");
+        let other = exec("ubuntu-groovy", "execution-allowed");
+        let request = OtlpSink::export_request(&[&denied, &allowed, &other]);
+        let get = |path: &str| ajson::get(&request, path).unwrap().to_string();
+
+        // one resource per host
+        assert_eq!(get("resourceLogs.#"), "2");
+        assert_eq!(
+            get("resourceLogs.0.resource.attributes.#(key==\"host.name\").value.stringValue"),
+            "ubuntu-hirsute"
+        );
+        assert_eq!(
+            get("resourceLogs.0.resource.attributes.#(key==\"host.ip\").value.arrayValue.values.0.stringValue"),
+            "10.0.2.15"
+        );
+        assert_eq!(
+            get("resourceLogs.1.resource.attributes.#(key==\"host.name\").value.stringValue"),
+            "ubuntu-groovy"
+        );
+
+        let records = "resourceLogs.0.scopeLogs.0.logRecords";
+        assert_eq!(get(&format!("{}.#", records)), "2");
+        assert_eq!(
+            get(&format!("{}.0.timeUnixNano", records)),
+            "1613149814000000000"
+        );
+        assert_eq!(get(&format!("{}.0.severityText", records)), "WARN");
+        assert_eq!(get(&format!("{}.1.severityText", records)), "INFO");
+        assert_eq!(
+            get(&format!("{}.0.body.stringValue", records)),
+            "execution-denied"
+        );
+        assert_eq!(
+            get(&format!(
+                "{}.0.attributes.#(key==\"process.pid\").value.intValue",
+                records
+            )),
+            "214904"
+        );
+        assert_eq!(
+            get(&format!(
+                "{}.0.attributes.#(key==\"process.args\").value.arrayValue.values.1.stringValue",
+                records
+            )),
+            "--color=auto"
+        );
+        // host fields only describe the resource
+        assert!(ajson::get(
+            &request,
+            &format!("{}.0.attributes.#(key==\"host.hostname\")", records)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_export() {
+        let (address, server) = serve(vec![
+            (503, "{}"),
+            (200, "{}"),
+            (400, r#"{"code":3,"message":"invalid log record"}"#),
+        ]);
+        let sink = sink(address);
+        let denied = exec("ubuntu-hirsute", "execution-denied");
+        // the collector was unavailable at first
+        assert_eq!(sink.send(&[&denied]), vec![BulkStatus::Indexed]);
+        assert_eq!(
+            sink.send(&[&denied]),
+            vec![BulkStatus::Rejected {
+                error: "status 400: invalid log record".to_string(),
+                attempts: 1,
+            }]
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].request_line, "POST /v1/logs HTTP/1.1");
+        assert_eq!(requests[1].header("content-type"), Some("application/json"));
+        assert_eq!(requests[1].header("authorization"), Some("Bearer token"));
+        assert_eq!(requests[1].text(), requests[0].text());
+    }
+}
+
+// these load the probe, so they need root and a kernel with the BPF LSM,
+// i.e. `sudo cargo test -- --ignored` inside the Vagrant box
+mod probe {
+    use once_cell::sync::Lazy;
+    use probe_sys::{entity_id, process_start, FieldValue, Probe, ProbeHandler, SerializableEvent};
+    use std::fs;
+    use std::process::Command;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    #[derive(Debug, Clone)]
+    struct Seen {
+        module: &'static str,
+        pid: u64,
+        parent_start: u64,
+        executable: String,
+        command_line: String,
+        args_truncated: bool,
+        env: Vec<String>,
+        env_truncated: bool,
+        duration: u64,
+    }
+
+    static SEEN: Lazy<Mutex<Vec<Seen>>> = Lazy::new(|| Mutex::new(vec![]));
+
+    #[derive(Clone, Copy)]
+    struct Capture;
+
+    impl ProbeHandler<String> for Capture {
+        fn enqueue<T>(&self, event: &mut T) -> Result<(), String>
+        where
+            T: SerializableEvent + std::fmt::Debug,
+        {
+            let string = |path| match event.get_field(path) {
+                Some(FieldValue::String(s)) => s,
+                _ => String::new(),
+            };
+            let number = |path| match event.get_field(path) {
+                Some(FieldValue::Number(n)) => n,
+                _ => 0,
+            };
+            let seen = Seen {
+                module: event.suffix(),
+                pid: number("process.pid"),
+                parent_start: number("process.parent.start"),
+                executable: string("process.executable"),
+                command_line: string("process.command_line"),
+                args_truncated: matches!(
+                    event.get_field("process.args_truncated"),
+                    Some(FieldValue::Bool(true))
+                ),
+                env: match event.get_field("process.env") {
+                    Some(FieldValue::Strings(env)) => env,
+                    _ => vec![],
+                },
+                env_truncated: matches!(
+                    event.get_field("process.env_truncated"),
+                    Some(FieldValue::Bool(true))
+                ),
+                duration: number("event.duration"),
+            };
+            SEEN.lock().unwrap().push(seen);
+            Ok(())
+        }
+    }
+
+    // polls the probe until an event matching the predicate comes in
+    fn wait_for<F>(probe: &Probe, predicate: F) -> Seen
+    where
+        F: Fn(&Seen) -> bool,
+    {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            probe.poll(100);
+            if let Some(seen) = SEEN.lock().unwrap().iter().find(|s| predicate(s)) {
+                return seen.clone();
+            }
+            assert!(Instant::now() < deadline, "event was never reported");
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn test_command_line_over_limit() {
+        let mut probe = Probe::new();
+        probe.command_line(true).run(Capture).unwrap();
+
+        // five arguments of a thousand bytes run just past the 4KB buffer
+        let marker = format!("probe-test-{}", std::process::id());
+        let filler = "a".repeat(1000);
+        let mut args = vec![marker.clone()];
+        args.extend(std::iter::repeat(filler).take(5));
+        Command::new("/bin/echo").args(&args).output().unwrap();
+
+        let seen = wait_for(&probe, |s| {
+            s.module == "bprm_check_security"
+                && s.executable == "/bin/echo"
+                && s.command_line.contains(&marker)
+        });
+        let prefix = format!("/bin/echo {} ", marker);
+        assert!(seen.command_line.starts_with(&prefix));
+        assert!(seen.command_line.len() < 4096);
+        // nothing past the last full argument was overwritten by a separator
+        assert!(seen.command_line[prefix.len()..]
+            .bytes()
+            .all(|b| b == b'a' || b == b' '));
+        assert!(seen.args_truncated);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_env_over_limits() {
+        let mut probe = Probe::new();
+        probe
+            .command_line(true)
+            .environment(vec!["PROBE_TEST".to_string()])
+            .run(Capture)
+            .unwrap();
+        let run = |marker: &str, value: &str, filler: usize| {
+            Command::new("/bin/echo")
+                .arg(marker)
+                .env_clear()
+                .env("PROBE_TEST", value)
+                .envs((0..filler).map(|i| (format!("PROBE_FILLER_{}", i), "x")))
+                .output()
+                .unwrap();
+            wait_for(&probe, |s| {
+                s.module == "bprm_check_security" && s.command_line.contains(marker)
+            })
+        };
+
+        let marker = format!("probe-test-{}", std::process::id());
+        let seen = run(&format!("{}-fits", marker), "short", 0);
+        assert_eq!(seen.env, vec!["PROBE_TEST=short".to_string()]);
+        assert!(!seen.env_truncated);
+
+        // a value longer than the 256 byte buffer
+        let seen = run(&format!("{}-long", marker), &"a".repeat(300), 0);
+        assert_eq!(seen.env.len(), 1);
+        assert!(seen.env_truncated);
+
+        // more variables than are scanned
+        let seen = run(&format!("{}-many", marker), "short", 200);
+        assert!(seen.env_truncated);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_exit_duration_leader_exits_first() {
+        let dir = std::env::temp_dir().join(format!("probe-exit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("leader_exits_first.c");
+        let binary = dir.join("leader_exits_first");
+        fs::write(
+            &source,
+            include_str!("../libprobe/tests/leader_exits_first.c"),
+        )
+        .unwrap();
+        let status = Command::new("cc")
+            .arg("-pthread")
+            .arg("-o")
+            .arg(&binary)
+            .arg(&source)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let mut probe = Probe::new();
+        probe.run(Capture).unwrap();
+        let mut child = Command::new(&binary).spawn().unwrap();
+        let pid = u64::from(child.id());
+        assert!(child.wait().unwrap().success());
+
+        let seen = wait_for(&probe, |s| s.module == "sched_process_exit" && s.pid == pid);
+        // measured from the main thread rather than the worker that exited last
+        assert!(Duration::from_nanos(seen.duration) >= Duration::from_millis(900));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    #[ignore]
+    fn test_seeded_entity_id_matches_events() {
+        let mut probe = Probe::new();
+        probe.run(Capture).unwrap();
+        let adjustment = probe.clock_adjustment().unwrap();
+
+        // the test was running before the probe, so it was seeded rather than
+        // tracked from an event
+        let pid = std::process::id();
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap();
+        let seeded = entity_id(pid, process_start(&stat, adjustment).unwrap()).unwrap();
+
+        let mut child = Command::new("/bin/true").spawn().unwrap();
+        let child_pid = u64::from(child.id());
+        assert!(child.wait().unwrap().success());
+
+        let seen = wait_for(&probe, |s| {
+            s.module == "bprm_check_security" && s.pid == child_pid
+        });
+        assert_eq!(entity_id(pid, seen.parent_start).unwrap(), seeded);
+    }
 }
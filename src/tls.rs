@@ -0,0 +1,170 @@
+use rustls::internal::pemfile::{certs, pkcs8_private_keys, rsa_private_keys};
+use rustls::{
+    Certificate, ClientConfig, PrivateKey, RootCertStore, ServerCertVerified, ServerCertVerifier,
+    TLSError, WebPKIVerifier,
+};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+pub struct SkipVerifier {}
+
+impl ServerCertVerifier for SkipVerifier {
+    fn verify_server_cert(
+        &self,
+        _roots: &RootCertStore,
+        _presented_certs: &[Certificate],
+        _dns_name: webpki::DNSNameRef<'_>,
+        _ocsp_response: &[u8],
+    ) -> Result<ServerCertVerified, TLSError> {
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+/// Only accepts a server whose certificate has the given SHA256 fingerprint,
+/// regardless of who issued it unless the chain is verified as well.
+pub struct FingerprintVerifier {
+    fingerprint: Vec<u8>,
+    verify_chain: bool,
+}
+
+impl FingerprintVerifier {
+    // accepts both `ab:cd:...` and plain hex
+    pub fn new(fingerprint: &str) -> Result<Self, String> {
+        let hex: Vec<u8> = fingerprint.bytes().filter(|c| *c != b':').collect();
+        if hex.len() != 64 || !hex.iter().all(u8::is_ascii_hexdigit) {
+            return Err(format!("invalid SHA256 fingerprint {}", fingerprint));
+        }
+        let fingerprint = hex
+            .chunks(2)
+            .map(|pair| u8::from_str_radix(&String::from_utf8_lossy(pair), 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| format!("invalid SHA256 fingerprint {}", fingerprint))?;
+        Ok(Self {
+            fingerprint,
+            verify_chain: false,
+        })
+    }
+
+    /// Also requires the certificate to chain up to one of the trusted
+    /// roots, i.e. the ones given with `--ca`.
+    pub fn verify_chain(mut self) -> Self {
+        self.verify_chain = true;
+        self
+    }
+}
+
+impl ServerCertVerifier for FingerprintVerifier {
+    fn verify_server_cert(
+        &self,
+        roots: &RootCertStore,
+        presented_certs: &[Certificate],
+        dns_name: webpki::DNSNameRef<'_>,
+        ocsp_response: &[u8],
+    ) -> Result<ServerCertVerified, TLSError> {
+        if self.verify_chain {
+            WebPKIVerifier::new().verify_server_cert(
+                roots,
+                presented_certs,
+                dns_name,
+                ocsp_response,
+            )?;
+        }
+        let certificate = presented_certs
+            .first()
+            .ok_or(TLSError::NoCertificatesPresented)?;
+        if Sha256::digest(&certificate.0).as_slice() == self.fingerprint.as_slice() {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(TLSError::General(String::from(
+                "server certificate doesn't match the pinned fingerprint",
+            )))
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TlsOptions {
+    pub insecure: bool,
+    pub ca: Option<String>,
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+    pub fingerprint: Option<String>,
+}
+
+fn open(path: &str) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| format!("could not open {}: {}", path, e))
+}
+
+fn load_key(path: &str) -> Result<PrivateKey, String> {
+    let mut keys = pkcs8_private_keys(&mut open(path)?).unwrap_or_default();
+    if keys.is_empty() {
+        keys = rsa_private_keys(&mut open(path)?).unwrap_or_default();
+    }
+    keys.into_iter()
+        .next()
+        .ok_or_else(|| format!("no private key found in {}", path))
+}
+
+impl TlsOptions {
+    /// Builds a rustls config when any of the options differ from the
+    /// defaults, otherwise the client sticks with ureq's.
+    pub fn client_config(&self) -> Result<Option<Arc<ClientConfig>>, String> {
+        if !self.insecure
+            && self.ca.is_none()
+            && self.client_cert.is_none()
+            && self.client_key.is_none()
+            && self.fingerprint.is_none()
+        {
+            return Ok(None);
+        }
+        let mut config = ClientConfig::new();
+        match &self.ca {
+            Some(ca) => {
+                let (added, _) = config
+                    .root_store
+                    .add_pem_file(&mut open(ca)?)
+                    .map_err(|_| format!("could not parse {}", ca))?;
+                if added == 0 {
+                    return Err(format!("no certificates found in {}", ca));
+                }
+            }
+            None => config
+                .root_store
+                .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS),
+        }
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => {
+                let chain =
+                    certs(&mut open(cert)?).map_err(|_| format!("could not parse {}", cert))?;
+                config
+                    .set_single_client_cert(chain, load_key(key)?)
+                    .map_err(|e| e.to_string())?;
+            }
+            (None, None) => {}
+            _ => {
+                return Err(String::from(
+                    "client certificates need both a certificate and a key",
+                ))
+            }
+        }
+        if let Some(fingerprint) = &self.fingerprint {
+            let mut verifier = FingerprintVerifier::new(fingerprint)?;
+            // a CA narrows down the pin rather than being ignored
+            if self.ca.is_some() {
+                verifier = verifier.verify_chain();
+            }
+            config
+                .dangerous()
+                .set_certificate_verifier(Arc::new(verifier));
+        } else if self.insecure {
+            config
+                .dangerous()
+                .set_certificate_verifier(Arc::new(SkipVerifier {}));
+        }
+        Ok(Some(Arc::new(config)))
+    }
+}